@@ -16,21 +16,24 @@ fn main() {
 async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     let _ = dotenv::dotenv();
     let mut args = std::env::args().skip(1);
+    let client = Foo::default();
     let token = UserToken::from_existing(
-        twitch_oauth2::client::reqwest_http_client,
+        &client.client.helix,
         std::env::var("TWITCH_TOKEN")
             .ok()
             .or_else(|| args.next())
             .map(AccessToken::new)
             .expect("Please set env: TWITCH_TOKEN or pass token as first argument"),
         None,
+        None,
     )
     .await?;
 
-    let client = Foo::default();
-
     let req = GetStreamsRequest::builder()
-        .user_login(vec![args.next().expect("please provide an username")])
+        .user_login(vec![args
+            .next()
+            .expect("please provide an username")
+            .into()])
         .build();
     client.client.helix.clone_client();
     let response = client.client.helix.req_get(req, &token).await?;
@@ -17,31 +17,25 @@ fn main() {
 async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     let _ = dotenv::dotenv();
     let mut args = std::env::args().skip(1);
+    let client = HelixClient::with_client(surf::Client::new());
     let token = UserToken::from_existing(
-        twitch_oauth2::client::surf_http_client,
+        &client,
         std::env::var("TWITCH_TOKEN")
             .ok()
             .or_else(|| args.next())
             .map(AccessToken::new)
             .expect("Please set env: TWITCH_TOKEN or pass token as first argument"),
         None,
+        None,
     )
     .await?;
 
-    let broadcaster_id = dbg!(
-        token
-            .validate_token(twitch_oauth2::client::surf_http_client)
-            .await?
-    )
-    .user_id
-    .unwrap();
-
-    let client = HelixClient::with_client(surf::Client::new());
+    let broadcaster_id = token.user_id.to_string();
     for user in args {
         let user_id = match client
             .req_get(
                 twitch_api2::helix::users::GetUsersRequest::builder()
-                    .login(vec![user.to_string()])
+                    .login(vec![user.into()])
                     .build(),
                 &token,
             )
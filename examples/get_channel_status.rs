@@ -17,22 +17,22 @@ fn main() {
 async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     let _ = dotenv::dotenv();
     let mut args = std::env::args().skip(1);
+    let client: HelixClient<'static, reqwest::Client> = HelixClient::new();
     let token = UserToken::from_existing(
-        twitch_oauth2::client::reqwest_http_client,
+        &client,
         std::env::var("TWITCH_TOKEN")
             .ok()
             .or_else(|| args.next())
             .map(AccessToken::new)
             .expect("Please set env: TWITCH_TOKEN or pass token as first argument"),
         None,
+        None,
     )
     .await
     .unwrap();
 
-    let client: HelixClient<'static, reqwest::Client> = HelixClient::new();
-
     let req = GetStreamsRequest::builder()
-        .user_login(vec![args.next().unwrap()])
+        .user_login(vec![args.next().unwrap().into()])
         .build();
 
     let response = client.req_get(req, &token).await.unwrap();
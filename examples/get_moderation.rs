@@ -5,7 +5,7 @@ use twitch_api2::{
     },
     HelixClient,
 };
-use twitch_oauth2::{AccessToken, TwitchToken, UserToken};
+use twitch_oauth2::{AccessToken, UserToken};
 
 fn main() {
     use std::error::Error;
@@ -23,24 +23,20 @@ fn main() {
 async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
     let _ = dotenv::dotenv();
     let mut args = std::env::args().skip(1);
+    let client = HelixClient::with_client(surf::Client::new());
     let token = UserToken::from_existing(
-        twitch_oauth2::client::surf_http_client,
+        &client,
         std::env::var("TWITCH_TOKEN")
             .ok()
             .or_else(|| args.next())
             .map(AccessToken::new)
             .expect("Please set env: TWITCH_TOKEN or pass token as first argument"),
         None,
+        None,
     )
     .await?;
 
-    let broadcaster_id = token
-        .validate_token(twitch_oauth2::client::surf_http_client)
-        .await?
-        .user_id
-        .unwrap();
-
-    let client = HelixClient::with_client(surf::Client::new());
+    let broadcaster_id = token.user_id.to_string();
 
     println!("====Moderators====");
     let moderators_req = GetModeratorsRequest::builder()
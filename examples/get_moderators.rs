@@ -0,0 +1,45 @@
+use twitch_api2::{helix::moderation::GetModeratorsRequest, HelixClient};
+use twitch_oauth2::{AccessToken, UserToken};
+
+fn main() {
+    use std::error::Error;
+    if let Err(err) = run() {
+        println!("Error: {}", err);
+        let mut e: &'_ dyn Error = err.as_ref();
+        while let Some(cause) = e.source() {
+            println!("Caused by: {:?}", cause);
+            e = cause;
+        }
+    }
+}
+
+#[tokio::main]
+async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let _ = dotenv::dotenv();
+    let mut args = std::env::args().skip(1);
+    // `reqwest::Client` implements `HttpClient` with the `reqwest_client` feature
+    let client = HelixClient::with_client(reqwest::Client::new());
+    let token = UserToken::from_existing(
+        &client,
+        std::env::var("TWITCH_TOKEN")
+            .ok()
+            .or_else(|| args.next())
+            .map(AccessToken::new)
+            .expect("Please set env: TWITCH_TOKEN or pass token as first argument"),
+        None,
+        None,
+    )
+    .await?;
+
+    let broadcaster_id = token.user_id.to_string();
+
+    let req = GetModeratorsRequest::for_broadcaster(broadcaster_id);
+    let mut response = client.req_get(req, &token).await?;
+    println!("{:?}", response.data);
+
+    while let Ok(Some(new_response)) = response.get_next(&client, &token).await {
+        response = new_response;
+        println!("{:?}", response.data);
+    }
+    Ok(())
+}
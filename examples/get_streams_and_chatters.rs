@@ -6,22 +6,22 @@ use twitch_oauth2::{AccessToken, UserToken};
 async fn main() {
     let _ = dotenv::dotenv();
     let mut args = std::env::args().skip(1);
+    let client = reqwest::Client::new();
+    let client_tmi = TMIClient::with_client(client.clone());
+    let client_helix = HelixClient::with_client(client);
     let token = UserToken::from_existing(
-        twitch_oauth2::client::surf_http_client,
+        &client_helix,
         std::env::var("TWITCH_TOKEN")
             .ok()
             .or_else(|| args.next())
             .map(AccessToken::new)
             .expect("Please set env: TWITCH_TOKEN or pass token as first argument"),
         None,
+        None,
     )
     .await
     .unwrap();
 
-    let client = reqwest::Client::new();
-    let client_tmi = TMIClient::with_client(client.clone());
-    let client_helix = HelixClient::with_client(client);
-
     let streams: Vec<String> = args.collect();
     let req = GetStreamsRequest::builder().build();
 
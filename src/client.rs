@@ -53,6 +53,29 @@
 //!
 //! See the source of this module for the implementation of [`Client`] for [surf](https://crates.io/crates/surf) and [reqwest](https://crates.io/crates/reqwest) if you need inspiration.
 //!
+//! # reqwest and surf
+//!
+//! You don't need to implement [`Client`] yourself for [reqwest](https://crates.io/crates/reqwest) or [surf](https://crates.io/crates/surf),
+//! enable the `reqwest_client` or `surf_client` feature and use their clients directly.
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "reqwest_client")]
+//! # async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::UserToken::from_existing_unchecked(
+//! #     twitch_oauth2::AccessToken::new("validtoken".to_string()), None,
+//! #     twitch_oauth2::ClientId::new("validclientid".to_string()), None,
+//! #     "justinfan".into(), "1234".into(), None, None);
+//! use twitch_api2::helix::{moderation::GetModeratorsRequest, HelixClient};
+//!
+//! let client = HelixClient::with_client(reqwest::Client::new());
+//! let request = GetModeratorsRequest::for_broadcaster("1234");
+//! let moderators = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`examples/get_moderators.rs`](https://github.com/Emilgardis/twitch_api2/blob/master/examples/get_moderators.rs) for a complete program.
+//!
 
 use std::error::Error;
 use std::future::Future;
@@ -112,7 +135,7 @@ impl<'a> Client<'a> for ReqwestClient {
                 // This should not fail, we just created the response.
                 .expect("expected to get headers mut when building response");
             std::mem::swap(headers, response.headers_mut());
-            let result = result.version(response.version());
+            let result = result.status(response.status()).version(response.version());
             Ok(result
                 .body(response.bytes().await?.as_ref().to_vec())
                 .expect("mismatch reqwest -> http conversion should not fail"))
@@ -185,7 +208,10 @@ impl<'a> Client<'a> for SurfClient {
                 })
                 .collect::<Result<_, SurfError>>()?;
 
-            let _ = std::mem::replace(&mut result.headers_mut(), Some(&mut response_headers));
+            if let Some(headers) = result.headers_mut() {
+                std::mem::swap(headers, &mut response_headers);
+            }
+            let result = result.status(u16::from(response.status()));
             let result = if let Some(v) = response.version() {
                 result.version(match v {
                     surf::http::Version::Http0_9 => http::Version::HTTP_09,
@@ -218,3 +244,11 @@ impl<'a> Client<'a> for DummyHttpClient {
         Box::pin(async { Err(DummyHttpClient) })
     }
 }
+
+impl<'a> twitch_oauth2::client::Client<'a> for DummyHttpClient {
+    type Error = DummyHttpClient;
+
+    fn req(&'a self, _: Req) -> BoxedFuture<'a, Result<Response, Self::Error>> {
+        Box::pin(async { Err(DummyHttpClient) })
+    }
+}
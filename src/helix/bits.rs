@@ -7,7 +7,7 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetCheermotesRequest::builder()
@@ -56,7 +56,7 @@ use serde::{Deserialize, Serialize};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_cheermotes::GetCheermotesRequest::builder().build();
 /// let response: Vec<get_cheermotes::Cheermote> = client.req_get(request, &token).await?.data;
 /// # Ok(())
@@ -73,6 +73,7 @@ pub mod get_cheermotes {
     /// [`get-cheermotes`](https://dev.twitch.tv/docs/api/reference#get-cheermotes)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetCheermotesRequest {
         /// ID for the broadcaster who might own specialized Cheermotes.
         #[builder(default, setter(into))]
@@ -83,7 +84,7 @@ pub mod get_cheermotes {
     ///
     /// [`get-cheermotes`](https://dev.twitch.tv/docs/api/reference#get-cheermotes)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct Cheermote {
         /// Indicates whether or not this emote provides a charity contribution match during charity campaigns.
@@ -103,7 +104,7 @@ pub mod get_cheermotes {
 
     /// Types of cheermotes
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[serde(rename_all = "snake_case")]
     #[non_exhaustive]
     pub enum CheermoteType {
@@ -125,7 +126,7 @@ pub mod get_cheermotes {
 
     /// Information about tier of emote
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct Tiers {
         /// Indicates whether or not emote information is accessible to users.
@@ -144,7 +145,7 @@ pub mod get_cheermotes {
 
     /// Images for different themes
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct CheermoteImages {
         /// Dark mode
@@ -155,7 +156,7 @@ pub mod get_cheermotes {
 
     /// URLs to the same image, animated or static.
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct CheermoteImage {
         /// Animated GIFs
@@ -166,7 +167,7 @@ pub mod get_cheermotes {
     }
     /// Images in sizes
     #[derive(PartialEq, Eq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct CheermoteImageArray {
         /// URL to image in size 28 × 28
@@ -196,6 +197,7 @@ pub mod get_cheermotes {
     impl helix::Request for GetCheermotesRequest {
         type Response = Vec<Cheermote>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "bits/cheermotes";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -435,13 +437,12 @@ pub mod get_cheermotes {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/bits/cheermotes?broadcaster_id=1234"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/bits/cheermotes?broadcaster_id=1234",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -459,7 +460,7 @@ pub mod get_cheermotes {
 /// ```rust, no_run
 /// use twitch_api2::helix::bits::get_bits_leaderboard;
 /// let request = get_bits_leaderboard::GetBitsLeaderboardRequest::builder()
-///     .started_at("2020-01-01T07:00:00Z".to_string())
+///     .started_at(twitch_api2::types::Timestamp::new("2020-01-01T07:00:00Z"))
 ///     .period("day".to_string())
 ///     .build();
 /// // Get leaderbord for the lifetime of the channel
@@ -479,7 +480,7 @@ pub mod get_cheermotes {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_bits_leaderboard::GetBitsLeaderboardRequest::builder().build();
 /// let response: get_bits_leaderboard::BitsLeaderboard = client.req_get(request, &token).await?.data;
 /// # Ok(())
@@ -489,8 +490,6 @@ pub mod get_cheermotes {
 /// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
 /// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
 pub mod get_bits_leaderboard {
-    use std::convert::TryInto;
-
     use super::*;
 
     /// Query Parameters for [Get Bits Leaderboard](super::get_bits_leaderboard)
@@ -498,6 +497,7 @@ pub mod get_bits_leaderboard {
     /// [`get-bits-leaderboard`](https://dev.twitch.tv/docs/api/reference#get-bits-leaderboard)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetBitsLeaderboardRequest {
         /// Number of results to be returned. Maximum: 100. Default: 10.
         #[builder(default, setter(into))]
@@ -524,7 +524,7 @@ pub mod get_bits_leaderboard {
     ///
     /// [`get-bits-leaderboard`](https://dev.twitch.tv/docs/api/reference#get-bits-leaderboard)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct BitsLeaderboard {
         /// Leaderboard
@@ -537,7 +537,7 @@ pub mod get_bits_leaderboard {
 
     /// Period over which the returned data is aggregated.
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct DateRange {
         /// Start of the date range for the returned data.
@@ -548,7 +548,7 @@ pub mod get_bits_leaderboard {
 
     /// Information about user in leaderboard
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct LeaderboardUser {
         /// Leaderboard rank of the user.
@@ -558,12 +558,13 @@ pub mod get_bits_leaderboard {
         /// ID of the user (viewer) in the leaderboard entry.
         pub user_id: types::UserId,
         /// Display name corresponding to user_id.
-        pub user_name: types::UserName,
+        pub user_name: types::DisplayName,
     }
 
     impl helix::Request for GetBitsLeaderboardRequest {
         type Response = BitsLeaderboard;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "bits/leaderboard";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -589,31 +590,21 @@ pub mod get_bits_leaderboard {
                 total: i64,
             }
 
-            let text = std::str::from_utf8(&response.body())
-                .map_err(|e| helix::HelixRequestGetError::Utf8Error(response.body().clone(), e))?;
-            //eprintln!("\n\nmessage is ------------ {} ------------", text);
-            if let Ok(helix::HelixRequestError {
-                error,
-                status,
-                message,
-            }) = serde_json::from_str::<helix::HelixRequestError>(&text)
-            {
-                return Err(helix::HelixRequestGetError::Error {
-                    error,
-                    status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
-                    message,
-                    uri: uri.clone(),
-                });
-            }
-            let response: InnerResponse = serde_json::from_str(&text)?;
+            let (text, request_id) = helix::get_response_text(uri, &response)?;
+            let status = response.status();
+            let response: InnerResponse = helix::parse_json(text)?;
             Ok(helix::Response {
                 data: BitsLeaderboard {
                     leaderboard: response.data,
                     date_range: response.date_range,
                     total: response.total,
                 },
-                pagination: None,
+                pagination: <_>::default(),
                 request: self,
+                request_id,
+                status,
+                #[cfg(feature = "raw_json")]
+                raw: helix::raw_json(text),
             })
         }
     }
@@ -645,12 +636,8 @@ pub mod get_bits_leaderboard {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/bits/leaderboard?"
-        );
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/bits/leaderboard?");
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
@@ -7,7 +7,7 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetChannelInformationRequest::builder()
@@ -20,6 +20,8 @@
 //! # }
 //! ```
 
+#[doc(inline)]
+pub use get_channel_editors::{Editor, GetChannelEditorsRequest};
 #[doc(inline)]
 pub use get_channel_information::{ChannelInformation, GetChannelInformationRequest};
 #[doc(inline)]
@@ -61,7 +63,7 @@ use serde::{Deserialize, Serialize};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_channel_information::GetChannelInformationRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -73,14 +75,13 @@ use serde::{Deserialize, Serialize};
 /// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
 /// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
 pub mod get_channel_information {
-    use std::convert::TryInto;
-
     use super::*;
     /// Query Parameters for [Get Channel Information](super::get_channel_information)
     ///
     /// [`get-channel-information`](https://dev.twitch.tv/docs/api/reference#get-channel-information)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetChannelInformationRequest {
         /// ID of the channel
         #[builder(setter(into))]
@@ -91,13 +92,13 @@ pub mod get_channel_information {
     ///
     /// [`get-channel-information`](https://dev.twitch.tv/docs/api/reference#get-channel-information)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct ChannelInformation {
         /// Twitch User ID of this channel owner
         pub broadcaster_id: types::UserId,
-        /// User name of this channel owner
-        pub broadcaster_name: types::UserName,
+        /// Display name of this channel owner
+        pub broadcaster_name: types::DisplayName,
         /// Current game ID being played on the channel
         pub game_id: types::CategoryId,
         /// Name of current game being played on the channel
@@ -114,6 +115,7 @@ pub mod get_channel_information {
     impl helix::Request for GetChannelInformationRequest {
         type Response = Option<ChannelInformation>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "channels";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -128,27 +130,17 @@ pub mod get_channel_information {
         where
             Self: Sized,
         {
-            let text = std::str::from_utf8(&response.body())
-                .map_err(|e| helix::HelixRequestGetError::Utf8Error(response.body().clone(), e))?;
-            //eprintln!("\n\nmessage is ------------ {} ------------", text);
-            if let Ok(helix::HelixRequestError {
-                error,
-                status,
-                message,
-            }) = serde_json::from_str::<helix::HelixRequestError>(&text)
-            {
-                return Err(helix::HelixRequestGetError::Error {
-                    error,
-                    status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
-                    message,
-                    uri: uri.clone(),
-                });
-            }
-            let response: helix::InnerResponse<Vec<_>> = serde_json::from_str(&text)?;
+            let (text, request_id) = helix::get_response_text(uri, &response)?;
+            let status = response.status();
+            let response: helix::InnerResponse<Vec<_>> = helix::parse_json(text)?;
             Ok(helix::Response {
                 data: response.data.into_iter().next(),
-                pagination: response.pagination.cursor,
+                pagination: response.pagination,
                 request: self,
+                request_id,
+                status,
+                #[cfg(feature = "raw_json")]
+                raw: helix::raw_json(text),
             })
         }
     }
@@ -179,13 +171,12 @@ pub mod get_channel_information {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/channels?broadcaster_id=44445592"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/channels?broadcaster_id=44445592",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -229,7 +220,7 @@ pub mod get_channel_information {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = modify_channel_information::ModifyChannelInformationRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -250,6 +241,7 @@ pub mod modify_channel_information {
     /// [`modify-channel-information`](https://dev.twitch.tv/docs/api/reference#modify-channel-information)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct ModifyChannelInformationRequest {
         /// ID of the channel
         #[builder(setter(into))]
@@ -303,6 +295,7 @@ pub mod modify_channel_information {
     impl helix::Request for ModifyChannelInformationRequest {
         type Response = ModifyChannelInformation;
 
+        const METHOD: http::Method = http::Method::PATCH;
         const PATH: &'static str = "channels";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserEditBroadcast];
@@ -324,13 +317,12 @@ pub mod modify_channel_information {
 
         let http_response = http::Response::builder().status(200).body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/channels?broadcaster_id=0"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/channels?broadcaster_id=0",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -371,7 +363,7 @@ pub mod modify_channel_information {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = start_commercial::StartCommercialRequest::new();
 /// let body = start_commercial::StartCommercialBody::builder()
 ///     .broadcaster_id("1234".to_string())
@@ -397,6 +389,7 @@ pub mod start_commercial {
     /// [`start-commercial`](https://dev.twitch.tv/docs/api/reference#start-commercial)
     #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct StartCommercialRequest {}
 
     impl Default for StartCommercialRequest {
@@ -420,7 +413,7 @@ pub mod start_commercial {
     ///
     /// [`start-commercial`](https://dev.twitch.tv/docs/api/reference#start-commercial)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct StartCommercial {
         /// Length of the triggered commercial
@@ -435,6 +428,7 @@ pub mod start_commercial {
         /// FIXME: Make non-vec
         type Response = Vec<StartCommercial>;
 
+        const METHOD: http::Method = http::Method::POST;
         const PATH: &'static str = "channels/commercial";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -464,12 +458,141 @@ pub mod start_commercial {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/channels/commercial?");
+
+        let res = dbg!(req.parse_response(&uri, http_response).unwrap());
+        assert_eq!(res.status, http::StatusCode::OK);
+    }
+
+    #[test]
+    fn test_request_accepted() {
+        use helix::*;
+        let req = StartCommercialRequest {};
+
+        let data = br#"{"data": [{"length": 60, "message": "", "retry_after": 480}]}"#.to_vec();
+        let http_response = http::Response::builder()
+            .status(http::StatusCode::ACCEPTED)
+            .body(data)
+            .unwrap();
+
         let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/channels/commercial?"
+        let res = req.parse_response(&uri, http_response).unwrap();
+        assert_eq!(res.status, http::StatusCode::ACCEPTED);
+    }
+}
+
+/// Gets a list of users who have editor permissions for a specific channel.
+/// [`get-channel-editors`](https://dev.twitch.tv/docs/api/reference#get-channel-editors)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetChannelEditorsRequest]
+///
+/// To use this endpoint, construct a [`GetChannelEditorsRequest`] with the [`GetChannelEditorsRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::channels::get_channel_editors;
+/// let request = get_channel_editors::GetChannelEditorsRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// ```
+///
+/// ## Response: [Editor]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, channels::get_channel_editors};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_channel_editors::GetChannelEditorsRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let response: Vec<get_channel_editors::Editor> = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_channel_editors {
+    use super::*;
+
+    /// Query Parameters for [Get Channel Editors](super::get_channel_editors)
+    ///
+    /// [`get-channel-editors`](https://dev.twitch.tv/docs/api/reference#get-channel-editors)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetChannelEditorsRequest {
+        /// Broadcaster’s user ID associated with the channel.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+    }
+
+    /// Return Values for [Get Channel Editors](super::get_channel_editors)
+    ///
+    /// [`get-channel-editors`](https://dev.twitch.tv/docs/api/reference#get-channel-editors)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct Editor {
+        /// User ID of the editor.
+        pub user_id: types::UserId,
+        /// Display name of the editor.
+        pub user_name: types::DisplayName,
+        /// Date and time the editor was given editor permissions.
+        pub created_at: types::Timestamp,
+    }
+
+    impl helix::Request for GetChannelEditorsRequest {
+        type Response = Vec<Editor>;
+
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "channels/editors";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadEditors];
+    }
+
+    impl helix::RequestGet for GetChannelEditorsRequest {}
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetChannelEditorsRequest::builder()
+            .broadcaster_id("44445592")
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+    "data": [
+        {
+            "user_id": "182891647",
+            "user_name": "mauerbac",
+            "created_at": "2019-02-15T21:19:50.380833Z"
+        },
+        {
+            "user_id": "135093069",
+            "user_name": "BlueLava",
+            "created_at": "2018-03-07T16:28:29.872937Z"
+        }
+    ]
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/channels/editors?broadcaster_id=44445592",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
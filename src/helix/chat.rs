@@ -0,0 +1,1318 @@
+//! Endpoints regarding chat
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, chat::GetUserChatColorRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetUserChatColorRequest::builder()
+//!     .user_id(vec!["1234".to_string()])
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+#[doc(inline)]
+pub use get_channel_emotes::GetChannelEmotesRequest;
+#[doc(inline)]
+pub use get_chatters::{Chatter, Chatters, GetChattersRequest};
+#[doc(inline)]
+pub use get_global_emotes::GetGlobalEmotesRequest;
+#[doc(inline)]
+pub use get_shared_chat_session::{GetSharedChatSessionRequest, Participant, SharedChatSession};
+#[doc(inline)]
+pub use get_user_chat_color::{GetUserChatColorRequest, UserChatColor};
+#[doc(inline)]
+pub use send_chat_announcement::{
+    SendChatAnnouncement, SendChatAnnouncementBody, SendChatAnnouncementRequest,
+};
+#[doc(inline)]
+pub use update_user_chat_color::{
+    UpdateUserChatColor, UpdateUserChatColorBody, UpdateUserChatColorRequest,
+};
+
+use crate::{helix, types};
+use serde::{Deserialize, Serialize};
+
+/// Emotes returned by [Get Channel Emotes](get_channel_emotes) and [Get Global Emotes](get_global_emotes)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Emotes {
+    /// The emotes
+    pub emotes: Vec<Emote>,
+    /// A templated URL for the emote images, see [`Emotes::url`]
+    pub template: String,
+}
+
+impl Emotes {
+    /// Get the image URL of `emote` from the [template](Emotes::template)
+    ///
+    /// `format`, `theme_mode` and `scale` should be one of the values listed on the emote, e.g. `"static"`, `"dark"` and `"1.0"`.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::chat::{Emote, Emotes};
+    /// # fn url(emotes: &Emotes, emote: &Emote) {
+    /// let url = emotes.url(emote, "static", "dark", "3.0");
+    /// # }
+    /// ```
+    pub fn url(&self, emote: &Emote, format: &str, theme_mode: &str, scale: &str) -> String {
+        self.template
+            .replace("{{id}}", &emote.id)
+            .replace("{{format}}", format)
+            .replace("{{theme_mode}}", theme_mode)
+            .replace("{{scale}}", scale)
+    }
+}
+
+/// An emote
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Emote {
+    /// An ID that identifies the emote.
+    pub id: String,
+    /// The name of the emote. This is the name that viewers type in the chat window to get the emote to appear.
+    pub name: String,
+    /// Static image URLs of the emote in small, medium and large sizes.
+    pub images: EmoteImages,
+    /// The subscriber tier at which the emote is unlocked. Only set for subscriber emotes of a channel.
+    #[serde(default)]
+    pub tier: Option<String>,
+    /// The type of emote, e.g. `bitstier`, `follower` or `subscriptions`. Only set for channel emotes.
+    #[serde(default)]
+    pub emote_type: Option<String>,
+    /// An ID that identifies the emote set that the emote belongs to. Only set for channel emotes.
+    #[serde(default)]
+    pub emote_set_id: Option<String>,
+    /// The formats that the emote is available in, `static` and, for animated emotes, `animated`.
+    pub format: Vec<String>,
+    /// The sizes that the emote is available in, e.g. `1.0`, `2.0` and `3.0`.
+    pub scale: Vec<String>,
+    /// The background themes that the emote is available in, `light` and `dark`.
+    pub theme_mode: Vec<String>,
+}
+
+/// Image URLs of an [`Emote`]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct EmoteImages {
+    /// A URL to the small version (28px x 28px) of the emote.
+    pub url_1x: String,
+    /// A URL to the medium version (56px x 56px) of the emote.
+    pub url_2x: String,
+    /// A URL to the large version (112px x 112px) of the emote.
+    pub url_4x: String,
+}
+
+/// Parse the response of an endpoint returning [`Emotes`], which has `template` next to `data`
+fn parse_emotes<R>(
+    request: R,
+    uri: &http::Uri,
+    response: http::Response<Vec<u8>>,
+) -> Result<helix::Response<R, Emotes>, helix::HelixRequestGetError>
+where
+    R: helix::Request<Response = Emotes>,
+{
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    struct InnerResponse {
+        data: Vec<Emote>,
+        template: String,
+    }
+
+    let (text, request_id) = helix::get_response_text(uri, &response)?;
+    let status = response.status();
+    let response: InnerResponse = helix::parse_json(text)?;
+    Ok(helix::Response {
+        data: Emotes {
+            emotes: response.data,
+            template: response.template,
+        },
+        pagination: <_>::default(),
+        request,
+        request_id,
+        status,
+        #[cfg(feature = "raw_json")]
+        raw: helix::raw_json(text),
+    })
+}
+
+/// Gets all emotes that the specified broadcaster created for their channel, e.g. to enforce emote-only rules.
+/// [`get-channel-emotes`](https://dev.twitch.tv/docs/api/reference#get-channel-emotes)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetChannelEmotesRequest]
+///
+/// To use this endpoint, construct a [`GetChannelEmotesRequest`] with the [`GetChannelEmotesRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::chat::get_channel_emotes;
+/// let request = get_channel_emotes::GetChannelEmotesRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// ```
+///
+/// ## Response: [Emotes]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, chat::{self, get_channel_emotes}};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_channel_emotes::GetChannelEmotesRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let response: chat::Emotes = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_channel_emotes {
+    use super::*;
+
+    /// Query Parameters for [Get Channel Emotes](super::get_channel_emotes)
+    ///
+    /// [`get-channel-emotes`](https://dev.twitch.tv/docs/api/reference#get-channel-emotes)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetChannelEmotesRequest {
+        /// An ID that identifies the broadcaster whose emotes you want to get.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+    }
+
+    impl helix::Request for GetChannelEmotesRequest {
+        type Response = Emotes;
+
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "chat/emotes";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    }
+
+    impl helix::RequestGet for GetChannelEmotesRequest {
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestGetError,
+        >
+        where
+            Self: Sized,
+        {
+            parse_emotes(self, uri, response)
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetChannelEmotesRequest::builder()
+            .broadcaster_id("141981764")
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "id": "304456832",
+      "name": "twitchdevPitchfork",
+      "images": {
+        "url_1x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/1.0",
+        "url_2x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/2.0",
+        "url_4x": "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/light/3.0"
+      },
+      "tier": "1000",
+      "emote_type": "subscriptions",
+      "emote_set_id": "301590448",
+      "format": [
+        "static"
+      ],
+      "scale": [
+        "1.0",
+        "2.0",
+        "3.0"
+      ],
+      "theme_mode": [
+        "light",
+        "dark"
+      ]
+    }
+  ],
+  "template": "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/chat/emotes?broadcaster_id=141981764",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap();
+        let emote = &res.data.emotes[0];
+        assert_eq!(emote.name, "twitchdevPitchfork");
+        assert_eq!(emote.tier.as_deref(), Some("1000"));
+        assert_eq!(
+            res.data.url(emote, "static", "dark", "3.0"),
+            "https://static-cdn.jtvnw.net/emoticons/v2/304456832/static/dark/3.0"
+        );
+    }
+}
+
+/// Gets the list of users that are connected to the broadcaster’s chat session.
+/// [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetChattersRequest]
+///
+/// To use this endpoint, construct a [`GetChattersRequest`] with the [`GetChattersRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::chat::get_chatters;
+/// let request = get_chatters::GetChattersRequest::builder()
+///     .broadcaster_id("1234")
+///     .moderator_id("5678")
+///     .build();
+/// ```
+///
+/// ## Response: [Chatters]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, chat::get_chatters};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_chatters::GetChattersRequest::builder()
+///     .broadcaster_id("1234")
+///     .moderator_id("5678")
+///     .build();
+/// let response: get_chatters::Chatters = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_chatters {
+    use super::*;
+
+    /// Query Parameters for [Get Chatters](super::get_chatters)
+    ///
+    /// [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetChattersRequest {
+        /// The ID of the broadcaster whose list of chatters you want to get.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// The ID of the broadcaster or one of the broadcaster’s moderators. Must match the User ID in the Bearer token.
+        ///
+        /// Defaults to [`broadcaster_id`](GetChattersRequest::broadcaster_id) when not set, for when the broadcaster is also the moderator.
+        #[builder(default, setter(into, strip_option))]
+        pub moderator_id: Option<types::UserId>,
+        /// The maximum number of items to return per page in the response. Maximum: 1000. Default: 100.
+        #[builder(default, setter(into))]
+        pub first: Option<usize>,
+        /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+        #[builder(default)]
+        pub after: Option<helix::Cursor>,
+    }
+
+    /// Return Values for [Get Chatters](super::get_chatters)
+    ///
+    /// [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub struct Chatters {
+        /// Users connected to the chat room, on this page
+        pub chatters: Vec<Chatter>,
+        /// The total number of users connected to the chat room. May differ from the number of chatters returned across all pages.
+        pub total: i64,
+    }
+
+    /// A user connected to the chat room
+    #[derive(PartialEq, Eq, Hash, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct Chatter {
+        /// The ID of a user that’s connected to the broadcaster’s chat room.
+        pub user_id: types::UserId,
+        /// The user’s login name.
+        pub user_login: types::UserName,
+        /// The user’s display name.
+        pub user_name: types::DisplayName,
+    }
+
+    impl helix::Request for GetChattersRequest {
+        type Response = Chatters;
+
+        const MAX_FIRST: usize = 1000;
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "chat/chatters";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ModeratorReadChatters];
+
+        fn query(&self) -> Result<String, helix::SerializeError> {
+            helix::query_with_default_moderator(self, |r| &mut r.moderator_id, &self.broadcaster_id)
+        }
+    }
+
+    impl helix::RequestGet for GetChattersRequest {
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestGetError,
+        >
+        where
+            Self: Sized,
+        {
+            #[derive(PartialEq, Deserialize, Debug, Clone)]
+            struct InnerResponse {
+                data: Vec<Chatter>,
+                #[serde(default)]
+                pagination: helix::Pagination,
+                total: i64,
+            }
+
+            let (text, request_id) = helix::get_response_text(uri, &response)?;
+            let status = response.status();
+            let response: InnerResponse = helix::parse_json(text)?;
+            Ok(helix::Response {
+                data: Chatters {
+                    chatters: response.data,
+                    total: response.total,
+                },
+                pagination: response.pagination,
+                request: self,
+                request_id,
+                status,
+                #[cfg(feature = "raw_json")]
+                raw: helix::raw_json(text),
+            })
+        }
+    }
+
+    impl helix::Paginated for GetChattersRequest {
+        fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetChattersRequest::builder()
+            .broadcaster_id("123456")
+            .moderator_id("654321")
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "user_id": "128393656",
+      "user_login": "smittysmithers",
+      "user_name": "smittysmithers"
+    }
+  ],
+  "pagination": {
+    "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
+  },
+  "total": 8
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/chat/chatters?broadcaster_id=123456&moderator_id=654321",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap();
+        assert_eq!(res.data.total, 8);
+        assert_eq!(res.data.chatters[0].user_login.as_str(), "smittysmithers");
+        assert_eq!(
+            res.pagination.cursor().map(Cursor::as_str),
+            Some("eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19")
+        );
+    }
+
+    #[test]
+    fn test_default_moderator_id() {
+        let req = GetChattersRequest::builder()
+            .broadcaster_id("123456")
+            .first(1000)
+            .build();
+        helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/chat/chatters?broadcaster_id=123456&moderator_id=123456&first=1000",
+        );
+    }
+}
+
+/// Gets all global emotes. Global emotes are Twitch-created emotes that users can use in any Twitch chat.
+/// [`get-global-emotes`](https://dev.twitch.tv/docs/api/reference#get-global-emotes)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetGlobalEmotesRequest]
+///
+/// To use this endpoint, construct a [`GetGlobalEmotesRequest`] with the [`GetGlobalEmotesRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::chat::get_global_emotes;
+/// let request = get_global_emotes::GetGlobalEmotesRequest::builder().build();
+/// ```
+///
+/// ## Response: [Emotes]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, chat::{self, get_global_emotes}};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_global_emotes::GetGlobalEmotesRequest::builder().build();
+/// let response: chat::Emotes = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_global_emotes {
+    use super::*;
+
+    /// Query Parameters for [Get Global Emotes](super::get_global_emotes)
+    ///
+    /// [`get-global-emotes`](https://dev.twitch.tv/docs/api/reference#get-global-emotes)
+    #[derive(
+        PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default,
+    )]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetGlobalEmotesRequest {}
+
+    impl helix::Request for GetGlobalEmotesRequest {
+        type Response = Emotes;
+
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "chat/emotes/global";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    }
+
+    impl helix::RequestGet for GetGlobalEmotesRequest {
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestGetError,
+        >
+        where
+            Self: Sized,
+        {
+            parse_emotes(self, uri, response)
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetGlobalEmotesRequest::builder().build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "id": "196892",
+      "name": "TwitchUnity",
+      "images": {
+        "url_1x": "https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/1.0",
+        "url_2x": "https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/2.0",
+        "url_4x": "https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/3.0"
+      },
+      "format": [
+        "static"
+      ],
+      "scale": [
+        "1.0",
+        "2.0",
+        "3.0"
+      ],
+      "theme_mode": [
+        "light",
+        "dark"
+      ]
+    }
+  ],
+  "template": "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}"
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/chat/emotes/global?");
+
+        let res = req.parse_response(&uri, http_response).unwrap();
+        let emote = &res.data.emotes[0];
+        assert_eq!(emote.name, "TwitchUnity");
+        assert_eq!(emote.tier, None);
+        assert_eq!(emote.theme_mode, vec!["light", "dark"]);
+    }
+}
+
+/// Retrieves the active shared chat session for a channel.
+/// [`get-shared-chat-session`](https://dev.twitch.tv/docs/api/reference#get-shared-chat-session)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetSharedChatSessionRequest]
+///
+/// To use this endpoint, construct a [`GetSharedChatSessionRequest`] with the [`GetSharedChatSessionRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::chat::get_shared_chat_session;
+/// let request = get_shared_chat_session::GetSharedChatSessionRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// ```
+///
+/// ## Response: [SharedChatSession]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, chat::get_shared_chat_session};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_shared_chat_session::GetSharedChatSessionRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let response: Vec<get_shared_chat_session::SharedChatSession> = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_shared_chat_session {
+    use super::*;
+
+    /// Query Parameters for [Get Shared Chat Session](super::get_shared_chat_session)
+    ///
+    /// [`get-shared-chat-session`](https://dev.twitch.tv/docs/api/reference#get-shared-chat-session)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetSharedChatSessionRequest {
+        /// The User ID of the channel broadcaster.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+    }
+
+    /// Return Values for [Get Shared Chat Session](super::get_shared_chat_session)
+    ///
+    /// Empty if the channel is not in a shared chat session.
+    ///
+    /// [`get-shared-chat-session`](https://dev.twitch.tv/docs/api/reference#get-shared-chat-session)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct SharedChatSession {
+        /// The unique identifier for the shared chat session.
+        pub session_id: String,
+        /// The User ID of the host channel.
+        pub host_broadcaster_id: types::UserId,
+        /// The list of participants in the session.
+        pub participants: Vec<Participant>,
+        /// The UTC date and time (in RFC3339 format) for when the session was created.
+        pub created_at: types::Timestamp,
+        /// The UTC date and time (in RFC3339 format) for when the session was last updated.
+        pub updated_at: types::Timestamp,
+    }
+
+    /// A channel participating in a [`SharedChatSession`]
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct Participant {
+        /// The User ID of the participant channel.
+        pub broadcaster_id: types::UserId,
+    }
+
+    impl helix::Request for GetSharedChatSessionRequest {
+        type Response = Vec<SharedChatSession>;
+
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "shared_chat/session";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    }
+
+    impl helix::RequestGet for GetSharedChatSessionRequest {}
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetSharedChatSessionRequest::builder()
+            .broadcaster_id("198704263")
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "session_id": "359bce59-fa6e-41ca-83b4-db3cc5e2a6d5",
+      "host_broadcaster_id": "198704263",
+      "participants": [
+        {
+          "broadcaster_id": "198704263"
+        },
+        {
+          "broadcaster_id": "487263401"
+        }
+      ],
+      "created_at": "2024-09-29T19:45:37.000Z",
+      "updated_at": "2024-09-29T19:45:37.000Z"
+    }
+  ]
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/shared_chat/session?broadcaster_id=198704263",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap();
+        let session = res.first().unwrap();
+        assert_eq!(session.host_broadcaster_id, "198704263");
+        assert_eq!(session.participants.len(), 2);
+    }
+}
+
+/// Gets the color used for the user’s name in chat.
+/// [`get-user-chat-color`](https://dev.twitch.tv/docs/api/reference#get-user-chat-color)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetUserChatColorRequest]
+///
+/// To use this endpoint, construct a [`GetUserChatColorRequest`] with the [`GetUserChatColorRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::chat::get_user_chat_color;
+/// let request = get_user_chat_color::GetUserChatColorRequest::builder()
+///     .user_id(vec!["1234".to_string()])
+///     .build();
+/// ```
+///
+/// ## Response: [UserChatColor]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, chat::get_user_chat_color};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_user_chat_color::GetUserChatColorRequest::builder()
+///     .user_id(vec!["1234".to_string()])
+///     .build();
+/// let response: Vec<get_user_chat_color::UserChatColor> = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_user_chat_color {
+    use super::*;
+
+    /// Query Parameters for [Get User Chat Color](super::get_user_chat_color)
+    ///
+    /// [`get-user-chat-color`](https://dev.twitch.tv/docs/api/reference#get-user-chat-color)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetUserChatColorRequest {
+        /// The ID of the user whose username color you want to get.
+        /// Format: Repeated Query Parameter, eg. /chat/color?user_id=1&user_id=2
+        /// Maximum: 100
+        #[builder(
+            default,
+            setter(transform = |user_id: impl IntoIterator<Item = impl Into<types::UserId>>| {
+                user_id.into_iter().map(Into::into).collect()
+            })
+        )]
+        #[serde(default)]
+        pub user_id: Vec<types::UserId>,
+    }
+
+    /// Return Values for [Get User Chat Color](super::get_user_chat_color)
+    ///
+    /// [`get-user-chat-color`](https://dev.twitch.tv/docs/api/reference#get-user-chat-color)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct UserChatColor {
+        /// The ID of the user.
+        pub user_id: types::UserId,
+        /// The user’s login name.
+        pub user_login: types::UserName,
+        /// The user’s display name.
+        pub user_name: types::DisplayName,
+        /// The color used for the user’s name in chat. `None` if the user has never set a color.
+        #[serde(deserialize_with = "empty_string_as_none")]
+        pub color: Option<types::ChatColor>,
+    }
+
+    fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<types::ChatColor>, D::Error>
+    where D: serde::Deserializer<'de> {
+        let s = String::deserialize(deserializer)?;
+        if s.is_empty() {
+            Ok(None)
+        } else {
+            s.parse().map(Some).map_err(serde::de::Error::custom)
+        }
+    }
+
+    impl helix::Request for GetUserChatColorRequest {
+        type Response = Vec<UserChatColor>;
+
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "chat/color";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    }
+
+    impl helix::RequestGet for GetUserChatColorRequest {}
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetUserChatColorRequest::builder()
+            .user_id(vec!["11111".to_string(), "44444".to_string()])
+            .build();
+
+        // From twitch docs
+        let data = br##"
+{
+  "data": [
+    {
+      "user_id": "11111",
+      "user_name": "SpeedySpeedster1",
+      "user_login": "speedyspeedster1",
+      "color": "#9146FF"
+    },
+    {
+      "user_id": "44444",
+      "user_name": "SpeedySpeedster2",
+      "user_login": "speedyspeedster2",
+      "color": ""
+    }
+  ]
+}
+"##
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/chat/color?user_id=11111&user_id=44444",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap().data;
+        assert_eq!(
+            res[0].color,
+            Some(types::ChatColor::Hex("#9146FF".to_string()))
+        );
+        assert_eq!(res[1].color, None);
+    }
+}
+
+/// Sends an announcement to the broadcaster’s chat room.
+/// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [SendChatAnnouncementRequest]
+///
+/// To use this endpoint, construct a [`SendChatAnnouncementRequest`] with the [`SendChatAnnouncementRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::chat::send_chat_announcement;
+/// let request = send_chat_announcement::SendChatAnnouncementRequest::builder()
+///     .broadcaster_id("1234")
+///     .moderator_id("5678")
+///     .build();
+/// ```
+///
+/// ## Body: [SendChatAnnouncementBody]
+///
+/// We also need to provide a body to the request containing the announcement.
+///
+/// ```
+/// # use twitch_api2::helix::chat::send_chat_announcement;
+/// # use twitch_api2::types::AnnouncementColor;
+/// let body = send_chat_announcement::SendChatAnnouncementBody::builder()
+///     .message("Hello chat!")
+///     .color(AnnouncementColor::Purple)
+///     .build();
+/// ```
+///
+/// ## Response: [SendChatAnnouncement]
+///
+/// Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, chat::send_chat_announcement};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = send_chat_announcement::SendChatAnnouncementRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let body = send_chat_announcement::SendChatAnnouncementBody::builder()
+///     .message("Hello chat!")
+///     .build();
+/// let response: send_chat_announcement::SendChatAnnouncement =
+///     client.req_post(request, body, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPost::parse_response())
+pub mod send_chat_announcement {
+    use super::*;
+    use std::convert::TryInto;
+
+    /// Longest announcement Twitch accepts, in characters
+    pub const MAX_ANNOUNCEMENT_LENGTH: usize = 500;
+
+    /// Query Parameters for [Send Chat Announcement](super::send_chat_announcement)
+    ///
+    /// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct SendChatAnnouncementRequest {
+        /// The ID of the broadcaster that owns the chat room to send the announcement to.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// The ID of the broadcaster or one of the broadcaster’s moderators. Must match the User ID in the Bearer token.
+        ///
+        /// Defaults to [`broadcaster_id`](SendChatAnnouncementRequest::broadcaster_id) when not set, for when the broadcaster is also the moderator.
+        #[builder(default, setter(into, strip_option))]
+        pub moderator_id: Option<types::UserId>,
+    }
+
+    /// Body Parameters for [Send Chat Announcement](super::send_chat_announcement)
+    ///
+    /// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct SendChatAnnouncementBody {
+        /// The announcement to make in the broadcaster’s chat room. Limited to [`MAX_ANNOUNCEMENT_LENGTH`] characters.
+        #[builder(setter(into))]
+        pub message: String,
+        /// The color used to highlight the announcement, [`Primary`](types::AnnouncementColor::Primary) by default.
+        #[builder(default, setter(into))]
+        #[serde(default)]
+        pub color: types::AnnouncementColor,
+    }
+
+    impl SendChatAnnouncementBody {
+        /// Check that the message is not longer than [`MAX_ANNOUNCEMENT_LENGTH`] and that Twitch accepts the color
+        pub fn validate(&self) -> Result<(), helix::RequestValidationError> {
+            let length = self.message.chars().count();
+            if length > MAX_ANNOUNCEMENT_LENGTH {
+                return Err(helix::RequestValidationError::MessageTooLong(
+                    length,
+                    MAX_ANNOUNCEMENT_LENGTH,
+                ));
+            }
+            self.color.validate().map_err(Into::into)
+        }
+    }
+
+    /// Return Values for [Send Chat Announcement](super::send_chat_announcement)
+    ///
+    /// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum SendChatAnnouncement {
+        /// 204 - Successfully sent the announcement
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for SendChatAnnouncement {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT | http::StatusCode::OK => {
+                    Ok(SendChatAnnouncement::Success)
+                }
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    impl helix::Request for SendChatAnnouncementRequest {
+        type Response = SendChatAnnouncement;
+
+        const METHOD: http::Method = http::Method::POST;
+        const PATH: &'static str = "chat/announcements";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ModeratorManageAnnouncements];
+
+        fn query(&self) -> Result<String, helix::SerializeError> {
+            helix::query_with_default_moderator(self, |r| &mut r.moderator_id, &self.broadcaster_id)
+        }
+    }
+
+    impl helix::RequestPost for SendChatAnnouncementRequest {
+        type Body = SendChatAnnouncementBody;
+
+        fn validate_body(&self, body: &Self::Body) -> Result<(), helix::RequestValidationError> {
+            body.validate()
+        }
+
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestPostError,
+        >
+        where
+            Self: Sized,
+        {
+            #[cfg_attr(not(feature = "raw_json"), allow(unused_variables))]
+            let (text, request_id) = helix::post_response_text(uri, &response)?;
+            let data = response.status().try_into().map_err(|_| {
+                // This path should never be taken, but just to be sure we do this
+                helix::HelixRequestPostError::Error {
+                    status: response.status(),
+                    uri: uri.clone(),
+                    request_id: request_id.clone(),
+                    body: response.body().clone(),
+                    message: String::new(),
+                    error: String::new(),
+                }
+            })?;
+            Ok(helix::Response {
+                data,
+                pagination: <_>::default(),
+                request: self,
+                request_id,
+                status: response.status(),
+                #[cfg(feature = "raw_json")]
+                raw: helix::raw_json(text),
+            })
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = SendChatAnnouncementRequest::builder()
+            .broadcaster_id("11111")
+            .moderator_id("44444")
+            .build();
+
+        let body = SendChatAnnouncementBody::builder()
+            .message("Hello chat!")
+            .color(types::AnnouncementColor::Purple)
+            .build();
+        assert_eq!(
+            req.body(&body).unwrap(),
+            r#"{"message":"Hello chat!","color":"purple"}"#
+        );
+
+        // From twitch docs
+        let data = br#""#.to_vec();
+
+        let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/chat/announcements?broadcaster_id=11111&moderator_id=44444",
+        );
+
+        assert_eq!(
+            req.parse_response(&uri, http_response).unwrap().data,
+            SendChatAnnouncement::Success
+        );
+    }
+
+    #[test]
+    fn test_default_moderator_id() {
+        let req = SendChatAnnouncementRequest::builder()
+            .broadcaster_id("11111")
+            .build();
+        helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/chat/announcements?broadcaster_id=11111&moderator_id=11111",
+        );
+    }
+
+    #[test]
+    fn test_validate_body() {
+        use helix::*;
+        let req = SendChatAnnouncementRequest::builder()
+            .broadcaster_id("11111")
+            .build();
+
+        let body = SendChatAnnouncementBody::builder()
+            .message("Hello chat!")
+            .build();
+        assert_eq!(body.color, types::AnnouncementColor::Primary);
+        assert_eq!(
+            req.body(&body).unwrap(),
+            r#"{"message":"Hello chat!","color":"primary"}"#
+        );
+
+        let body = SendChatAnnouncementBody::builder()
+            .message("Hello chat!")
+            .color(types::AnnouncementColor::Unknown("gold".to_string()))
+            .build();
+        assert!(matches!(
+            req.create_request(body, "token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::InvalidAnnouncementColor(_)
+            ))
+        ));
+
+        let body = SendChatAnnouncementBody::builder()
+            .message("a".repeat(MAX_ANNOUNCEMENT_LENGTH + 1))
+            .build();
+        assert!(matches!(
+            req.create_request(body, "token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::MessageTooLong(501, 500)
+            ))
+        ));
+    }
+}
+
+/// Updates the color used for the user’s name in chat.
+/// [`update-user-chat-color`](https://dev.twitch.tv/docs/api/reference#update-user-chat-color)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [UpdateUserChatColorRequest]
+///
+/// To use this endpoint, construct a [`UpdateUserChatColorRequest`] with the [`UpdateUserChatColorRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::chat::update_user_chat_color;
+/// let request = update_user_chat_color::UpdateUserChatColorRequest::builder()
+///     .user_id("1234")
+///     .build();
+/// ```
+///
+/// ## Body: [UpdateUserChatColorBody]
+///
+/// We also need to provide a body to the request containing the new color.
+///
+/// ```
+/// # use twitch_api2::helix::chat::update_user_chat_color;
+/// # use twitch_api2::types::ChatColor;
+/// let body = update_user_chat_color::UpdateUserChatColorBody::builder()
+///     .color(ChatColor::hex("#9146FF").unwrap())
+///     .build();
+/// ```
+///
+/// ## Response: [UpdateUserChatColor]
+///
+///
+/// Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+///
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, chat::update_user_chat_color};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = update_user_chat_color::UpdateUserChatColorRequest::builder()
+///     .user_id("1234")
+///     .build();
+/// let body = update_user_chat_color::UpdateUserChatColorBody::builder()
+///     .color(twitch_api2::types::ChatColor::BlueViolet)
+///     .build();
+/// let response: update_user_chat_color::UpdateUserChatColor = client.req_put(request, body, &token).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPut::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPut::parse_response())
+pub mod update_user_chat_color {
+    use super::*;
+
+    /// Query Parameters for [Update User Chat Color](super::update_user_chat_color)
+    ///
+    /// [`update-user-chat-color`](https://dev.twitch.tv/docs/api/reference#update-user-chat-color)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct UpdateUserChatColorRequest {
+        /// The ID of the user whose chat color you want to update. Must match the User ID in the Bearer token.
+        #[builder(setter(into))]
+        pub user_id: types::UserId,
+    }
+
+    /// Body Parameters for [Update User Chat Color](super::update_user_chat_color)
+    ///
+    /// [`update-user-chat-color`](https://dev.twitch.tv/docs/api/reference#update-user-chat-color)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct UpdateUserChatColorBody {
+        /// The color to use for the user’s name in chat.
+        ///
+        /// [`ChatColor::Hex`](types::ChatColor::Hex) is only available to Turbo and Prime users.
+        #[builder(setter(into))]
+        pub color: types::ChatColor,
+    }
+
+    /// Return Values for [Update User Chat Color](super::update_user_chat_color)
+    ///
+    /// [`update-user-chat-color`](https://dev.twitch.tv/docs/api/reference#update-user-chat-color)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum UpdateUserChatColor {
+        /// 204 - Successfully updated the user’s chat color
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for UpdateUserChatColor {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT | http::StatusCode::OK => {
+                    Ok(UpdateUserChatColor::Success)
+                }
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    impl helix::Request for UpdateUserChatColorRequest {
+        type Response = UpdateUserChatColor;
+
+        const METHOD: http::Method = http::Method::PUT;
+        const PATH: &'static str = "chat/color";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserManageChatColor];
+    }
+
+    impl helix::RequestPut for UpdateUserChatColorRequest {
+        type Body = UpdateUserChatColorBody;
+
+        fn validate_body(&self, body: &Self::Body) -> Result<(), helix::RequestValidationError> {
+            body.color.validate().map_err(Into::into)
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = UpdateUserChatColorRequest::builder().user_id("123").build();
+
+        // From twitch docs
+        let data = br#""#.to_vec();
+
+        let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+        let uri =
+            helix::test::assert_uri(&req, "https://api.twitch.tv/helix/chat/color?user_id=123");
+
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_hex() {
+        use helix::*;
+        let req = UpdateUserChatColorRequest::builder().user_id("123").build();
+
+        assert!(types::ChatColor::hex("9146FF").is_err());
+        assert!(types::ChatColor::hex("#9146FG").is_err());
+
+        let body = UpdateUserChatColorBody::builder()
+            .color(types::ChatColor::Hex("not a color".to_string()))
+            .build();
+        assert!(matches!(
+            req.create_request(body, "token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::InvalidChatColor(_)
+            ))
+        ));
+    }
+}
@@ -7,7 +7,7 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetClipsRequest::builder()
@@ -53,7 +53,7 @@ use serde::{Deserialize, Serialize};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_clips::GetClipsRequest::builder()
 ///     .broadcaster_id("1234".to_string())
 ///     .build();
@@ -71,6 +71,7 @@ pub mod get_clips {
     /// [`get-clips`](https://dev.twitch.tv/docs/api/reference#get-clips)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetClipsRequest {
         /// ID of the broadcaster for whom clips are returned. The number of clips returned is determined by the first query-string parameter (default: 20). Results are ordered by view count.
         #[builder(default, setter(into))]
@@ -80,6 +81,7 @@ pub mod get_clips {
         pub game_id: Option<types::CategoryId>,
         /// ID of the clip being queried. Limit: 100.
         #[builder(default)]
+        #[serde(default)]
         pub id: Vec<String>,
         // one of above is needed.
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. This applies only to queries specifying broadcaster_id or game_id. The cursor value specified here is from the pagination response field of a prior query.
@@ -103,7 +105,7 @@ pub mod get_clips {
     ///
     /// [`get-clips`](https://dev.twitch.tv/docs/api/reference#get-clips)
     #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct Clip {
         /// User ID of the stream from which the clip was created.
@@ -139,6 +141,7 @@ pub mod get_clips {
     impl helix::Request for GetClipsRequest {
         type Response = Vec<Clip>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "clips";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -184,12 +187,11 @@ pub mod get_clips {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/clips?id=AwkwardHelplessSalamanderSwiftRage"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/clips?id=AwkwardHelplessSalamanderSwiftRage",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
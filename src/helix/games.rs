@@ -39,7 +39,7 @@ use serde::{Deserialize, Serialize};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_games::GetGamesRequest::builder()
 ///     .id(vec!["4321".to_string()])
 ///     .build();
@@ -58,12 +58,15 @@ pub mod get_games {
     /// [`get-games`](https://dev.twitch.tv/docs/api/reference#get-games)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetGamesRequest {
         /// Game ID. At most 100 id values can be specified.
         #[builder(default)]
+        #[serde(default)]
         pub id: Vec<types::CategoryId>,
         /// Game name. The name must be an exact match. For instance, “Pokemon” will not return a list of Pokemon games; instead, query the specific Pokemon game(s) in which you are interested. At most 100 name values can be specified.
         #[builder(default)]
+        #[serde(default)]
         pub name: Vec<String>,
     }
 
@@ -75,6 +78,7 @@ pub mod get_games {
     impl helix::Request for GetGamesRequest {
         type Response = Vec<Game>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "games";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -113,13 +117,9 @@ pub mod get_games {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/games?id=493057"
-        );
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/games?id=493057");
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -150,7 +150,7 @@ pub mod get_games {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_top_games::GetTopGamesRequest::builder()
 ///     .build();
 /// let response: Vec<get_top_games::Game> = client.req_get(request, &token).await?.data;
@@ -168,6 +168,7 @@ pub mod get_top_games {
     /// [`get-top-games`](https://dev.twitch.tv/docs/api/reference#get-top-games)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetTopGamesRequest {
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
         #[builder(default)]
@@ -189,6 +190,7 @@ pub mod get_top_games {
     impl helix::Request for GetTopGamesRequest {
         type Response = Vec<Game>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "games/top";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -227,9 +229,8 @@ pub mod get_top_games {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(uri.to_string(), "https://api.twitch.tv/helix/games/top?");
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/games/top?");
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
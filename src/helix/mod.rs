@@ -11,12 +11,12 @@
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //!
 //! let request = GetUsersRequest::builder()
-//!     .login(vec!["justintv123".to_string()])
+//!     .login(vec!["justintv123".into()])
 //!     .build();
 //!
 //! // Send it however you want
 //! // Create a [`http::Response<Vec<u8>>`] with RequestGet::create_request, which takes an access token and a client_id
-//! let response = send_http_request(request.create_request("accesstoken", "client_id")?)?;
+//! let response = send_http_request(request.create_request("accesstoken", &"client_id".into())?)?;
 //!
 //! // then parse the response
 //! let uri = request.get_uri()?;
@@ -34,19 +34,30 @@ use twitch_oauth2::TwitchToken;
 
 pub mod bits;
 pub mod channels;
+pub mod chat;
 pub mod clips;
 pub mod games;
 pub mod moderation;
+pub mod polls;
+pub mod predictions;
+pub mod raids;
+pub mod schedule;
 pub mod search;
 pub mod streams;
 pub mod subscriptions;
 pub mod tags;
 pub mod users;
 pub mod videos;
+pub mod whispers;
 
 pub(crate) mod ser;
 pub use ser::Error as SerializeError;
 
+#[cfg(feature = "tolerant")]
+mod tolerant;
+#[cfg(feature = "tolerant")]
+pub use tolerant::{ItemError, PartialResponse};
+
 #[doc(no_inline)]
 #[cfg(feature = "twitch_oauth2")]
 pub use twitch_oauth2::Scope;
@@ -68,15 +79,380 @@ pub use twitch_oauth2::Scope;
 /// ```
 ///
 /// See [`HttpClient`][crate::HttpClient] for implemented http clients, you can also define your own if needed.
+///
+/// Requests accept any [`TwitchToken`], so an [app access token](twitch_oauth2::AppAccessToken) can be used for endpoints that don't need [scopes](Request::SCOPE).
+/// Use [`dry_run`](RequestGet::dry_run) to check a request against the kind and scopes of a token without sending it.
+///
+/// Cloning a [`HelixClient`] is cheap, the underlying [`HttpClient`][crate::HttpClient] is shared between clones.
+/// To do requests in spawned tasks, give each task its own clone.
+/// The futures of requests are `Send` as long as the [`HttpClient`][crate::HttpClient] is `Sync` and the token is `Sync`,
+/// which is the case for the reqwest and surf clients and all tokens in [`twitch_oauth2`].
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// use twitch_api2::helix::{moderation::GetModeratorsRequest, HelixClient};
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let client: HelixClient<'static, twitch_api2::DummyHttpClient> = HelixClient::new();
+/// let token = std::sync::Arc::new(token);
+/// let mut tasks = vec![];
+/// for channel in vec!["1234", "5678"] {
+///     let client = client.clone();
+///     let token = token.clone();
+///     tasks.push(tokio::spawn(async move {
+///         let req = GetModeratorsRequest::builder()
+///             .broadcaster_id(channel)
+///             .build();
+///         client.req_get(req, &*token).await.map(|r| r.data)
+///     }));
+/// }
+/// for task in tasks {
+///     println!("{:?}", task.await?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[cfg(all(feature = "client"))]
 #[cfg_attr(nightly, doc(cfg(all(feature = "client", feature = "helix"))))]
-#[derive(Clone)]
 pub struct HelixClient<'a, C>
 where C: crate::HttpClient<'a> {
-    client: C,
+    client: std::sync::Arc<C>,
+    default_headers: std::sync::Arc<http::HeaderMap>,
+    timeout: Option<std::time::Duration>,
+    client_id: Option<crate::types::ClientId>,
+    base_url: Option<String>,
+    strict_scopes: bool,
+    interceptors: Vec<std::sync::Arc<dyn Interceptor>>,
+    retry_policy: Option<RetryPolicy>,
+    #[cfg(feature = "single_flight")]
+    in_flight: std::sync::Arc<std::sync::Mutex<InFlight>>,
     _pd: std::marker::PhantomData<&'a ()>, // TODO: Implement rate limiter...
 }
 
+/// Hooks run by [`HelixClient`] around every HTTP call, see [`HelixClient::with_interceptor`]
+///
+/// Both hooks do nothing by default, so implement only what you need.
+///
+/// ```rust
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use twitch_api2::helix::{HelixClient, Interceptor};
+///
+/// #[derive(Default)]
+/// struct CountRequests(AtomicUsize);
+///
+/// impl Interceptor for CountRequests {
+///     fn before_request(&self, _: &mut http::Request<Vec<u8>>) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let client: HelixClient<twitch_api2::DummyHttpClient> =
+///     HelixClient::new().with_interceptor(CountRequests::default());
+/// ```
+#[cfg(feature = "client")]
+pub trait Interceptor: Send + Sync {
+    /// Called with the request right before it is sent, after [default headers](HelixClient::with_default_headers) are applied
+    fn before_request(&self, _request: &mut http::Request<Vec<u8>>) {}
+    /// Called with the response as soon as it is received, before it is parsed
+    fn after_response(&self, _response: &http::Response<Vec<u8>>) {}
+}
+
+/// When and how often [`HelixClient`] retries requests that failed with a server error, see [`HelixClient::with_retry_policy`]
+///
+/// Requests are retried on `500`, `502`, `503` and `504`, with an exponential backoff and full jitter between attempts.
+/// Only `GET` requests are retried, unless [non-idempotent retries](RetryPolicy::with_retry_non_idempotent) are allowed.
+///
+/// ```rust
+/// # use twitch_api2::helix::{HelixClient, RetryPolicy};
+/// let policy = RetryPolicy::new(3).with_base_delay(std::time::Duration::from_millis(500));
+/// let client: HelixClient<twitch_api2::DummyHttpClient> =
+///     HelixClient::new().with_retry_policy(policy);
+/// ```
+#[cfg(feature = "client")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RetryPolicy {
+    /// Number of attempts in total, including the first one
+    pub max_attempts: u32,
+    /// Longest possible delay before the first retry, doubled for every retry after it
+    pub base_delay: std::time::Duration,
+    /// Upper bound for the delay between two attempts
+    pub max_delay: std::time::Duration,
+    /// Also retry `POST`, `PATCH`, `PUT` and `DELETE` requests, which can apply them twice
+    pub retry_non_idempotent: bool,
+}
+
+#[cfg(feature = "client")]
+impl RetryPolicy {
+    /// Statuses that are retried, Twitch sends these for transient errors
+    const RETRY_STATUSES: &'static [http::StatusCode] = &[
+        http::StatusCode::INTERNAL_SERVER_ERROR,
+        http::StatusCode::BAD_GATEWAY,
+        http::StatusCode::SERVICE_UNAVAILABLE,
+        http::StatusCode::GATEWAY_TIMEOUT,
+    ];
+
+    /// Make up to `max_attempts` attempts, with a base delay of 250 milliseconds and at most 10 seconds between attempts
+    pub fn new(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: std::time::Duration::from_millis(250),
+            max_delay: std::time::Duration::from_secs(10),
+            retry_non_idempotent: false,
+        }
+    }
+
+    /// Set the [base delay](RetryPolicy::base_delay)
+    pub fn with_base_delay(mut self, base_delay: std::time::Duration) -> RetryPolicy {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the [max delay](RetryPolicy::max_delay)
+    pub fn with_max_delay(mut self, max_delay: std::time::Duration) -> RetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set whether [non-idempotent requests are retried](RetryPolicy::retry_non_idempotent)
+    pub fn with_retry_non_idempotent(mut self, retry: bool) -> RetryPolicy {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Whether a `method` request can be retried after `attempt` attempts
+    fn allows(&self, method: &http::Method, attempt: u32) -> bool {
+        attempt < self.max_attempts && (self.retry_non_idempotent || method == http::Method::GET)
+    }
+
+    /// Whether a response with `status` should be retried
+    fn retries_status(&self, status: http::StatusCode) -> bool {
+        Self::RETRY_STATUSES.contains(&status)
+    }
+
+    /// Delay before retry number `retry`, a random duration up to the exponential backoff
+    fn delay(&self, retry: u32) -> std::time::Duration {
+        use std::hash::{BuildHasher, Hasher};
+
+        let backoff = self
+            .base_delay
+            .checked_mul(1 << retry.saturating_sub(1).min(31))
+            .map_or(self.max_delay, |backoff| backoff.min(self.max_delay));
+        // RandomState is seeded randomly, which is enough for jitter
+        let random = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        backoff.mul_f64(random as f64 / u64::MAX as f64)
+    }
+}
+
+/// Copy a request, to send it again when it is [retried](HelixClient::with_retry_policy)
+#[cfg(feature = "client")]
+fn clone_request(req: &http::Request<Vec<u8>>) -> http::Request<Vec<u8>> {
+    let mut clone = http::Request::new(req.body().clone());
+    *clone.method_mut() = req.method().clone();
+    *clone.uri_mut() = req.uri().clone();
+    *clone.version_mut() = req.version();
+    *clone.headers_mut() = req.headers().clone();
+    clone
+}
+
+/// GET requests currently being done by [`HelixClient::req_get_single_flight`], keyed by uri and token
+///
+/// Only the receiving end of the result is stored, the request itself is driven by the call that started it.
+/// Errors are type erased, as the error type of the [`HttpClient`][crate::HttpClient] may depend on the lifetime of the call.
+#[cfg(feature = "single_flight")]
+type InFlight = std::collections::HashMap<
+    (String, String),
+    futures::future::Shared<
+        futures::channel::oneshot::Receiver<
+            Result<SharedResponse, std::sync::Arc<dyn std::any::Any + Send + Sync>>,
+        >,
+    >,
+>;
+
+/// Removes a request from the [`InFlight`] map when the call that started it finishes or is dropped
+#[cfg(feature = "single_flight")]
+struct InFlightGuard<'m> {
+    in_flight: &'m std::sync::Mutex<InFlight>,
+    key: &'m (String, String),
+}
+
+#[cfg(feature = "single_flight")]
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut in_flight) = self.in_flight.lock() {
+            in_flight.remove(self.key);
+        }
+    }
+}
+
+/// A [`http::Response`] that can be cloned and handed to every awaiter of a coalesced request
+#[cfg(feature = "single_flight")]
+#[derive(Clone)]
+struct SharedResponse {
+    status: http::StatusCode,
+    version: http::Version,
+    headers: http::HeaderMap,
+    body: Vec<u8>,
+}
+
+#[cfg(feature = "single_flight")]
+impl From<http::Response<Vec<u8>>> for SharedResponse {
+    fn from(response: http::Response<Vec<u8>>) -> Self {
+        let (parts, body) = response.into_parts();
+        SharedResponse {
+            status: parts.status,
+            version: parts.version,
+            headers: parts.headers,
+            body,
+        }
+    }
+}
+
+#[cfg(feature = "single_flight")]
+impl From<SharedResponse> for http::Response<Vec<u8>> {
+    fn from(shared: SharedResponse) -> Self {
+        let mut response = http::Response::new(shared.body);
+        *response.status_mut() = shared.status;
+        *response.version_mut() = shared.version;
+        *response.headers_mut() = shared.headers;
+        response
+    }
+}
+
+#[cfg(feature = "client")]
+impl<'a, C: crate::HttpClient<'a>> Clone for HelixClient<'a, C> {
+    fn clone(&self) -> Self {
+        HelixClient {
+            client: self.client.clone(),
+            default_headers: self.default_headers.clone(),
+            timeout: self.timeout,
+            client_id: self.client_id.clone(),
+            base_url: self.base_url.clone(),
+            strict_scopes: self.strict_scopes,
+            interceptors: self.interceptors.clone(),
+            retry_policy: self.retry_policy.clone(),
+            #[cfg(feature = "single_flight")]
+            in_flight: self.in_flight.clone(),
+            _pd: std::marker::PhantomData::default(),
+        }
+    }
+}
+
+/// Deserialize a response body
+///
+/// Uses [`simd-json`](https://docs.rs/simd-json) if the `simd-json` feature is enabled, and `serde_json` otherwise.
+pub(crate) fn parse_json<T: serde::de::DeserializeOwned>(
+    text: &str,
+) -> Result<T, serde_json::Error> {
+    #[cfg(feature = "simd-json")]
+    {
+        // simd-json parses in place, so it needs its own copy of the body
+        let mut bytes = text.as_bytes().to_vec();
+        simd_json::serde::from_slice(&mut bytes)
+            .map_err(<serde_json::Error as serde::de::Error>::custom)
+    }
+    #[cfg(not(feature = "simd-json"))]
+    {
+        serde_json::from_str(text)
+    }
+}
+
+/// Count a HTTP call to the endpoint at `path` in the `twitch_api2_requests_total` counter of [`metrics`](https://docs.rs/metrics)
+///
+/// `status` is the status code of the response, or why there was no response.
+#[cfg(all(feature = "metrics", feature = "client"))]
+fn record_request(
+    path: &'static str,
+    method: &http::Method,
+    status: Result<http::StatusCode, &'static str>,
+) {
+    let status = match status {
+        Ok(status) => status.as_u16().to_string(),
+        Err(reason) => reason.to_string(),
+    };
+    metrics::counter!(
+        "twitch_api2_requests_total",
+        1,
+        "path" => path,
+        "method" => method.to_string(),
+        "status" => status
+    );
+}
+
+/// Start an [OpenTelemetry](https://docs.rs/opentelemetry) client span for a HTTP call to the endpoint at `path`
+#[cfg(all(feature = "opentelemetry", feature = "client"))]
+fn start_span(
+    path: &'static str,
+    req: &http::Request<Vec<u8>>,
+) -> opentelemetry::global::BoxedSpan {
+    use opentelemetry::trace::{SpanKind, Tracer};
+
+    let tracer = opentelemetry::global::tracer("twitch_api2");
+    tracer
+        .span_builder(&format!("{} {}", req.method(), path))
+        .with_kind(SpanKind::Client)
+        .with_attributes(vec![
+            opentelemetry::KeyValue::new("http.method", req.method().to_string()),
+            opentelemetry::KeyValue::new("http.url", span_url(req.uri())),
+        ])
+        .start(&tracer)
+}
+
+/// The `http.url` of a span, `uri` without its query so that no ids or cursors end up in traces
+#[cfg(all(feature = "opentelemetry", feature = "client"))]
+fn span_url(uri: &http::Uri) -> String {
+    format!(
+        "{}://{}{}",
+        uri.scheme_str().unwrap_or("https"),
+        uri.authority().map_or("", |authority| authority.as_str()),
+        uri.path()
+    )
+}
+
+/// Record the outcome of a HTTP call on its span and end it
+#[cfg(all(feature = "opentelemetry", feature = "client"))]
+fn end_span<E: std::fmt::Display>(
+    span: opentelemetry::global::BoxedSpan,
+    response: &Result<Result<http::Response<Vec<u8>>, E>, std::time::Duration>,
+) {
+    use opentelemetry::trace::{Span, StatusCode};
+
+    match response {
+        Ok(Ok(response)) => {
+            span.set_attribute(opentelemetry::KeyValue::new(
+                "http.status_code",
+                i64::from(response.status().as_u16()),
+            ));
+            if response.status().is_client_error() || response.status().is_server_error() {
+                span.set_status(StatusCode::Error, response.status().to_string());
+            }
+        }
+        Ok(Err(e)) => span.set_status(StatusCode::Error, e.to_string()),
+        Err(timeout) => {
+            span.set_status(StatusCode::Error, format!("timed out after {:?}", timeout))
+        }
+    }
+    span.end();
+}
+
+/// Keep the body of a response as JSON for [`Response::raw`]
+#[cfg(feature = "raw_json")]
+pub(crate) fn raw_json(text: &str) -> Option<serde_json::Value> { serde_json::from_str(text).ok() }
+
+/// Serialize the query of `request` with its `moderator_id` defaulting to `broadcaster_id`, for endpoints where the broadcaster moderates their own channel
+pub(crate) fn query_with_default_moderator<R: serde::Serialize + Clone>(
+    request: &R,
+    moderator_id: fn(&mut R) -> &mut Option<crate::types::UserId>,
+    broadcaster_id: &crate::types::UserId,
+) -> Result<String, SerializeError> {
+    let mut request = request.clone();
+    moderator_id(&mut request).get_or_insert_with(|| broadcaster_id.clone());
+    ser::to_string(&request)
+}
+
 #[derive(PartialEq, Deserialize, Debug)]
 struct InnerResponse<D> {
     data: D,
@@ -84,19 +460,43 @@ struct InnerResponse<D> {
     #[serde(default)]
     pagination: Pagination,
 }
-#[derive(Deserialize, Clone, Debug)]
-struct HelixRequestError {
-    error: String,
-    status: u16,
-    message: String,
+
+/// Error returned by Twitch when a request fails, e.g. `{"error": "Unauthorized", "status": 401, "message": "Invalid OAuth token"}`
+///
+/// Displayed as `401 Unauthorized: Invalid OAuth token`
+#[derive(Deserialize, serde::Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct TwitchErrorResponse {
+    /// Error message related to status code
+    pub error: String,
+    /// Status code of error, usually 400-499
+    pub status: u16,
+    /// Error message from Twitch
+    pub message: String,
+}
+
+impl std::fmt::Display for TwitchErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}: {}", self.status, self.error, self.message)
+    }
 }
 
+impl std::error::Error for TwitchErrorResponse {}
+
 #[cfg(feature = "client")]
 impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     /// Create a new client with an existing client
     pub fn with_client(client: C) -> HelixClient<'a, C> {
         HelixClient {
-            client,
+            client: std::sync::Arc::new(client),
+            default_headers: std::sync::Arc::new(http::HeaderMap::new()),
+            timeout: None,
+            client_id: None,
+            base_url: None,
+            strict_scopes: false,
+            interceptors: vec![],
+            retry_policy: None,
+            #[cfg(feature = "single_flight")]
+            in_flight: Default::default(),
             _pd: std::marker::PhantomData::default(),
         }
     }
@@ -108,10 +508,325 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         HelixClient::with_client(client)
     }
 
+    /// Create a new [`HelixClient`] with a default [`HttpClient`][crate::HttpClient], configured from the environment
+    ///
+    /// The [client id](HelixClient::with_client_id) is read from `TWITCH_CLIENT_ID`
+    /// and the [base url](HelixClient::with_base_url) from `TWITCH_API_BASE`, e.g. to use a local mock server.
+    /// Variables that are not set or empty are ignored.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::HelixClient;
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> = HelixClient::from_env();
+    /// ```
+    pub fn from_env() -> HelixClient<'a, C>
+    where C: Default {
+        HelixClient::new().configure_from(|name| std::env::var(name).ok())
+    }
+
+    /// Apply the configuration of [`HelixClient::from_env`], looking up variables with `var`
+    fn configure_from(mut self, var: impl Fn(&str) -> Option<String>) -> HelixClient<'a, C> {
+        let get = |name: &str| var(name).filter(|value| !value.is_empty());
+        if let Some(client_id) = get("TWITCH_CLIENT_ID") {
+            self = self.with_client_id(client_id);
+        }
+        if let Some(base_url) = get("TWITCH_API_BASE") {
+            self = self.with_base_url(base_url);
+        }
+        self
+    }
+
     /// Retrieve a clone of the [`HttpClient`][crate::HttpClient] inside this [`HelixClient`]
     pub fn clone_client(&self) -> C
     where C: Clone {
-        self.client.clone()
+        (*self.client).clone()
+    }
+
+    /// Set headers to be sent with every request, e.g. a custom `User-Agent`
+    ///
+    /// Headers already set on the request, like `Authorization` and `Client-ID`, are never overwritten.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::HelixClient;
+    /// let mut headers = http::HeaderMap::new();
+    /// headers.insert(http::header::USER_AGENT, "my-bot/1.0".parse().unwrap());
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> =
+    ///     HelixClient::new().with_default_headers(headers);
+    /// ```
+    pub fn with_default_headers(mut self, headers: http::HeaderMap) -> HelixClient<'a, C> {
+        self.default_headers = std::sync::Arc::new(headers);
+        self
+    }
+
+    /// Headers sent with every request
+    pub fn default_headers(&self) -> &http::HeaderMap { &self.default_headers }
+
+    /// Mutable access to the headers sent with every request
+    pub fn default_headers_mut(&mut self) -> &mut http::HeaderMap {
+        std::sync::Arc::make_mut(&mut self.default_headers)
+    }
+
+    /// Fail requests that take longer than `timeout` with [`ClientRequestError::Timeout`]
+    ///
+    /// The timeout applies to each HTTP call on its own, so a [paginated](HelixClient::paginate) request can take longer in total.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::HelixClient;
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> =
+    ///     HelixClient::new().with_timeout(std::time::Duration::from_secs(10));
+    /// ```
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> HelixClient<'a, C> {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The [timeout](HelixClient::with_timeout) for requests, if any
+    pub fn timeout(&self) -> Option<std::time::Duration> { self.timeout }
+
+    /// Send `client_id` as the `Client-Id` of requests
+    ///
+    /// Useful for tokens that don't know the client id they were minted for.
+    /// If a token does know its client id and it is not `client_id`, requests fail with [`CreateRequestError::ClientIdMismatch`]
+    /// instead of getting a `401 Unauthorized` from Twitch.
+    pub fn with_client_id(
+        mut self,
+        client_id: impl Into<crate::types::ClientId>,
+    ) -> HelixClient<'a, C> {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Send requests to `base_url` instead of [`TWITCH_HELIX_URL`](crate::TWITCH_HELIX_URL), e.g. a reverse proxy or a local mock server
+    ///
+    /// The path of the endpoint is appended to `base_url`. Any scheme can be used, so a plain `http://` proxy works.
+    /// To route requests through a forward proxy instead, configure it on the [`HttpClient`][crate::HttpClient],
+    /// e.g. with [`reqwest::Proxy`](https://docs.rs/reqwest/*/reqwest/struct.Proxy.html), and pass that client to [`HelixClient::with_client`].
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::HelixClient;
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> =
+    ///     HelixClient::new().with_base_url("http://localhost:8080/mock");
+    /// assert_eq!(client.base_url(), "http://localhost:8080/mock/");
+    /// ```
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> HelixClient<'a, C> {
+        let mut base_url = base_url.into();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        self.base_url = Some(base_url);
+        self
+    }
+
+    /// The url requests are sent to, [`TWITCH_HELIX_URL`](crate::TWITCH_HELIX_URL) unless [changed](HelixClient::with_base_url)
+    pub fn base_url(&self) -> &str { self.base_url.as_deref().unwrap_or(crate::TWITCH_HELIX_URL) }
+
+    /// Refuse to send requests that the token is missing [scopes](Request::SCOPE) for, instead of letting Twitch reject them
+    ///
+    /// Requests are then failed with [`RequestValidationError::ScopeError`] before anything is sent. Off by default.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::HelixClient;
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> =
+    ///     HelixClient::new().with_strict_scopes(true);
+    /// assert!(client.strict_scopes());
+    /// ```
+    pub fn with_strict_scopes(mut self, strict: bool) -> HelixClient<'a, C> {
+        self.strict_scopes = strict;
+        self
+    }
+
+    /// Whether [strict scopes](HelixClient::with_strict_scopes) are enabled
+    pub fn strict_scopes(&self) -> bool { self.strict_scopes }
+
+    /// Run `interceptor` around every HTTP call made by this client
+    ///
+    /// Interceptors compose: [`before_request`](Interceptor::before_request) hooks run in the order the interceptors were added,
+    /// and [`after_response`](Interceptor::after_response) hooks in the reverse order.
+    pub fn with_interceptor(
+        mut self,
+        interceptor: impl Interceptor + 'static,
+    ) -> HelixClient<'a, C> {
+        self.interceptors.push(std::sync::Arc::new(interceptor));
+        self
+    }
+
+    /// Retry requests that failed with a server error according to `policy`
+    ///
+    /// Requests coalesced by [`req_get_single_flight`](HelixClient::req_get_single_flight) are retried once for all waiters,
+    /// so every waiter gets the response of the last attempt.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> HelixClient<'a, C> {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// The [retry policy](HelixClient::with_retry_policy) of this client, if any
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> { self.retry_policy.as_ref() }
+
+    /// Run the [`before_request`](Interceptor::before_request) hooks of all interceptors
+    fn before_request(&self, req: &mut http::Request<Vec<u8>>) {
+        for interceptor in &self.interceptors {
+            interceptor.before_request(req);
+        }
+    }
+
+    /// Run the [`after_response`](Interceptor::after_response) hooks of all interceptors
+    fn after_response(&self, response: &http::Response<Vec<u8>>) {
+        for interceptor in self.interceptors.iter().rev() {
+            interceptor.after_response(response);
+        }
+    }
+
+    /// The `Client-Id` to send with requests made with `token`, see [`HelixClient::with_client_id`]
+    fn client_id_for<T: TwitchToken + ?Sized>(
+        &self,
+        token: &T,
+    ) -> Result<crate::types::ClientId, CreateRequestError> {
+        let token_client_id = token.client_id().as_str();
+        match &self.client_id {
+            None => Ok(token_client_id.into()),
+            Some(client_id) if token_client_id.is_empty() => Ok(client_id.clone()),
+            Some(client_id) if client_id == token_client_id => Ok(client_id.clone()),
+            Some(client_id) => Err(CreateRequestError::ClientIdMismatch {
+                client_id: client_id.as_str().to_string(),
+                token_client_id: token_client_id.to_string(),
+            }),
+        }
+    }
+
+    /// Check that `token` has the [scopes](Request::scopes) needed by `request`, if [strict scopes](HelixClient::with_strict_scopes) are enabled
+    fn check_strict_scopes<R: Request, T: TwitchToken + ?Sized>(
+        &self,
+        request: &R,
+        token: &T,
+    ) -> Result<(), CreateRequestError> {
+        if self.strict_scopes {
+            check_scopes(request.scopes(), token.scopes()).map_err(RequestValidationError::from)?;
+        }
+        Ok(())
+    }
+
+    /// Wait for `future`, or until the [timeout](HelixClient::with_timeout) passes
+    async fn timed<F: std::future::Future>(
+        &self,
+        future: F,
+    ) -> Result<F::Output, std::time::Duration> {
+        use futures::future::{select, Either};
+
+        match self.timeout {
+            Some(timeout) => {
+                match select(Box::pin(future), futures_timer::Delay::new(timeout)).await {
+                    Either::Left((output, _)) => Ok(output),
+                    Either::Right(_) => Err(timeout),
+                }
+            }
+            None => Ok(future.await),
+        }
+    }
+}
+
+#[cfg(feature = "client")]
+impl<'a, C> HelixClient<'a, C>
+where C: for<'c> crate::HttpClient<'c>
+{
+    /// Send `req` with the [`HttpClient`][crate::HttpClient], respecting the [timeout](HelixClient::with_timeout) and [retry policy](HelixClient::with_retry_policy) and running [interceptors](HelixClient::with_interceptor)
+    ///
+    /// `path` is the [path](Request::PATH) of the endpoint, used as a label for metrics.
+    async fn send<'c>(
+        &'c self,
+        path: &'static str,
+        mut req: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    {
+        self.before_request(&mut req);
+        let response = self
+            .call_retrying(path, req)
+            .await
+            .map_err(ClientRequestError::Timeout)?
+            .map_err(|e| ClientRequestError::RequestError(std::sync::Arc::new(e)))?;
+        self.after_response(&response);
+        Ok(response)
+    }
+
+    /// Call the [`HttpClient`][crate::HttpClient] until the response is not one the [retry policy](HelixClient::with_retry_policy) retries
+    async fn call_retrying<'c>(
+        &'c self,
+        path: &'static str,
+        req: http::Request<Vec<u8>>,
+    ) -> Result<
+        Result<http::Response<Vec<u8>>, <C as crate::HttpClient<'c>>::Error>,
+        std::time::Duration,
+    > {
+        let mut attempt = 1;
+        loop {
+            let policy = match &self.retry_policy {
+                Some(policy) if policy.allows(req.method(), attempt) => policy,
+                _ => return self.call(path, req).await,
+            };
+            let response = self.call(path, clone_request(&req)).await;
+            match &response {
+                Ok(Ok(response)) if policy.retries_status(response.status()) => {}
+                _ => return response,
+            }
+            futures_timer::Delay::new(policy.delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Do a single HTTP call with the [`HttpClient`][crate::HttpClient], respecting the [timeout](HelixClient::with_timeout)
+    async fn call<'c>(
+        &'c self,
+        #[cfg_attr(
+            not(any(feature = "metrics", feature = "opentelemetry")),
+            allow(unused_variables)
+        )]
+        path: &'static str,
+        req: http::Request<Vec<u8>>,
+    ) -> Result<
+        Result<http::Response<Vec<u8>>, <C as crate::HttpClient<'c>>::Error>,
+        std::time::Duration,
+    > {
+        #[cfg(feature = "metrics")]
+        let method = req.method().clone();
+        #[cfg(feature = "opentelemetry")]
+        let span = start_span(path, &req);
+        let response = self.timed(self.client.req(req)).await;
+        #[cfg(feature = "opentelemetry")]
+        end_span(span, &response);
+        #[cfg(feature = "metrics")]
+        record_request(
+            path,
+            &method,
+            match &response {
+                Ok(Ok(response)) => Ok(response.status()),
+                Ok(Err(_)) => Err("error"),
+                Err(_) => Err("timeout"),
+            },
+        );
+        response
+    }
+
+    /// Point `req` at the [base url](HelixClient::with_base_url) of this client
+    fn apply_base_url(&self, req: &mut http::Request<Vec<u8>>) -> Result<(), CreateRequestError> {
+        if let Some(base_url) = &self.base_url {
+            let uri = req.uri().to_string();
+            let path = uri.strip_prefix(crate::TWITCH_HELIX_URL).unwrap_or(&uri);
+            *req.uri_mut() = format!("{}{}", base_url, path)
+                .parse()
+                .map_err(InvalidUri::from)?;
+        }
+        Ok(())
+    }
+
+    /// Add [default headers](HelixClient::default_headers) that are not already set on the request
+    fn apply_default_headers(&self, req: &mut http::Request<Vec<u8>>) {
+        let headers = req.headers_mut();
+        for name in self.default_headers.keys() {
+            if headers.contains_key(name) {
+                continue;
+            }
+            for value in self.default_headers.get_all(name) {
+                headers.append(name.clone(), value.clone());
+            }
+        }
     }
 
     /// Request on a valid [`RequestGet`] endpoint
@@ -122,7 +837,8 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     /// #   use twitch_api2::helix::{HelixClient, channels};
     /// #   let token = Box::new(twitch_oauth2::UserToken::from_existing_unchecked(
     /// #       twitch_oauth2::AccessToken::new("totallyvalidtoken".to_string()), None,
-    /// #       twitch_oauth2::ClientId::new("validclientid".to_string()), None, None));
+    /// #       twitch_oauth2::ClientId::new("validclientid".to_string()), None,
+    /// #       "justinfan".into(), "1234".into(), None, None));
     ///     let req = channels::GetChannelInformationRequest::builder().broadcaster_id("123456").build();
     ///     let client = HelixClient::new();
     /// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
@@ -131,82 +847,437 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     /// # }
     /// # // fn main() {run()}
     /// ```
-    pub async fn req_get<R, D, T>(
-        &'a self,
+    pub async fn req_get<'c, R, D, T>(
+        &'c self,
         request: R,
         token: &T,
-    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
     where
         R: Request<Response = D> + Request + RequestGet,
         D: serde::de::DeserializeOwned + PartialEq,
         T: TwitchToken + ?Sized,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.check_strict_scopes(&request, token)?;
+        let mut req =
+            request.create_request(token.token().secret(), &self.client_id_for(token)?)?;
+        self.apply_default_headers(&mut req);
+        self.apply_base_url(&mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send(R::PATH, req).await?;
         request.parse_response(&uri, response).map_err(Into::into)
     }
 
-    /// Request on a valid [`RequestPost`] endpoint
-    pub async fn req_post<R, B, D, T>(
-        &'a self,
+    /// Request on a valid [`RequestGet`] endpoint, coalescing identical requests that are already in flight
+    ///
+    /// If a request with the same uri and token is currently being done by this client (or any of its clones),
+    /// no new HTTP call is made and the response of the in-flight request is used instead.
+    /// This reduces rate-limit pressure when many tasks ask for the same resource at the same time.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::{HelixClient, moderation};
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> = HelixClient::new();
+    /// let req = || moderation::GetModeratorsRequest::builder().broadcaster_id("1234").build();
+    /// // Only one HTTP call is made
+    /// let (first, second) = futures::join!(
+    ///     client.req_get_single_flight(req(), &token),
+    ///     client.req_get_single_flight(req(), &token),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "single_flight")]
+    #[cfg_attr(nightly, doc(cfg(feature = "single_flight")))]
+    pub async fn req_get_single_flight<'c, R, D, T>(
+        &'c self,
         request: R,
-        body: B,
         token: &T,
-    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
     where
-        R: Request<Response = D> + Request + RequestPost<Body = B>,
-        B: serde::Serialize,
+        R: Request<Response = D> + Request + RequestGet,
         D: serde::de::DeserializeOwned + PartialEq,
         T: TwitchToken + ?Sized,
     {
-        let req =
-            request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        use futures::FutureExt;
+
+        self.check_strict_scopes(&request, token)?;
+        let mut req =
+            request.create_request(token.token().secret(), &self.client_id_for(token)?)?;
+        self.apply_default_headers(&mut req);
+        self.apply_base_url(&mut req)?;
+        self.before_request(&mut req);
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
-        request.parse_response(&uri, response).map_err(Into::into)
+        let key = (uri.to_string(), token.token().secret().to_string());
+        let leader = {
+            let mut in_flight = self
+                .in_flight
+                .lock()
+                .expect("in-flight request map was poisoned");
+            match in_flight.get(&key) {
+                Some(receiver) => Err(receiver.clone()),
+                None => {
+                    let (sender, receiver) = futures::channel::oneshot::channel();
+                    in_flight.insert(key.clone(), receiver.shared());
+                    Ok(sender)
+                }
+            }
+        };
+        let response = match leader {
+            Ok(sender) => {
+                let _guard = InFlightGuard {
+                    in_flight: &self.in_flight,
+                    key: &key,
+                };
+                let response = self
+                    .req_shared(R::PATH, req)
+                    .await
+                    .map_err(ClientRequestError::Timeout)?;
+                let _ = sender.send(response.clone().map_err(|e| e as _));
+                response
+            }
+            Err(receiver) => match self
+                .timed(receiver)
+                .await
+                .map_err(ClientRequestError::Timeout)?
+            {
+                Ok(Ok(response)) => Ok(response),
+                Ok(Err(e)) => match e.downcast() {
+                    Ok(e) => Err(e),
+                    Err(_) => self
+                        .req_shared(R::PATH, req)
+                        .await
+                        .map_err(ClientRequestError::Timeout)?,
+                },
+                // The call doing the request was dropped before it finished, so do it here instead
+                Err(futures::channel::oneshot::Canceled) => self
+                    .req_shared(R::PATH, req)
+                    .await
+                    .map_err(ClientRequestError::Timeout)?,
+            },
+        }
+        .map_err(ClientRequestError::RequestError)?;
+        request
+            .parse_response(&uri, response.into())
+            .map_err(Into::into)
     }
 
-    /// Request on a valid [`RequestPatch`] endpoint
-    pub async fn req_patch<R, B, D, T>(
-        &'a self,
+    /// Send `req` with the [`HttpClient`][crate::HttpClient] for [`HelixClient::req_get_single_flight`], like [`send`](HelixClient::send) but keeping errors shareable
+    ///
+    /// `path` is the [path](Request::PATH) of the endpoint, used as a label for metrics.
+    #[cfg(feature = "single_flight")]
+    async fn req_shared<'c>(
+        &'c self,
+        path: &'static str,
+        req: http::Request<Vec<u8>>,
+    ) -> Result<
+        Result<SharedResponse, std::sync::Arc<<C as crate::HttpClient<'c>>::Error>>,
+        std::time::Duration,
+    > {
+        let response = self.call_retrying(path, req).await?;
+        if let Ok(response) = &response {
+            self.after_response(response);
+        }
+        Ok(response
+            .map(SharedResponse::from)
+            .map_err(std::sync::Arc::new))
+    }
+
+    /// Get all pages of a [`Paginated`] request as a stream
+    ///
+    /// Use [`PageStream::items`] to get a stream of the individual items instead.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// use futures::StreamExt;
+    /// use twitch_api2::helix::{moderation::GetBannedUsersRequest, HelixClient};
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> = HelixClient::new();
+    /// let req = GetBannedUsersRequest::builder().broadcaster_id("1234").build();
+    /// let mut banned = client.paginate(req, &token).items();
+    /// while let Some(user) = banned.next().await {
+    ///     println!("{}", user?.user_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate<'c, R, D, T>(
+        &'c self,
         request: R,
-        body: B,
-        token: &T,
-    ) -> Result<D, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+        token: &'c T,
+    ) -> PageStream<'c, R, D, <C as crate::HttpClient<'c>>::Error>
     where
-        R: Request<Response = D> + Request + RequestPatch<Body = B>,
-        B: serde::Serialize,
-        D: std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>
-            + serde::de::DeserializeOwned
-            + PartialEq,
-        T: TwitchToken + ?Sized,
+        C: Sync,
+        R: Request<Response = D> + Clone + Paginated + RequestGet + std::fmt::Debug + Send + 'c,
+        D: serde::de::DeserializeOwned + std::fmt::Debug + PartialEq + Clone + Send + 'c,
+        T: TwitchToken + Send + Sync + 'c,
     {
-        let req =
-            request.create_request(body, token.token().secret(), token.client_id().as_str())?;
-        let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
-        request.parse_response(&uri, response).map_err(Into::into)
+        use futures::StreamExt;
+
+        let stream = futures::stream::unfold(PageState::First(request), move |state| async move {
+            let page = match state {
+                PageState::First(request) => self.req_get(request, token).await.map(Some),
+                PageState::Next(previous) => previous.get_next(self, token).await,
+                PageState::Done => return None,
+            };
+            match page {
+                Ok(Some(page)) => {
+                    let previous = page.clone();
+                    Some((Ok(page), PageState::Next(previous)))
+                }
+                Ok(None) => None,
+                Err(e) => Some((Err(e), PageState::Done)),
+            }
+        });
+        PageStream {
+            stream: stream.boxed(),
+        }
+    }
+
+    /// Follow pagination of a [`Paginated`] request until an item matches `predicate`
+    ///
+    /// Returns the first matching item together with the cursor for the page after the one it was found on,
+    /// which is `None` if that was the last page. Returns `None` if no page had a matching item.
+    /// No more pages are requested after a match.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// use twitch_api2::helix::{moderation::GetBannedUsersRequest, HelixClient};
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> = HelixClient::new();
+    /// let req = GetBannedUsersRequest::builder().broadcaster_id("1234").build();
+    /// if let Some((ban, _next_cursor)) = client
+    ///     .req_get_until(req, &token, |ban| ban.user_id == "1234")
+    ///     .await?
+    /// {
+    ///     println!("{} is banned", ban.user_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn req_get_until<'c, R, I, T, F>(
+        &'c self,
+        request: R,
+        token: &T,
+        mut predicate: F,
+    ) -> Result<Option<(I, Option<Cursor>)>, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        R: Request<Response = Vec<I>> + Clone + Paginated + RequestGet + std::fmt::Debug,
+        I: serde::de::DeserializeOwned + std::fmt::Debug + PartialEq,
+        T: TwitchToken,
+        F: FnMut(&I) -> bool,
+    {
+        let mut response = self.req_get(request, token).await?;
+        loop {
+            if let Some(position) = response.data.iter().position(&mut predicate) {
+                let cursor = response.pagination.cursor.take();
+                return Ok(Some((response.data.swap_remove(position), cursor)));
+            }
+            match response.get_next(self, token).await? {
+                Some(next) => response = next,
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Request any `GET` endpoint and get the response as untyped JSON
+    ///
+    /// An escape hatch for endpoints that are not supported by this crate yet.
+    /// `path` is relative to the [base url](HelixClient::base_url), e.g. `schedule`, and `query` is sent in the given order.
+    /// Default headers, the timeout and interceptors of this client are used like for any other request.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::HelixClient;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// let client: HelixClient<twitch_api2::DummyHttpClient> = HelixClient::new();
+    /// let schedule = client
+    ///     .req_get_raw("schedule", &[("broadcaster_id", "1234")], &token)
+    ///     .await?;
+    /// println!("{}", schedule["data"]["segments"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn req_get_raw<'c, T>(
+        &'c self,
+        path: &str,
+        query: &[(&str, &str)],
+        token: &T,
+    ) -> Result<serde_json::Value, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(query)
+            .finish();
+        let uri: http::Uri = format!(
+            "{}{}?{}",
+            crate::TWITCH_HELIX_URL,
+            path.trim_start_matches('/'),
+            query
+        )
+        .parse()
+        .map_err(|e| CreateRequestError::from(InvalidUri::from(e)))?;
+
+        let mut req = build_request(
+            http::Method::GET,
+            uri,
+            token.token().secret(),
+            &self.client_id_for(token)?,
+            Vec::with_capacity(0),
+        )?;
+        self.apply_default_headers(&mut req);
+        self.apply_base_url(&mut req)?;
+        let uri = req.uri().clone();
+        let response = self.send("raw", req).await?;
+        let (text, _) = get_response_text(&uri, &response)?;
+        parse_json(text).map_err(|e| HelixRequestGetError::from(e).into())
+    }
+
+    /// Request on a valid [`RequestPost`] endpoint
+    pub async fn req_post<'c, R, B, D, T>(
+        &'c self,
+        request: R,
+        body: B,
+        token: &T,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        R: Request<Response = D> + Request + RequestPost<Body = B>,
+        B: serde::Serialize,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken + ?Sized,
+    {
+        self.check_strict_scopes(&request, token)?;
+        let mut req =
+            request.create_request(body, token.token().secret(), &self.client_id_for(token)?)?;
+        self.apply_default_headers(&mut req);
+        self.apply_base_url(&mut req)?;
+        let uri = req.uri().clone();
+        let response = self.send(R::PATH, req).await?;
+        request.parse_response(&uri, response).map_err(Into::into)
+    }
+
+    /// Request on a valid [`RequestPost`] endpoint with a body that was already serialized with [`RequestPost::body`]
+    ///
+    /// Sending the same body again, e.g. in a retry loop, does not serialize it again. See [`RequestPost::create_request_prebuilt`].
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::HelixClient;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// use twitch_api2::helix::{moderation, RequestPost};
+    /// let client = HelixClient::new();
+    /// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+    /// let request = moderation::CheckAutoModStatusRequest::builder()
+    ///     .broadcaster_id("1234")
+    ///     .build();
+    /// let body = request.body(&vec![moderation::CheckAutoModStatusBody::builder()
+    ///     .msg_id("123")
+    ///     .msg_text("hello")
+    ///     .user_id("5678")
+    ///     .build()])?;
+    /// let response = loop {
+    ///     match client
+    ///         .req_post_prebuilt(request.clone(), body.as_str().into(), &token)
+    ///         .await
+    ///     {
+    ///         Ok(response) => break response,
+    ///         Err(e) => println!("retrying after {}", e),
+    ///     }
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn req_post_prebuilt<'c, R, D, T>(
+        &'c self,
+        request: R,
+        body: std::borrow::Cow<'_, str>,
+        token: &T,
+    ) -> Result<Response<R, D>, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        R: Request<Response = D> + Request + RequestPost,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: TwitchToken + ?Sized,
+    {
+        self.check_strict_scopes(&request, token)?;
+        let mut req = request.create_request_prebuilt(
+            body,
+            token.token().secret(),
+            &self.client_id_for(token)?,
+        )?;
+        self.apply_default_headers(&mut req);
+        self.apply_base_url(&mut req)?;
+        let uri = req.uri().clone();
+        let response = self.send(R::PATH, req).await?;
+        request.parse_response(&uri, response).map_err(Into::into)
+    }
+
+    /// Request on a valid [`RequestPatch`] endpoint
+    pub async fn req_patch<'c, R, B, D, T>(
+        &'c self,
+        request: R,
+        body: B,
+        token: &T,
+    ) -> Result<D, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        R: Request<Response = D> + Request + RequestPatch<Body = B>,
+        B: serde::Serialize,
+        D: std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>
+            + serde::de::DeserializeOwned
+            + PartialEq,
+        T: TwitchToken + ?Sized,
+    {
+        self.check_strict_scopes(&request, token)?;
+        let mut req =
+            request.create_request(body, token.token().secret(), &self.client_id_for(token)?)?;
+        self.apply_default_headers(&mut req);
+        self.apply_base_url(&mut req)?;
+        let uri = req.uri().clone();
+        let response = self.send(R::PATH, req).await?;
+        request.parse_response(&uri, response).map_err(Into::into)
+    }
+
+    /// Request on a valid [`RequestPut`] endpoint
+    pub async fn req_put<'c, R, B, D, T>(
+        &'c self,
+        request: R,
+        body: B,
+        token: &T,
+    ) -> Result<D, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        R: Request<Response = D> + Request + RequestPut<Body = B>,
+        B: serde::Serialize,
+        D: std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>
+            + serde::de::DeserializeOwned
+            + PartialEq,
+        T: TwitchToken + ?Sized,
+    {
+        self.check_strict_scopes(&request, token)?;
+        let mut req =
+            request.create_request(body, token.token().secret(), &self.client_id_for(token)?)?;
+        self.apply_default_headers(&mut req);
+        self.apply_base_url(&mut req)?;
+        let uri = req.uri().clone();
+        let response = self.send(R::PATH, req).await?;
+        request.parse_response(&uri, response).map_err(Into::into)
     }
 
     /// Request on a valid [`RequestDelete`] endpoint
-    pub async fn req_delete<R, D, T>(
-        &'a self,
+    pub async fn req_delete<'c, R, D, T>(
+        &'c self,
         request: R,
         token: &T,
-    ) -> Result<D, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    ) -> Result<D, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
     where
         R: Request<Response = D> + Request + RequestDelete,
         D: std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>
@@ -214,13 +1285,13 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
             + PartialEq,
         T: TwitchToken + ?Sized,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.check_strict_scopes(&request, token)?;
+        let mut req =
+            request.create_request(token.token().secret(), &self.client_id_for(token)?)?;
+        self.apply_default_headers(&mut req);
+        self.apply_base_url(&mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let response = self.send(R::PATH, req).await?;
         request.parse_response(&uri, response).map_err(Into::into)
     }
 }
@@ -232,12 +1303,900 @@ where C: crate::HttpClient<'a> + Default
     fn default() -> HelixClient<'a, C> { HelixClient::new() }
 }
 
+/// Lets [`twitch_oauth2`] get, validate and refresh tokens with the HTTP client of a [`HelixClient`]
+///
+/// The requests are sent as is, without [default headers](HelixClient::with_default_headers), [interceptors](HelixClient::with_interceptor) or [retries](HelixClient::with_retry_policy).
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// use twitch_api2::helix::HelixClient;
+/// let client = HelixClient::new();
+/// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+/// let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "client")]
+impl<'a, C> twitch_oauth2::client::Client<'a> for HelixClient<'a, C>
+where C: crate::HttpClient<'a> + Sync
+{
+    type Error = C::Error;
+
+    fn req(
+        &'a self,
+        request: crate::client::Req,
+    ) -> crate::client::BoxedFuture<'a, Result<crate::client::Response, Self::Error>> {
+        self.client.req(request)
+    }
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_oauth_client() {
+    let client = HelixClient::with_client(test::MockClient::with_body(
+        r#"{"client_id":"clientid","login":"justinfan","scopes":["moderation:read"],"user_id":"1234","expires_in":3600}"#,
+    ));
+    let token = futures::executor::block_on(twitch_oauth2::UserToken::from_existing(
+        &client,
+        twitch_oauth2::AccessToken::new("token".to_string()),
+        None,
+        None,
+    ))
+    .unwrap();
+    assert_eq!(token.user_id().unwrap().as_str(), "1234");
+    assert_eq!(token.scopes(), &[twitch_oauth2::Scope::ModerationRead]);
+
+    let requests = client.client.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].uri(), "https://id.twitch.tv/oauth2/validate");
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_default_headers() {
+    let mut headers = http::HeaderMap::new();
+    headers.insert(http::header::USER_AGENT, "my-bot/1.0".parse().unwrap());
+    headers.insert("X-Correlation-Id", "abc".parse().unwrap());
+    headers.insert(http::header::AUTHORIZATION, "Bearer nope".parse().unwrap());
+    headers.insert("Client-ID", "nope".parse().unwrap());
+    let client: HelixClient<crate::DummyHttpClient> =
+        HelixClient::new().with_default_headers(headers);
+
+    let mut req = users::GetUsersRequest::builder()
+        .build()
+        .create_request("token", &"clientid".into())
+        .unwrap();
+    client.apply_default_headers(&mut req);
+
+    let headers = req.headers();
+    assert_eq!(headers[http::header::USER_AGENT], "my-bot/1.0");
+    assert_eq!(headers["X-Correlation-Id"], "abc");
+    assert_eq!(headers[http::header::AUTHORIZATION], "Bearer token");
+    assert_eq!(headers["Client-ID"], "clientid");
+    assert_eq!(
+        headers.get_all(http::header::AUTHORIZATION).iter().count(),
+        1
+    );
+}
+
+#[cfg(all(feature = "client", debug_assertions))]
+#[test]
+#[should_panic(expected = "wrong METHOD for test/wrong")]
+fn test_wrong_method() {
+    #[derive(serde::Serialize)]
+    struct WrongMethodRequest {}
+    impl Request for WrongMethodRequest {
+        type Response = Vec<()>;
+
+        const METHOD: http::Method = http::Method::POST;
+        const PATH: &'static str = "test/wrong";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    }
+    impl RequestGet for WrongMethodRequest {}
+
+    let _ = WrongMethodRequest {}.create_request("token", &"clientid".into());
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_strict_scopes() {
+    let token = test::token(Some(vec![twitch_oauth2::Scope::ModerationRead]));
+    let ban = moderation::BanUserRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let moderators = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let client: HelixClient<crate::DummyHttpClient> = HelixClient::new();
+    assert!(client.check_strict_scopes(&ban, &token).is_ok());
+
+    let client = client.with_strict_scopes(true);
+    assert!(client.check_strict_scopes(&moderators, &token).is_ok());
+    assert!(matches!(
+        client.check_strict_scopes(&ban, &token),
+        Err(CreateRequestError::ValidationError(
+            RequestValidationError::ScopeError(ScopeError::MissingScopes(_))
+        ))
+    ));
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_scope_override() {
+    let token = test::token(Some(vec![twitch_oauth2::Scope::ChannelModerate]));
+    let client: HelixClient<crate::DummyHttpClient> = HelixClient::new().with_strict_scopes(true);
+    let request = moderation::GetBannedUsersRequest::for_broadcaster("1234");
+    assert!(client.check_strict_scopes(&request, &token).is_err());
+    assert!(request.can_execute(&token).is_err());
+
+    let request = request.with_scope_override(twitch_oauth2::Scope::ChannelModerate);
+    assert_eq!(request.scopes(), &[twitch_oauth2::Scope::ChannelModerate]);
+    assert!(client.check_strict_scopes(&request, &token).is_ok());
+    assert!(request.can_execute(&token).is_ok());
+    assert_eq!(
+        request
+            .create_request("token", &"clientid".into())
+            .unwrap()
+            .uri()
+            .to_string(),
+        "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=1234"
+    );
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_scope_override_pagination() {
+    let token = test::token(Some(vec![twitch_oauth2::Scope::ChannelModerate]));
+    let client = HelixClient::with_client(test::MockClient::new(|n, _| {
+        Some(test::response(
+            200,
+            if n == 0 {
+                r#"{"data":[{"user_id":"1","user_login":"one","expires_at":""}],"pagination":{"cursor":"page2"}}"#
+            } else {
+                r#"{"data":[{"user_id":"2","user_login":"two","expires_at":""}],"pagination":{}}"#
+            },
+        ))
+    }))
+    .with_strict_scopes(true);
+    let request = moderation::GetBannedUsersRequest::for_broadcaster("1234")
+        .with_scope_override(twitch_oauth2::Scope::ChannelModerate);
+
+    let response = futures::executor::block_on(client.req_get(request, &token)).unwrap();
+    let next = futures::executor::block_on(response.get_next(&client, &token))
+        .unwrap()
+        .unwrap();
+    assert_eq!(next.data[0].user_id, "2");
+    // The cursor is set on the wrapped request, which keeps its scope
+    assert_eq!(
+        next.request.request.after.as_ref().unwrap().as_str(),
+        "page2"
+    );
+    assert_eq!(next.request.scope, twitch_oauth2::Scope::ChannelModerate);
+    assert_eq!(
+        client.client.requests()[1].uri(),
+        "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=1234&after=page2"
+    );
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_configure_from() {
+    let vars = |name: &str| match name {
+        "TWITCH_CLIENT_ID" => Some("clientid".to_string()),
+        "TWITCH_API_BASE" => Some("http://localhost:8080/mock".to_string()),
+        _ => None,
+    };
+    let client: HelixClient<crate::DummyHttpClient> = HelixClient::new().configure_from(vars);
+    assert_eq!(client.client_id.as_ref().unwrap(), "clientid");
+    assert_eq!(client.base_url(), "http://localhost:8080/mock/");
+
+    let client: HelixClient<crate::DummyHttpClient> =
+        HelixClient::new().configure_from(|_| Some(String::new()));
+    assert!(client.client_id.is_none());
+    assert_eq!(client.base_url(), crate::TWITCH_HELIX_URL);
+}
+
+#[cfg(all(feature = "opentelemetry", feature = "client"))]
+#[test]
+fn test_span_url() {
+    let uri: http::Uri = "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=1234"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        span_url(&uri),
+        "https://api.twitch.tv/helix/moderation/banned"
+    );
+    let uri: http::Uri = "http://localhost:8080/mock/users".parse().unwrap();
+    assert_eq!(span_url(&uri), "http://localhost:8080/mock/users");
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_base_url() {
+    let request = || {
+        moderation::GetModeratorsRequest::builder()
+            .broadcaster_id("1234")
+            .build()
+            .create_request("token", &"clientid".into())
+            .unwrap()
+    };
+    let client: HelixClient<crate::DummyHttpClient> = HelixClient::new();
+    let mut req = request();
+    client.apply_base_url(&mut req).unwrap();
+    assert_eq!(
+        req.uri().to_string(),
+        "https://api.twitch.tv/helix/moderation/moderators?broadcaster_id=1234"
+    );
+
+    let client = client.with_base_url("http://localhost:8080/proxy/helix");
+    let mut req = request();
+    client.apply_base_url(&mut req).unwrap();
+    assert_eq!(
+        req.uri().to_string(),
+        "http://localhost:8080/proxy/helix/moderation/moderators?broadcaster_id=1234"
+    );
+}
+
+#[cfg(feature = "single_flight")]
+#[test]
+fn test_single_flight() {
+    let token = test::token(None);
+    let client = HelixClient::with_client(test::MockClient::with_body(
+        r#"{"data":[{"user_id":"424596340","user_login":"quotrok","user_name":"quotrok"}],"pagination":{}}"#,
+    ));
+    let req = || {
+        moderation::GetModeratorsRequest::builder()
+            .broadcaster_id("198704263")
+            .build()
+    };
+
+    let (first, second) = futures::executor::block_on(async {
+        futures::join!(
+            client.req_get_single_flight(req(), &token),
+            client.req_get_single_flight(req(), &token),
+        )
+    });
+    assert_eq!(client.client.requests().len(), 1);
+    assert_eq!(first.unwrap().data, second.unwrap().data);
+
+    // Nothing is in flight anymore, so a new call is made
+    assert!(futures::executor::block_on(client.req_get_single_flight(req(), &token)).is_ok());
+    assert_eq!(client.client.requests().len(), 2);
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_req_get_until() {
+    let token = test::token(None);
+    let client = HelixClient::with_client(test::MockClient::new(|_, req| {
+        let body = if req
+            .uri()
+            .query()
+            .unwrap_or_default()
+            .contains("after=page2")
+        {
+            r#"{"data":[{"user_id":"2","user_login":"two","user_name":"Two"},{"user_id":"3","user_login":"three","user_name":"Three"}],"pagination":{"cursor":"page3"}}"#
+        } else {
+            r#"{"data":[{"user_id":"1","user_login":"one","user_name":"One"}],"pagination":{"cursor":"page2"}}"#
+        };
+        Some(test::response(200, body))
+    }));
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+
+    let (moderator, cursor) =
+        futures::executor::block_on(client.req_get_until(req, &token, |m| m.user_login == "three"))
+            .unwrap()
+            .unwrap();
+    assert_eq!(moderator.user_id, "3");
+    assert_eq!(cursor.unwrap().as_str(), "page3");
+    // The page with the match is the last one requested
+    assert_eq!(client.client.requests().len(), 2);
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_req_get_raw() {
+    let token = test::token(None);
+    let client = HelixClient::with_client(test::MockClient::new(|_, req| {
+        Some(if req.uri().path().ends_with("/schedule") {
+            test::response(200, &format!(r#"{{"data":{{"uri":"{}"}}}}"#, req.uri()))
+        } else {
+            test::response(
+                404,
+                r#"{"error":"Not Found","status":404,"message":"Not Found"}"#,
+            )
+        })
+    }))
+    .with_base_url("http://localhost:8080/mock");
+
+    let value = futures::executor::block_on(client.req_get_raw(
+        "schedule",
+        &[("broadcaster_id", "1234"), ("id", "a b"), ("id", "c")],
+        &token,
+    ))
+    .unwrap();
+    assert_eq!(
+        value["data"]["uri"],
+        "http://localhost:8080/mock/schedule?broadcaster_id=1234&id=a+b&id=c"
+    );
+
+    match futures::executor::block_on(client.req_get_raw("nothing", &[], &token)) {
+        Err(ClientRequestError::HelixRequestGetError(HelixRequestGetError::Error {
+            status,
+            ..
+        })) => assert_eq!(status, http::StatusCode::NOT_FOUND),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_retry_policy() {
+    // Fails with `503 Service Unavailable` until the third call
+    let flaky = || {
+        test::MockClient::new(|n, _| {
+            Some(if n < 2 {
+                test::response(503, r#"{"error":"Service Unavailable","status":503}"#)
+            } else {
+                test::response(200, r#"{"data":[]}"#)
+            })
+        })
+    };
+
+    let token = test::token(None);
+    let policy = RetryPolicy::new(3).with_base_delay(std::time::Duration::from_millis(1));
+    let client = HelixClient::with_client(flaky()).with_retry_policy(policy.clone());
+    let req = moderation::GetModeratorsRequest::for_broadcaster("1234");
+    assert!(futures::executor::block_on(client.req_get(req, &token)).is_ok());
+    assert_eq!(client.client.requests().len(), 3);
+
+    let client = HelixClient::with_client(flaky()).with_retry_policy(policy.clone());
+    let req = moderation::CheckAutoModStatusRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let body = moderation::CheckAutoModStatusBody::builder()
+        .msg_id("123")
+        .msg_text("hello")
+        .user_id("5678")
+        .build();
+    // POST is not retried
+    let response =
+        futures::executor::block_on(client.req_post(req.clone(), vec![body.clone()], &token));
+    assert!(response.is_err());
+    assert_eq!(client.client.requests().len(), 1);
+
+    let client = HelixClient::with_client(flaky())
+        .with_retry_policy(policy.clone().with_retry_non_idempotent(true));
+    assert!(
+        futures::executor::block_on(client.req_post(req.clone(), vec![body.clone()], &token))
+            .is_ok()
+    );
+    assert_eq!(client.client.requests().len(), 3);
+
+    // A prebuilt body is retried like any other
+    let client =
+        HelixClient::with_client(flaky()).with_retry_policy(policy.with_retry_non_idempotent(true));
+    let body = req.body(&vec![body]).unwrap();
+    assert!(
+        futures::executor::block_on(client.req_post_prebuilt(req, body.into(), &token)).is_ok()
+    );
+    assert_eq!(client.client.requests().len(), 3);
+}
+
+#[cfg(feature = "single_flight")]
+#[test]
+fn test_single_flight_retry_policy() {
+    let token = test::token(None);
+    let policy = RetryPolicy::new(3).with_base_delay(std::time::Duration::from_millis(1));
+    let client = HelixClient::with_client(test::MockClient::new(|n, _| {
+        Some(if n < 2 {
+            test::response(503, r#"{"error":"Service Unavailable","status":503}"#)
+        } else {
+            test::response(200, r#"{"data":[],"pagination":{}}"#)
+        })
+    }))
+    .with_retry_policy(policy);
+    let req = || moderation::GetModeratorsRequest::for_broadcaster("1234");
+
+    let (first, second) = futures::executor::block_on(async {
+        futures::join!(
+            client.req_get_single_flight(req(), &token),
+            client.req_get_single_flight(req(), &token),
+        )
+    });
+    assert!(first.is_ok());
+    assert!(second.is_ok());
+    // The leading call retried twice, the other one waited for it
+    assert_eq!(client.client.requests().len(), 3);
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_futures_are_send() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let token = test::token(None);
+    let client: HelixClient<crate::DummyHttpClient> = HelixClient::new();
+
+    let moderators = || moderation::GetModeratorsRequest::for_broadcaster("1234");
+    assert_send(&client.req_get(moderators(), &token));
+    #[cfg(feature = "single_flight")]
+    assert_send(&client.req_get_single_flight(moderators(), &token));
+    let banned = moderation::GetBannedUsersRequest::for_broadcaster("1234");
+    assert_send(&client.req_get_until(banned, &token, |ban| ban.user_id == "5678"));
+    assert_send(&client.req_get_raw("moderation/moderators", &[], &token));
+
+    let automod = moderation::CheckAutoModStatusRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    assert_send(&client.req_post(automod.clone(), vec![], &token));
+    assert_send(&client.req_post_prebuilt(automod, "[]".into(), &token));
+
+    let channel = channels::ModifyChannelInformationRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let body = channels::ModifyChannelInformationBody::builder()
+        .title("title".to_string())
+        .build();
+    assert_send(&client.req_patch(channel, body, &token));
+
+    let color = chat::UpdateUserChatColorRequest::builder()
+        .user_id("1234")
+        .build();
+    let body = chat::UpdateUserChatColorBody::builder()
+        .color(crate::types::ChatColor::Blue)
+        .build();
+    assert_send(&client.req_put(color, body, &token));
+
+    let unban = moderation::UnbanUserRequest::builder()
+        .broadcaster_id("1234")
+        .user_id("5678")
+        .build();
+    assert_send(&client.req_delete(unban, &token));
+}
+
+#[cfg(all(test, feature = "client"))]
+#[tokio::test]
+async fn test_spawn_requests() {
+    let token = std::sync::Arc::new(test::token(None));
+    let client: HelixClient<crate::DummyHttpClient> = HelixClient::new();
+
+    let tasks: Vec<_> = vec!["1234", "5678"]
+        .into_iter()
+        .map(|channel| {
+            let client = client.clone();
+            let token = token.clone();
+            tokio::spawn(async move {
+                let req = moderation::GetModeratorsRequest::for_broadcaster(channel);
+                client.req_get(req, &*token).await
+            })
+        })
+        .collect();
+    for task in tasks {
+        assert!(matches!(
+            task.await.unwrap(),
+            Err(ClientRequestError::RequestError(_))
+        ));
+    }
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_client_id_mismatch() {
+    let token = test::token(None);
+    let client: HelixClient<crate::DummyHttpClient> =
+        HelixClient::new().with_client_id(twitch_oauth2::ClientId::new("other".to_string()));
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("198704263")
+        .build();
+
+    match futures::executor::block_on(client.req_get(req, &token)) {
+        Err(ClientRequestError::CreateRequestError(CreateRequestError::ClientIdMismatch {
+            client_id,
+            token_client_id,
+        })) => {
+            assert_eq!(client_id, "other");
+            assert_eq!(token_client_id, "clientid");
+        }
+        other => panic!("unexpected result: {:?}", other.map(|r| r.data)),
+    }
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_timeout() {
+    let token = test::token(None);
+    let timeout = std::time::Duration::from_millis(10);
+    let client = HelixClient::with_client(test::MockClient::new(|_, _| None)).with_timeout(timeout);
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("198704263")
+        .build();
+
+    match futures::executor::block_on(client.req_get(req, &token)) {
+        Err(ClientRequestError::Timeout(t)) => assert_eq!(t, timeout),
+        other => panic!("unexpected result: {:?}", other.map(|r| r.data)),
+    }
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_interceptors() {
+    use std::sync::{Arc, Mutex};
+
+    struct Record(&'static str, Arc<Mutex<Vec<String>>>);
+
+    impl Interceptor for Record {
+        fn before_request(&self, request: &mut http::Request<Vec<u8>>) {
+            self.1.lock().unwrap().push(format!("before {}", self.0));
+            request
+                .headers_mut()
+                .append("X-Interceptor", self.0.parse().unwrap());
+        }
+
+        fn after_response(&self, response: &http::Response<Vec<u8>>) {
+            self.1
+                .lock()
+                .unwrap()
+                .push(format!("after {} {}", self.0, response.status().as_u16()));
+        }
+    }
+
+    let token = test::token(None);
+    let log = Arc::new(Mutex::new(vec![]));
+    let client = HelixClient::with_client(test::MockClient::with_body(
+        r#"{"data":[],"pagination":{}}"#,
+    ))
+    .with_interceptor(Record("first", log.clone()))
+    .with_interceptor(Record("second", log.clone()));
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("198704263")
+        .build();
+
+    assert!(futures::executor::block_on(client.req_get(req, &token)).is_ok());
+    let seen: Vec<_> = client.client.requests()[0]
+        .headers()
+        .get_all("X-Interceptor")
+        .iter()
+        .map(|v| v.to_str().unwrap().to_string())
+        .collect();
+    assert_eq!(seen, vec!["first", "second"]);
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "before first",
+            "before second",
+            "after second 200",
+            "after first 200"
+        ]
+    );
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_paginate_items() {
+    use futures::StreamExt;
+
+    let token = test::token(None);
+    let client = HelixClient::with_client(test::MockClient::new(|_, req| {
+        let body = if req
+            .uri()
+            .query()
+            .unwrap_or_default()
+            .contains("after=page2")
+        {
+            r#"{"data":[{"user_id":"3","user_name":"three","expires_at":""}],"pagination":{}}"#
+        } else {
+            r#"{"data":[{"user_id":"1","user_name":"one","expires_at":""},{"user_id":"2","user_name":"two","expires_at":""}],"pagination":{"cursor":"page2"}}"#
+        };
+        Some(test::response(200, body))
+    }));
+    let req = moderation::GetBannedUsersRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+
+    let pages: Vec<_> = futures::executor::block_on(client.paginate(req.clone(), &token).collect());
+    assert_eq!(pages.len(), 2);
+
+    let users: Vec<_> = futures::executor::block_on(
+        client
+            .paginate(req, &token)
+            .items()
+            .map(|user| user.unwrap().user_id)
+            .collect(),
+    );
+    assert_eq!(users, vec!["1", "2", "3"]);
+}
+
+#[cfg(feature = "twitch_oauth2")]
+#[test]
+fn test_dry_run_app_token() {
+    let token = twitch_oauth2::AppAccessToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new("token".to_string()),
+        None,
+        twitch_oauth2::ClientId::new("clientid".to_string()),
+        twitch_oauth2::ClientSecret::new("secret".to_string()),
+        Some(vec![]),
+        None,
+    );
+
+    let req = users::GetUsersRequest::builder()
+        .id(vec!["44322889".to_string()])
+        .build();
+    let request = req.dry_run(&token).unwrap();
+    assert_eq!(
+        request.headers()[http::header::AUTHORIZATION],
+        "Bearer token"
+    );
+
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("198704263")
+        .build();
+    assert!(matches!(
+        req.dry_run(&token),
+        Err(CreateRequestError::ValidationError(
+            RequestValidationError::ScopeError(ScopeError::UserTokenRequired)
+        ))
+    ));
+}
+
+#[cfg(feature = "tolerant")]
+#[test]
+fn test_parse_response_tolerant() {
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("198704263")
+        .build();
+    let data = br#"{"data":[
+        {"user_id":"1","user_login":"one","user_name":"One"},
+        {"user_login":"two","user_name":"Two"},
+        {"user_id":"3","user_login":"three","user_name":"Three"}
+    ],"pagination":{}}"#
+        .to_vec();
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let res = req.parse_response_tolerant(&uri, http_response).unwrap();
+    assert!(!res.is_complete());
+    assert_eq!(res.response.len(), 2);
+    assert_eq!(res.response.data[1].user_login, "three");
+    assert_eq!(res.errors[0].index, 1);
+}
+
+#[test]
+fn test_error_source() {
+    use std::error::Error;
+
+    let err: ClientRequestError<std::io::Error> =
+        CreateRequestError::from(serde_json::from_str::<u32>("x").unwrap_err()).into();
+    let source = err.source().and_then(|e| e.source()).unwrap();
+    assert!(source.downcast_ref::<serde_json::Error>().is_some());
+
+    // Every error wrapping another one is in the chain, down to the error of serde_json
+    fn chain<'e>(err: &'e (dyn Error + 'static)) -> Vec<&'e (dyn Error + 'static)> {
+        std::iter::successors(Some(err), |e| (*e).source()).collect()
+    }
+    let json = || serde_json::from_str::<u32>("x").unwrap_err();
+    let err: ClientRequestError<std::io::Error> = HelixRequestGetError::from(json()).into();
+    let errors = chain(&err);
+    assert_eq!(errors.len(), 3);
+    assert!(errors[1].downcast_ref::<HelixRequestGetError>().is_some());
+    assert!(errors[2].downcast_ref::<serde_json::Error>().is_some());
+
+    let err: ClientRequestError<std::io::Error> = HelixRequestPostError::from(json()).into();
+    let errors = chain(&err);
+    assert_eq!(errors.len(), 3);
+    assert!(errors[1].downcast_ref::<HelixRequestPostError>().is_some());
+    assert!(errors[2].downcast_ref::<serde_json::Error>().is_some());
+
+    let err: ClientRequestError<std::io::Error> =
+        ClientRequestError::HelixRequestDeleteError(HelixRequestDeleteError::Error {
+            error: "Bad Request".to_string(),
+            status: http::StatusCode::BAD_REQUEST,
+            message: String::new(),
+            uri: http::Uri::from_static("https://api.twitch.tv/helix/moderation/bans"),
+            request_id: None,
+        });
+    let errors = chain(&err);
+    assert_eq!(errors.len(), 2);
+    assert!(errors[1]
+        .downcast_ref::<HelixRequestDeleteError>()
+        .is_some());
+}
+
+/// Helpers for testing endpoints
+#[cfg(test)]
+pub(crate) mod test {
+    use super::Request;
+
+    /// Assert that `req` is sent to the uri `expected`, and return the uri
+    ///
+    /// The whole uri is compared, so the order of repeated query parameters is checked too.
+    /// Query parameters without a value are rejected, as they mean an unset option was not skipped.
+    #[track_caller]
+    pub(crate) fn assert_uri<R: Request>(req: &R, expected: &str) -> http::Uri {
+        let uri = req.get_uri().expect("request should have a valid uri");
+        assert_eq!(uri.to_string(), expected);
+        let query = uri.query().unwrap_or_default();
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            assert!(
+                !value.is_empty(),
+                "query parameter `{}` has no value in {}",
+                key,
+                uri
+            );
+        }
+        uri
+    }
+
+    /// A user token for tests, with `scopes` if they should be checked
+    #[cfg(feature = "twitch_oauth2")]
+    pub(crate) fn token(scopes: Option<Vec<twitch_oauth2::Scope>>) -> twitch_oauth2::UserToken {
+        twitch_oauth2::UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("token".to_string()),
+            None,
+            twitch_oauth2::ClientId::new("clientid".to_string()),
+            None,
+            "justinfan".into(),
+            "1234".into(),
+            scopes,
+            None,
+        )
+    }
+
+    /// A response with `status` and `body`
+    #[cfg(feature = "client")]
+    pub(crate) fn response(status: u16, body: &str) -> http::Response<Vec<u8>> {
+        http::Response::builder()
+            .status(status)
+            .body(body.as_bytes().to_vec())
+            .unwrap()
+    }
+
+    /// Answer of [`MockClient`] to the `n`th request (counting from zero), `None` to never answer it
+    #[cfg(feature = "client")]
+    type Respond =
+        dyn Fn(usize, &http::Request<Vec<u8>>) -> Option<http::Response<Vec<u8>>> + Send + Sync;
+
+    /// [`HttpClient`](crate::HttpClient) answering with canned responses and recording the requests it got
+    ///
+    /// Every request stays in flight for one poll, so concurrent requests overlap.
+    #[cfg(feature = "client")]
+    pub(crate) struct MockClient {
+        respond: Box<Respond>,
+        requests: std::sync::Mutex<Vec<http::Request<Vec<u8>>>>,
+        in_flight: std::sync::atomic::AtomicUsize,
+        max_in_flight: std::sync::atomic::AtomicUsize,
+    }
+
+    #[cfg(feature = "client")]
+    impl MockClient {
+        /// Answer the `n`th request (counting from zero) with what `respond` returns for it, `None` to never answer it
+        pub(crate) fn new(
+            respond: impl Fn(usize, &http::Request<Vec<u8>>) -> Option<http::Response<Vec<u8>>>
+                + Send
+                + Sync
+                + 'static,
+        ) -> MockClient {
+            MockClient {
+                respond: Box::new(respond),
+                requests: Default::default(),
+                in_flight: Default::default(),
+                max_in_flight: Default::default(),
+            }
+        }
+
+        /// Answer every request with `200 OK` and `body`
+        pub(crate) fn with_body(body: &'static str) -> MockClient {
+            MockClient::new(move |_, _| Some(response(200, body)))
+        }
+
+        /// The requests this client got, in order
+        pub(crate) fn requests(&self) -> std::sync::MutexGuard<'_, Vec<http::Request<Vec<u8>>>> {
+            self.requests.lock().unwrap()
+        }
+
+        /// The most requests this client had in flight at the same time
+        pub(crate) fn max_in_flight(&self) -> usize {
+            self.max_in_flight.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[cfg(feature = "client")]
+    impl<'a> crate::HttpClient<'a> for MockClient {
+        type Error = std::io::Error;
+
+        fn req(
+            &'a self,
+            req: crate::client::Req,
+        ) -> crate::client::BoxedFuture<'a, Result<crate::client::Response, Self::Error>> {
+            let response = {
+                let mut requests = self.requests();
+                let response = (self.respond)(requests.len(), &req);
+                requests.push(req);
+                response
+            };
+            let in_flight = self
+                .in_flight
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+                + 1;
+            self.max_in_flight
+                .fetch_max(in_flight, std::sync::atomic::Ordering::SeqCst);
+            let mut yielded = false;
+            Box::pin(async move {
+                futures::future::poll_fn(|cx| {
+                    if yielded {
+                        std::task::Poll::Ready(())
+                    } else {
+                        yielded = true;
+                        cx.waker().wake_by_ref();
+                        std::task::Poll::Pending
+                    }
+                })
+                .await;
+                self.in_flight
+                    .fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+                match response {
+                    Some(response) => Ok(response),
+                    None => futures::future::pending().await,
+                }
+            })
+        }
+    }
+}
+
+/// Compare the serialized form of `value` against the golden file `tests/fixtures/{name}`
+///
+/// Run with `UPDATE_FIXTURES=1` to write the current output to the golden file instead.
+#[cfg(all(test, feature = "test-fixtures"))]
+pub(crate) fn assert_fixture<T: serde::Serialize>(value: &T, name: &str) {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    // Going through a Value sorts object keys, so maps serialize deterministically
+    let actual = serde_json::to_value(value).unwrap();
+    if std::env::var_os("UPDATE_FIXTURES").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let json = serde_json::to_string_pretty(&actual).unwrap();
+        std::fs::write(&path, json + "\n").unwrap();
+        return;
+    }
+    let golden = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read fixture {}: {}", path.display(), e));
+    let expected: serde_json::Value = serde_json::from_str(&golden).unwrap();
+    assert_eq!(
+        actual,
+        expected,
+        "serialized output differs from {}, rerun with UPDATE_FIXTURES=1 if this is intended",
+        path.display()
+    );
+}
+
+/// How the body of a [`RequestPost`] is sent
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum BodyWrapping {
+    /// Wrap the body in `{"data": ...}` if it is a collection, send it as is otherwise
+    Auto,
+    /// Always wrap the body in `{"data": ...}`
+    Data,
+    /// Never wrap the body
+    Bare,
+}
+
 /// A request is a Twitch endpoint, see [New Twitch API](https://dev.twitch.tv/docs/api/reference) reference
 #[async_trait::async_trait]
 #[cfg_attr(nightly, doc(spotlight))]
 pub trait Request: serde::Serialize {
     /// The path to the endpoint relative to the helix root. eg. `channels` for [Get Channel Information](https://dev.twitch.tv/docs/api/reference#get-channel-information)
     const PATH: &'static str;
+    /// HTTP method of this endpoint, matching the trait it implements, e.g. [`GET`](http::Method::GET) for [`RequestGet`]
+    const METHOD: http::Method;
+    /// Largest `first` this endpoint accepts, larger values are rejected with [`RequestValidationError::FirstTooLarge`] before sending the request
+    const MAX_FIRST: usize = 100;
     /// Scopes needed by this endpoint
     #[cfg(feature = "twitch_oauth2")]
     const SCOPE: &'static [twitch_oauth2::Scope];
@@ -258,6 +2217,358 @@ pub trait Request: serde::Serialize {
         ))
         .map_err(Into::into)
     }
+    /// Validate the request parameters before the request is sent. Override for endpoints with client-side constraints
+    fn validate(&self) -> Result<(), RequestValidationError> { Ok(()) }
+    /// Change some fields of this request, e.g. to derive a modified copy of an existing request
+    ///
+    /// Requests are `#[non_exhaustive]`, so they can't be created with struct literal syntax,
+    /// and their builders can't be pre-populated from an existing request. Their fields are public though,
+    /// so any of them can be set again here, and the other fields are kept as they are.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::{moderation::GetBannedEventsRequest, Request};
+    /// let request = GetBannedEventsRequest::builder()
+    ///     .broadcaster_id("1234")
+    ///     .build();
+    /// let bigger = request.clone().modify(|r| r.first = Some(100));
+    /// assert_eq!(bigger.broadcaster_id, request.broadcaster_id);
+    /// assert_eq!(bigger.first, Some(100));
+    ///
+    /// let other = bigger.modify(|r| {
+    ///     r.broadcaster_id = "5678".into();
+    ///     r.user_id = vec!["9012".into()];
+    /// });
+    /// assert_eq!(other.broadcaster_id, "5678");
+    /// assert_eq!(other.first, Some(100));
+    /// ```
+    fn modify(mut self, f: impl FnOnce(&mut Self)) -> Self
+    where Self: Sized {
+        f(&mut self);
+        self
+    }
+    /// Check that `token` can be used for this request, without creating or sending it
+    ///
+    /// App access tokens can only be used for endpoints without [scopes](Request::SCOPE).
+    /// User tokens need all scopes of the endpoint, if the scopes of the token are known.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::{moderation, Request};
+    /// # let token = twitch_oauth2::UserToken::from_existing_unchecked(
+    /// #     twitch_oauth2::AccessToken::new("token".to_string()), None,
+    /// #     twitch_oauth2::ClientId::new("clientid".to_string()), None,
+    /// #     "justinfan".into(), "1234".into(), Some(vec![twitch_oauth2::Scope::ModerationRead]), None);
+    /// // Check the endpoints the bot uses on startup
+    /// let moderators = moderation::GetModeratorsRequest::builder().broadcaster_id("1234").build();
+    /// let ban = moderation::BanUserRequest::builder().broadcaster_id("1234").moderator_id("1234").build();
+    /// assert!(moderators.can_execute(&token).is_ok());
+    /// assert_eq!(
+    ///     ban.can_execute(&token).unwrap_err().to_string(),
+    ///     "token is missing required scopes: moderator:manage:banned_users"
+    /// );
+    /// ```
+    #[cfg(feature = "twitch_oauth2")]
+    fn can_execute<T: HelixToken + ?Sized>(&self, token: &T) -> Result<(), ScopeError> {
+        if token.is_app_token() {
+            if !self.scopes().is_empty() {
+                return Err(ScopeError::UserTokenRequired);
+            }
+            return Ok(());
+        }
+        check_scopes(self.scopes(), token.scopes())
+    }
+    /// Scopes needed by this request, [`SCOPE`](Request::SCOPE) unless [overridden](Request::with_scope_override)
+    #[cfg(feature = "twitch_oauth2")]
+    fn scopes(&self) -> &[twitch_oauth2::Scope] { Self::SCOPE }
+    /// Need `scope` for this request instead of the [scopes](Request::SCOPE) of the endpoint
+    ///
+    /// Some endpoints accept one of several scopes, e.g. depending on whether the token is of the broadcaster or of a moderator,
+    /// which [`SCOPE`](Request::SCOPE) can't express. The override is honored by [`can_execute`](Request::can_execute),
+    /// `dry_run` and [strict scopes](HelixClient::with_strict_scopes).
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::{moderation, Request};
+    /// # let token = twitch_oauth2::UserToken::from_existing_unchecked(
+    /// #     twitch_oauth2::AccessToken::new("token".to_string()), None,
+    /// #     twitch_oauth2::ClientId::new("clientid".to_string()), None,
+    /// #     "justinfan".into(), "1234".into(), Some(vec![twitch_oauth2::Scope::ChannelModerate]), None);
+    /// let request = moderation::GetBannedUsersRequest::for_broadcaster("1234");
+    /// assert!(request.can_execute(&token).is_err());
+    /// let request = request.with_scope_override(twitch_oauth2::Scope::ChannelModerate);
+    /// assert!(request.can_execute(&token).is_ok());
+    /// ```
+    #[cfg(feature = "twitch_oauth2")]
+    fn with_scope_override(self, scope: twitch_oauth2::Scope) -> ScopeOverride<Self>
+    where Self: Sized {
+        ScopeOverride {
+            request: self,
+            scope,
+        }
+    }
+}
+
+/// A request that needs another scope than its endpoint, see [`Request::with_scope_override`]
+///
+/// Sent like the request it wraps.
+#[cfg(feature = "twitch_oauth2")]
+#[derive(PartialEq, serde::Serialize, Clone, Debug)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct ScopeOverride<R> {
+    /// The wrapped request
+    pub request: R,
+    /// Scope needed instead of the [scopes](Request::SCOPE) of the endpoint
+    #[serde(skip)]
+    pub scope: twitch_oauth2::Scope,
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<R: Request> ScopeOverride<R> {
+    /// Wrap the request of `response`, which was parsed by the wrapped request, again
+    fn wrap_response(
+        response: Response<R, R::Response>,
+        scope: twitch_oauth2::Scope,
+    ) -> Response<Self, R::Response> {
+        Response {
+            data: response.data,
+            pagination: response.pagination,
+            request: ScopeOverride {
+                request: response.request,
+                scope,
+            },
+            request_id: response.request_id,
+            status: response.status,
+            #[cfg(feature = "raw_json")]
+            raw: response.raw,
+        }
+    }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<R: Request> Request for ScopeOverride<R> {
+    type Response = R::Response;
+
+    const MAX_FIRST: usize = R::MAX_FIRST;
+    const METHOD: http::Method = R::METHOD;
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = R::OPT_SCOPE;
+    const PATH: &'static str = R::PATH;
+    const SCOPE: &'static [twitch_oauth2::Scope] = R::SCOPE;
+
+    fn query(&self) -> Result<String, ser::Error> { self.request.query() }
+
+    fn get_uri(&self) -> Result<http::Uri, InvalidUri> { self.request.get_uri() }
+
+    fn validate(&self) -> Result<(), RequestValidationError> { self.request.validate() }
+
+    fn scopes(&self) -> &[twitch_oauth2::Scope] { std::slice::from_ref(&self.scope) }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<R: RequestGet> RequestGet for ScopeOverride<R> {
+    fn create_request(
+        &self,
+        token: &str,
+        client_id: &crate::types::ClientId,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+        self.request.create_request(token, client_id)
+    }
+
+    fn parse_response(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<Response<Self, R::Response>, HelixRequestGetError> {
+        let ScopeOverride { request, scope } = self;
+        let response = request.parse_response(uri, response)?;
+        Ok(Self::wrap_response(response, scope))
+    }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<R: Paginated> Paginated for ScopeOverride<R> {
+    fn set_pagination(&mut self, cursor: Option<Cursor>) { self.request.set_pagination(cursor) }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<R: RequestPost> RequestPost for ScopeOverride<R> {
+    type Body = R::Body;
+
+    const BODY_WRAPPING: BodyWrapping = R::BODY_WRAPPING;
+
+    fn body(&self, body: &Self::Body) -> Result<String, serde_json::Error> {
+        self.request.body(body)
+    }
+
+    fn validate_body(&self, body: &Self::Body) -> Result<(), RequestValidationError> {
+        self.request.validate_body(body)
+    }
+
+    fn create_request(
+        &self,
+        body: Self::Body,
+        token: &str,
+        client_id: &crate::types::ClientId,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+        self.request.create_request(body, token, client_id)
+    }
+
+    fn create_request_prebuilt(
+        &self,
+        body: std::borrow::Cow<'_, str>,
+        token: &str,
+        client_id: &crate::types::ClientId,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+        self.request.create_request_prebuilt(body, token, client_id)
+    }
+
+    fn parse_response(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<Response<Self, R::Response>, HelixRequestPostError> {
+        let ScopeOverride { request, scope } = self;
+        let response = request.parse_response(uri, response)?;
+        Ok(Self::wrap_response(response, scope))
+    }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<R> RequestPatch for ScopeOverride<R>
+where
+    R: RequestPatch,
+    R::Response: std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>,
+{
+    type Body = R::Body;
+
+    fn body(&self, body: &Self::Body) -> Result<String, serde_json::Error> {
+        self.request.body(body)
+    }
+
+    fn validate_body(&self, body: &Self::Body) -> Result<(), RequestValidationError> {
+        self.request.validate_body(body)
+    }
+
+    fn create_request(
+        &self,
+        body: Self::Body,
+        token: &str,
+        client_id: &crate::types::ClientId,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+        self.request.create_request(body, token, client_id)
+    }
+
+    fn parse_response(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<R::Response, HelixRequestPatchError> {
+        self.request.parse_response(uri, response)
+    }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<R> RequestPut for ScopeOverride<R>
+where
+    R: RequestPut,
+    R::Response: std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>,
+{
+    type Body = R::Body;
+
+    fn body(&self, body: &Self::Body) -> Result<String, serde_json::Error> {
+        self.request.body(body)
+    }
+
+    fn validate_body(&self, body: &Self::Body) -> Result<(), RequestValidationError> {
+        self.request.validate_body(body)
+    }
+
+    fn create_request(
+        &self,
+        body: Self::Body,
+        token: &str,
+        client_id: &crate::types::ClientId,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+        self.request.create_request(body, token, client_id)
+    }
+
+    fn parse_response(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<R::Response, HelixRequestPutError> {
+        self.request.parse_response(uri, response)
+    }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<R: RequestDelete> RequestDelete for ScopeOverride<R> {
+    fn create_request(
+        &self,
+        token: &str,
+        client_id: &crate::types::ClientId,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+        self.request.create_request(token, client_id)
+    }
+
+    fn parse_response(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<R::Response, HelixRequestDeleteError>
+    where
+        <Self as Request>::Response:
+            std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>,
+    {
+        self.request.parse_response(uri, response)
+    }
+}
+
+/// Check that `scopes` of a token contain all `required` scopes
+#[cfg(feature = "twitch_oauth2")]
+fn check_scopes(
+    required: &[twitch_oauth2::Scope],
+    scopes: &[twitch_oauth2::Scope],
+) -> Result<(), ScopeError> {
+    let missing: Vec<_> = required
+        .iter()
+        .filter(|scope| !scopes.contains(scope))
+        .cloned()
+        .collect();
+    if !missing.is_empty() {
+        return Err(ScopeError::MissingScopes(missing));
+    }
+    Ok(())
+}
+
+/// A [`TwitchToken`] that knows if it's an app access token or a user token
+///
+/// Endpoints that need [scopes](Request::SCOPE) only accept user tokens, others also accept app access tokens.
+#[cfg(feature = "twitch_oauth2")]
+pub trait HelixToken: TwitchToken {
+    /// Returns `true` if this is an [app access token](twitch_oauth2::AppAccessToken)
+    fn is_app_token(&self) -> bool;
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl HelixToken for twitch_oauth2::UserToken {
+    fn is_app_token(&self) -> bool { false }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl HelixToken for twitch_oauth2::AppAccessToken {
+    fn is_app_token(&self) -> bool { true }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl<T: HelixToken + Send> HelixToken for Box<T> {
+    fn is_app_token(&self) -> bool {
+        (**self).is_app_token()
+    }
+}
+
+/// Make the body of a request readable for a dry run
+#[cfg(feature = "twitch_oauth2")]
+fn dry_run_body(request: http::Request<Vec<u8>>) -> http::Request<String> {
+    request.map(|body| String::from_utf8_lossy(&body).into_owned())
 }
 
 /// Helix endpoint POSTs information
@@ -265,38 +2576,108 @@ pub trait Request: serde::Serialize {
 pub trait RequestPost: Request {
     /// Body parameters
     type Body: serde::Serialize;
+    /// How [`RequestPost::Body`] is wrapped when sent, see [`BodyWrapping`]
+    const BODY_WRAPPING: BodyWrapping = BodyWrapping::Auto;
 
-    /// Create body text from [`RequestPost::Body`]
+    /// Create body text from [`RequestPost::Body`], wrapped according to [`RequestPost::BODY_WRAPPING`]
     fn body(&self, body: &Self::Body) -> Result<String, serde_json::Error> {
-        serde_json::to_string(body)
+        let body = serde_json::to_string(body)?;
+        let wrap = match Self::BODY_WRAPPING {
+            BodyWrapping::Auto => body.starts_with('['),
+            BodyWrapping::Data => true,
+            BodyWrapping::Bare => false,
+        };
+        if wrap {
+            Ok(format!(r#"{{"data":{}}}"#, body))
+        } else {
+            Ok(body)
+        }
+    }
+
+    /// The body text that is sent for `body`, for logging
+    ///
+    /// Same as [`RequestPost::body`], but serialization errors are described in the returned text instead.
+    /// The body never contains the token, so it is safe to log.
+    fn debug_body(&self, body: &Self::Body) -> String {
+        self.body(body)
+            .unwrap_or_else(|e| format!("<body could not be serialized: {}>", e))
     }
 
+    /// Validate [`RequestPost::Body`] before it is sent. Override for endpoints with client-side constraints
+    fn validate_body(&self, _body: &Self::Body) -> Result<(), RequestValidationError> { Ok(()) }
+
     /// Create a [`http::Request`] from this [`Request`] in your client
     fn create_request(
         &self,
         body: Self::Body,
         token: &str,
-        client_id: &str,
+        client_id: &crate::types::ClientId,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError>
     {
-        let uri = self.get_uri()?;
-
+        self.validate_body(&body)?;
         let body = self.body(&body)?;
         // eprintln!("\n\nbody is ------------ {} ------------", body);
+        self.create_request_prebuilt(body.into(), token, client_id)
+    }
 
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::POST)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(body.into_bytes())
-            .map_err(Into::into)
+    /// Create a [`http::Request`] from this [`Request`] with a body that was already serialized with [`RequestPost::body`]
+    ///
+    /// Useful when sending the same body again, e.g. when retrying, as the body is not serialized again.
+    /// The body is sent as is, so [`RequestPost::validate_body`] is not called.
+    fn create_request_prebuilt(
+        &self,
+        body: std::borrow::Cow<'_, str>,
+        token: &str,
+        client_id: &crate::types::ClientId,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError>
+    {
+        let uri = self.get_uri()?;
+        validate_required_parameters(&uri)?;
+        validate_first(&uri, Self::MAX_FIRST)?;
+        self.validate()?;
+        debug_assert_eq!(
+            Self::METHOD,
+            http::Method::POST,
+            "wrong METHOD for {}",
+            Self::PATH
+        );
+
+        build_request(
+            Self::METHOD,
+            uri,
+            token,
+            client_id,
+            body.into_owned().into_bytes(),
+        )
+    }
+
+    /// Create the [`http::Request`] this request would send, without sending it
+    ///
+    /// Performs all client-side validation, including checking that `token` is of the right [kind](HelixToken) and has the [scopes](Request::SCOPE) needed by the endpoint.
+    #[cfg(feature = "twitch_oauth2")]
+    fn dry_run<T: HelixToken + ?Sized>(
+        &self,
+        body: Self::Body,
+        token: &T,
+    ) -> Result<http::Request<String>, CreateRequestError> {
+        self.can_execute(token)
+            .map_err(RequestValidationError::from)?;
+        self.create_request(body, token.token().secret(), &token.client_id().into())
+            .map(dry_run_body)
+    }
+
+    /// Parse response without the uri it was requested from, e.g. when the request was sent with another HTTP client
+    ///
+    /// The uri is only used in errors, so it is recreated with [`Request::get_uri`].
+    fn parse_response_without_uri(
+        self,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let uri = self.get_uri().unwrap_or_default();
+        self.parse_response(&uri, response)
     }
 
     /// Parse response. Override for different behavior
@@ -308,27 +2689,17 @@ pub trait RequestPost: Request {
     where
         Self: Sized,
     {
-        let text = std::str::from_utf8(&response.body())
-            .map_err(|e| HelixRequestPostError::Utf8Error(response.body().clone(), e))?;
-        if let Ok(HelixRequestError {
-            error,
-            status,
-            message,
-        }) = serde_json::from_str::<HelixRequestError>(&text)
-        {
-            return Err(HelixRequestPostError::Error {
-                error,
-                status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
-                message,
-                uri: uri.clone(),
-                body: response.body().clone(),
-            });
-        }
-        let response: InnerResponse<<Self as Request>::Response> = serde_json::from_str(&text)?;
+        let (text, request_id) = post_response_text(uri, &response)?;
+        let status = response.status();
+        let response: InnerResponse<<Self as Request>::Response> = parse_json(text)?;
         Ok(Response {
             data: response.data,
-            pagination: response.pagination.cursor,
+            pagination: response.pagination,
             request: self,
+            request_id,
+            status,
+            #[cfg(feature = "raw_json")]
+            raw: raw_json(text),
         })
     }
 }
@@ -346,32 +2717,62 @@ where <Self as Request>::Response:
         serde_json::to_string(body)
     }
 
+    /// Validate [`RequestPatch::Body`] before it is sent. Override for endpoints with client-side constraints
+    fn validate_body(&self, _body: &Self::Body) -> Result<(), RequestValidationError> { Ok(()) }
+
     /// Create a [`http::Request`] from this [`Request`] in your client
     fn create_request(
         &self,
         body: Self::Body,
         token: &str,
-        client_id: &str,
+        client_id: &crate::types::ClientId,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError>
     {
         let uri = self.get_uri()?;
+        validate_required_parameters(&uri)?;
+        validate_first(&uri, Self::MAX_FIRST)?;
+        self.validate()?;
 
+        self.validate_body(&body)?;
         let body = self.body(&body)?;
         // eprintln!("\n\nbody is ------------ {} ------------", body);
+        debug_assert_eq!(
+            Self::METHOD,
+            http::Method::PATCH,
+            "wrong METHOD for {}",
+            Self::PATH
+        );
 
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::PATCH)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(body.into_bytes())
-            .map_err(Into::into)
+        build_request(Self::METHOD, uri, token, client_id, body.into_bytes())
+    }
+
+    /// Create the [`http::Request`] this request would send, without sending it
+    ///
+    /// Performs all client-side validation, including checking that `token` is of the right [kind](HelixToken) and has the [scopes](Request::SCOPE) needed by the endpoint.
+    #[cfg(feature = "twitch_oauth2")]
+    fn dry_run<T: HelixToken + ?Sized>(
+        &self,
+        body: Self::Body,
+        token: &T,
+    ) -> Result<http::Request<String>, CreateRequestError> {
+        self.can_execute(token)
+            .map_err(RequestValidationError::from)?;
+        self.create_request(body, token.token().secret(), &token.client_id().into())
+            .map(dry_run_body)
+    }
+
+    /// Parse response without the uri it was requested from, e.g. when the request was sent with another HTTP client
+    ///
+    /// The uri is only used in errors, so it is recreated with [`Request::get_uri`].
+    fn parse_response_without_uri(
+        self,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<<Self as Request>::Response, HelixRequestPatchError>
+    where
+        Self: Sized,
+    {
+        let uri = self.get_uri().unwrap_or_default();
+        self.parse_response(&uri, response)
     }
 
     /// Parse response. Override for different behavior
@@ -383,12 +2784,125 @@ where <Self as Request>::Response:
     where
         Self: Sized,
     {
+        warn_deprecation(uri, response.headers());
         match response.status().try_into() {
             Ok(result) => Ok(result),
             Err(err) => Err(HelixRequestPatchError {
                 status: response.status(),
                 message: err.to_string(),
                 uri: uri.clone(),
+                request_id: read_request_id(response.headers()),
+                body: response.body().clone(),
+            }),
+        }
+    }
+}
+
+/// Helix endpoint PUTs information
+#[cfg_attr(nightly, doc(spotlight))]
+pub trait RequestPut: Request
+where <Self as Request>::Response:
+        std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>> {
+    /// Body parameters
+    type Body: serde::Serialize;
+
+    /// Create body text from [`RequestPut::Body`]
+    fn body(&self, body: &Self::Body) -> Result<String, serde_json::Error> {
+        serde_json::to_string(body)
+    }
+
+    /// Validate [`RequestPut::Body`] before it is sent. Override for endpoints with client-side constraints
+    fn validate_body(&self, _body: &Self::Body) -> Result<(), RequestValidationError> { Ok(()) }
+
+    /// Create a [`http::Request`] from this [`Request`] in your client
+    fn create_request(
+        &self,
+        body: Self::Body,
+        token: &str,
+        client_id: &crate::types::ClientId,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError>
+    {
+        let uri = self.get_uri()?;
+        validate_required_parameters(&uri)?;
+        validate_first(&uri, Self::MAX_FIRST)?;
+        self.validate()?;
+
+        self.validate_body(&body)?;
+        let body = self.body(&body)?;
+
+        debug_assert_eq!(
+            Self::METHOD,
+            http::Method::PUT,
+            "wrong METHOD for {}",
+            Self::PATH
+        );
+
+        build_request(Self::METHOD, uri, token, client_id, body.into_bytes())
+    }
+
+    /// Create the [`http::Request`] this request would send, without sending it
+    ///
+    /// Performs all client-side validation, including checking that `token` is of the right [kind](HelixToken) and has the [scopes](Request::SCOPE) needed by the endpoint.
+    #[cfg(feature = "twitch_oauth2")]
+    fn dry_run<T: HelixToken + ?Sized>(
+        &self,
+        body: Self::Body,
+        token: &T,
+    ) -> Result<http::Request<String>, CreateRequestError> {
+        self.can_execute(token)
+            .map_err(RequestValidationError::from)?;
+        self.create_request(body, token.token().secret(), &token.client_id().into())
+            .map(dry_run_body)
+    }
+
+    /// Parse response without the uri it was requested from, e.g. when the request was sent with another HTTP client
+    ///
+    /// The uri is only used in errors, so it is recreated with [`Request::get_uri`].
+    fn parse_response_without_uri(
+        self,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<<Self as Request>::Response, HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        let uri = self.get_uri().unwrap_or_default();
+        self.parse_response(&uri, response)
+    }
+
+    /// Parse response. Override for different behavior
+    fn parse_response(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<<Self as Request>::Response, HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        warn_deprecation(uri, response.headers());
+        if let Ok(TwitchErrorResponse {
+            error,
+            status,
+            message,
+        }) = serde_json::from_slice::<TwitchErrorResponse>(response.body())
+        {
+            return Err(HelixRequestPutError {
+                error,
+                status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
+                message,
+                uri: uri.clone(),
+                request_id: read_request_id(response.headers()),
+                body: response.body().clone(),
+            });
+        }
+
+        match response.status().try_into() {
+            Ok(result) => Ok(result),
+            Err(err) => Err(HelixRequestPutError {
+                error: String::new(),
+                status: response.status(),
+                message: err.to_string(),
+                uri: uri.clone(),
+                request_id: read_request_id(response.headers()),
                 body: response.body().clone(),
             }),
         }
@@ -402,24 +2916,51 @@ pub trait RequestDelete: Request {
     fn create_request(
         &self,
         token: &str,
-        client_id: &str,
+        client_id: &crate::types::ClientId,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError>
     {
         let uri = self.get_uri()?;
+        validate_required_parameters(&uri)?;
+        validate_first(&uri, Self::MAX_FIRST)?;
+        self.validate()?;
+        debug_assert_eq!(
+            Self::METHOD,
+            http::Method::DELETE,
+            "wrong METHOD for {}",
+            Self::PATH
+        );
 
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::DELETE)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(Vec::with_capacity(0))
-            .map_err(Into::into)
+        build_request(Self::METHOD, uri, token, client_id, Vec::with_capacity(0))
+    }
+
+    /// Create the [`http::Request`] this request would send, without sending it
+    ///
+    /// Performs all client-side validation, including checking that `token` is of the right [kind](HelixToken) and has the [scopes](Request::SCOPE) needed by the endpoint.
+    #[cfg(feature = "twitch_oauth2")]
+    fn dry_run<T: HelixToken + ?Sized>(
+        &self,
+        token: &T,
+    ) -> Result<http::Request<String>, CreateRequestError> {
+        self.can_execute(token)
+            .map_err(RequestValidationError::from)?;
+        self.create_request(token.token().secret(), &token.client_id().into())
+            .map(dry_run_body)
+    }
+
+    /// Parse response without the uri it was requested from, e.g. when the request was sent with another HTTP client
+    ///
+    /// The uri is only used in errors, so it is recreated with [`Request::get_uri`].
+    fn parse_response_without_uri(
+        self,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<<Self as Request>::Response, HelixRequestDeleteError>
+    where
+        <Self as Request>::Response:
+            std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>,
+        Self: Sized,
+    {
+        let uri = self.get_uri().unwrap_or_default();
+        self.parse_response(&uri, response)
     }
 
     /// Parse response. Override for different behavior
@@ -433,21 +2974,23 @@ pub trait RequestDelete: Request {
             std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>,
         Self: Sized,
     {
+        warn_deprecation(uri, response.headers());
         let text = std::str::from_utf8(&response.body())
             .map_err(|e| HelixRequestDeleteError::Utf8Error(response.body().clone(), e))?;
         // eprintln!("\n\nmessage is ------------ {} ------------", text);
 
-        if let Ok(HelixRequestError {
+        if let Ok(TwitchErrorResponse {
             error,
             status,
             message,
-        }) = serde_json::from_str::<HelixRequestError>(&text)
+        }) = serde_json::from_str::<TwitchErrorResponse>(&text)
         {
             return Err(HelixRequestDeleteError::Error {
                 error,
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
                 uri: uri.clone(),
+                request_id: read_request_id(response.headers()),
             });
         }
 
@@ -458,6 +3001,7 @@ pub trait RequestDelete: Request {
                 status: response.status(),
                 message: err.to_string(),
                 uri: uri.clone(),
+                request_id: read_request_id(response.headers()),
             }),
         }
     }
@@ -470,24 +3014,49 @@ pub trait RequestGet: Request {
     fn create_request(
         &self,
         token: &str,
-        client_id: &str,
+        client_id: &crate::types::ClientId,
     ) -> Result<http::Request<Vec<u8>>, CreateRequestError>
     {
         let uri = self.get_uri()?;
+        validate_required_parameters(&uri)?;
+        validate_first(&uri, Self::MAX_FIRST)?;
+        self.validate()?;
+        debug_assert_eq!(
+            Self::METHOD,
+            http::Method::GET,
+            "wrong METHOD for {}",
+            Self::PATH
+        );
 
-        let mut bearer =
-            http::HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                CreateRequestError::Custom("Could not make token into headervalue".into())
-            })?;
-        bearer.set_sensitive(true);
-        http::Request::builder()
-            .method(http::Method::GET)
-            .uri(uri)
-            .header("Client-ID", client_id)
-            .header("Content-Type", "application/json")
-            .header(http::header::AUTHORIZATION, bearer)
-            .body(Vec::with_capacity(0))
-            .map_err(Into::into)
+        build_request(Self::METHOD, uri, token, client_id, Vec::with_capacity(0))
+    }
+
+    /// Create the [`http::Request`] this request would send, without sending it
+    ///
+    /// Performs all client-side validation, including checking that `token` is of the right [kind](HelixToken) and has the [scopes](Request::SCOPE) needed by the endpoint.
+    #[cfg(feature = "twitch_oauth2")]
+    fn dry_run<T: HelixToken + ?Sized>(
+        &self,
+        token: &T,
+    ) -> Result<http::Request<String>, CreateRequestError> {
+        self.can_execute(token)
+            .map_err(RequestValidationError::from)?;
+        self.create_request(token.token().secret(), &token.client_id().into())
+            .map(dry_run_body)
+    }
+
+    /// Parse response without the uri it was requested from, e.g. when the request was sent with another HTTP client
+    ///
+    /// The uri is only used in errors, so it is recreated with [`Request::get_uri`].
+    fn parse_response_without_uri(
+        self,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        let uri = self.get_uri().unwrap_or_default();
+        self.parse_response(&uri, response)
     }
 
     /// Parse response. Override for different behavior
@@ -499,33 +3068,280 @@ pub trait RequestGet: Request {
     where
         Self: Sized,
     {
-        let text = std::str::from_utf8(&response.body())
-            .map_err(|e| HelixRequestGetError::Utf8Error(response.body().clone(), e))?;
+        let (text, request_id) = get_response_text(uri, &response)?;
         //eprintln!("\n\nmessage is ------------ {} ------------", text);
-        if let Ok(HelixRequestError {
-            error,
+        let status = response.status();
+        let response: InnerResponse<_> = parse_json(text)?;
+        Ok(Response {
+            data: response.data,
+            pagination: response.pagination,
+            request: self,
+            request_id,
             status,
-            message,
-        }) = serde_json::from_str::<HelixRequestError>(&text)
-        {
+            #[cfg(feature = "raw_json")]
+            raw: raw_json(text),
+        })
+    }
+
+    /// Parse response, keeping every element of `data` that could be parsed instead of failing on the first bad one
+    ///
+    /// Elements that fail to parse, and the point where the body stopped being valid JSON, are returned in [`PartialResponse::errors`].
+    /// Fails only if Twitch returned an error or nothing could be salvaged.
+    #[cfg(feature = "tolerant")]
+    fn parse_response_tolerant<T>(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<PartialResponse<Self, T>, HelixRequestGetError>
+    where
+        Self: Request<Response = Vec<T>> + Sized,
+        T: serde::de::DeserializeOwned + PartialEq,
+    {
+        let (text, request_id) = get_response_text(uri, &response)?;
+        let (data, pagination, errors) = tolerant::parse(text)?;
+        Ok(PartialResponse {
+            response: Response {
+                data,
+                pagination,
+                request: self,
+                request_id,
+                status: response.status(),
+                #[cfg(feature = "raw_json")]
+                raw: raw_json(text),
+            },
+            errors,
+        })
+    }
+
+    /// Parse response into only its data and the cursor for the next page, if any
+    ///
+    /// Useful for manual pagination, where the other fields of [`Response`] are not needed.
+    fn parse_response_parts(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<(<Self as Request>::Response, Option<Cursor>), HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        self.parse_response(uri, response)
+            .map(|response| (response.data, response.pagination.cursor))
+    }
+
+    /// Parse response directly from a reader over the response body, without buffering it first.
+    ///
+    /// Since the body can only be read once, the response `status` decides if the body is parsed as an error or as data.
+    fn parse_response_reader<R: std::io::Read>(
+        self,
+        uri: &http::Uri,
+        status: http::StatusCode,
+        reader: R,
+    ) -> Result<Response<Self, <Self as Request>::Response>, HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        if status == http::StatusCode::GONE {
+            return Err(HelixRequestGetError::Gone {
+                uri: uri.clone(),
+                request_id: None,
+            });
+        }
+        if !status.is_success() {
+            let TwitchErrorResponse {
+                error,
+                status,
+                message,
+            } = serde_json::from_reader(reader)?;
             return Err(HelixRequestGetError::Error {
                 error,
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
                 uri: uri.clone(),
+                request_id: None,
             });
         }
-        let response: InnerResponse<_> = serde_json::from_str(&text)?;
+        let response: InnerResponse<_> = serde_json::from_reader(reader)?;
         Ok(Response {
             data: response.data,
-            pagination: response.pagination.cursor,
+            pagination: response.pagination,
             request: self,
+            request_id: None,
+            status,
+            #[cfg(feature = "raw_json")]
+            raw: None,
+        })
+    }
+}
+
+/// Build a [`http::Request`] to `uri` with the headers every helix endpoint needs
+pub(crate) fn build_request(
+    method: http::Method,
+    uri: http::Uri,
+    token: &str,
+    client_id: &crate::types::ClientId,
+    body: Vec<u8>,
+) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+    let mut bearer = http::HeaderValue::from_str(&format!("Bearer {}", token))
+        .map_err(|_| CreateRequestError::Custom("Could not make token into headervalue".into()))?;
+    bearer.set_sensitive(true);
+    http::Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Client-ID", client_id.as_str())
+        .header("Content-Type", "application/json")
+        .header(http::header::AUTHORIZATION, bearer)
+        .body(body)
+        .map_err(Into::into)
+}
+
+/// Check the response to a GET request to `uri` for errors, returning its body and [request id](REQUEST_ID_HEADER)
+///
+/// Warns if the endpoint is [deprecated](DeprecationWarning), and returns [`HelixRequestGetError::Gone`] for removed endpoints
+/// and [`HelixRequestGetError::Error`] if Twitch sent an error instead of data.
+pub(crate) fn get_response_text<'r>(
+    uri: &http::Uri,
+    response: &'r http::Response<Vec<u8>>,
+) -> Result<(&'r str, Option<String>), HelixRequestGetError> {
+    let request_id = read_request_id(response.headers());
+    warn_deprecation(uri, response.headers());
+    if response.status() == http::StatusCode::GONE {
+        return Err(HelixRequestGetError::Gone {
+            uri: uri.clone(),
+            request_id,
+        });
+    }
+    let text = std::str::from_utf8(response.body())
+        .map_err(|e| HelixRequestGetError::Utf8Error(response.body().clone(), e))?;
+    if let Ok(TwitchErrorResponse {
+        error,
+        status,
+        message,
+    }) = serde_json::from_str::<TwitchErrorResponse>(text)
+    {
+        return Err(HelixRequestGetError::Error {
+            error,
+            status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
+            message,
+            uri: uri.clone(),
+            request_id,
+        });
+    }
+    Ok((text, request_id))
+}
+
+/// Check the response to a POST request to `uri` for errors, returning its body and [request id](REQUEST_ID_HEADER)
+///
+/// Warns if the endpoint is [deprecated](DeprecationWarning), and returns [`HelixRequestPostError::Error`] if Twitch sent an error instead of data.
+pub(crate) fn post_response_text<'r>(
+    uri: &http::Uri,
+    response: &'r http::Response<Vec<u8>>,
+) -> Result<(&'r str, Option<String>), HelixRequestPostError> {
+    let request_id = read_request_id(response.headers());
+    warn_deprecation(uri, response.headers());
+    let text = std::str::from_utf8(response.body())
+        .map_err(|e| HelixRequestPostError::Utf8Error(response.body().clone(), e))?;
+    if let Ok(TwitchErrorResponse {
+        error,
+        status,
+        message,
+    }) = serde_json::from_str::<TwitchErrorResponse>(text)
+    {
+        return Err(HelixRequestPostError::Error {
+            error,
+            status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
+            message,
+            uri: uri.clone(),
+            request_id,
+            body: response.body().clone(),
+        });
+    }
+    Ok((text, request_id))
+}
+
+/// Header Twitch uses for the id of a request
+pub const REQUEST_ID_HEADER: &str = "Twitch-Trace-Id";
+
+/// Read the [request id](REQUEST_ID_HEADER) from the headers of a response, if any
+pub fn read_request_id(headers: &http::HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|id| id.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Twitch marked an endpoint as deprecated, read from the headers of a response
+///
+/// Logged as a warning with [`tracing`](https://docs.rs/tracing) when a response is parsed, if the `tracing` feature is enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DeprecationWarning {
+    /// URI of the deprecated endpoint
+    pub uri: http::Uri,
+    /// Value of the `Twitch-Api-Deprecation` or `Deprecation` header, if any
+    pub deprecation: Option<String>,
+    /// Value of the `Sunset` header, i.e. when the endpoint will be removed, if any
+    pub sunset: Option<String>,
+}
+
+impl DeprecationWarning {
+    /// Read the deprecation headers of a response, `None` if the endpoint is not deprecated
+    pub fn from_headers(uri: &http::Uri, headers: &http::HeaderMap) -> Option<DeprecationWarning> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let deprecation = header("Twitch-Api-Deprecation").or_else(|| header("Deprecation"));
+        let sunset = header("Sunset");
+        if deprecation.is_none() && sunset.is_none() {
+            return None;
+        }
+        Some(DeprecationWarning {
+            uri: uri.clone(),
+            deprecation,
+            sunset,
         })
     }
 }
 
+/// Log a [`DeprecationWarning`] if the response says the endpoint is deprecated
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn warn_deprecation(uri: &http::Uri, headers: &http::HeaderMap) {
+    #[cfg(feature = "tracing")]
+    {
+        if let Some(warning) = DeprecationWarning::from_headers(uri, headers) {
+            tracing::warn!(
+                uri = %warning.uri,
+                deprecation = ?warning.deprecation,
+                sunset = ?warning.sunset,
+                "helix endpoint is deprecated"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_deprecation_warning() {
+    let uri: http::Uri = "https://api.twitch.tv/helix/moderation/banned/events"
+        .parse()
+        .unwrap();
+    let mut headers = http::HeaderMap::new();
+    assert_eq!(DeprecationWarning::from_headers(&uri, &headers), None);
+
+    headers.insert("Sunset", "Tue, 28 Feb 2023 00:00:00 GMT".parse().unwrap());
+    headers.insert("Deprecation", "true".parse().unwrap());
+    let warning = DeprecationWarning::from_headers(&uri, &headers).unwrap();
+    assert_eq!(warning.deprecation.as_deref(), Some("true"));
+    assert_eq!(
+        warning.sunset.as_deref(),
+        Some("Tue, 28 Feb 2023 00:00:00 GMT")
+    );
+}
+
 /// Response retrieved from endpoint. Data is the type in [`Request::Response`]
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
+#[must_use]
 pub struct Response<R, D>
 where
     R: Request<Response = D>,
@@ -533,9 +3349,255 @@ where
     /// Twitch's response field for `data`.
     pub data: D,
     /// A cursor value, to be used in a subsequent request to specify the starting point of the next set of results.
-    pub pagination: Option<Cursor>,
+    pub pagination: Pagination,
     /// The request that was sent, used for [pagination](Paginated)
     pub request: R,
+    /// The [request id](REQUEST_ID_HEADER) Twitch gave this request, if any. Include this when contacting Twitch support.
+    pub request_id: Option<String>,
+    /// HTTP status code of the response, e.g. [`202 Accepted`](http::StatusCode::ACCEPTED) when Twitch has accepted the action but not yet completed it
+    pub status: http::StatusCode,
+    /// The raw JSON body of the response, for inspecting fields this crate doesn't model yet.
+    ///
+    /// `None` if the body was empty or not JSON, or when parsed with [`RequestGet::parse_response_reader`].
+    #[cfg(feature = "raw_json")]
+    pub raw: Option<serde_json::Value>,
+}
+
+impl<R, T> Response<R, Vec<T>>
+where
+    R: Request<Response = Vec<T>>,
+    T: serde::de::DeserializeOwned + PartialEq,
+{
+    /// Get the first entry in [`data`](Response::data), if any.
+    ///
+    /// Useful for endpoints where only one result is expected.
+    pub fn first(&self) -> Option<&T> { self.data.first() }
+
+    /// Number of entries in [`data`](Response::data).
+    ///
+    /// Twitch may return fewer results than requested, e.g. [Check AutoMod Status](moderation::check_automod_status) drops duplicate `msg_id`s,
+    /// so compare this to what was sent when every entry is expected to have a result.
+    pub fn len(&self) -> usize { self.data.len() }
+
+    /// Returns `true` if [`data`](Response::data) is empty.
+    ///
+    /// An empty response is not an error, Twitch returns an empty `data` array when nothing matched the request.
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+}
+
+impl<R, T> IntoIterator for Response<R, Vec<T>>
+where
+    R: Request<Response = Vec<T>>,
+    T: serde::de::DeserializeOwned + PartialEq,
+{
+    type IntoIter = std::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter { self.data.into_iter() }
+}
+
+impl<'r, R, T> IntoIterator for &'r Response<R, Vec<T>>
+where
+    R: Request<Response = Vec<T>>,
+    T: serde::de::DeserializeOwned + PartialEq,
+{
+    type IntoIter = std::slice::Iter<'r, T>;
+    type Item = &'r T;
+
+    fn into_iter(self) -> Self::IntoIter { self.data.iter() }
+}
+
+impl<R, D> Response<R, D>
+where
+    R: Request<Response = D>,
+    D: serde::de::DeserializeOwned + PartialEq,
+{
+    /// Returns `true` if Twitch has more results after this page, i.e. the [pagination](Response::pagination) has a non-empty cursor
+    pub fn has_more(&self) -> bool {
+        self.pagination
+            .cursor()
+            .map_or(false, |cursor| !cursor.as_str().is_empty())
+    }
+}
+
+#[test]
+fn test_parse_response_without_uri() {
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let http_response = http::Response::builder()
+        .body(
+            br#"{"data":[{"user_id":"1","user_login":"one","user_name":"One"}],"pagination":{}}"#
+                .to_vec(),
+        )
+        .unwrap();
+    let response = req.parse_response_without_uri(http_response).unwrap();
+    assert_eq!(response.data[0].user_id, "1");
+
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let http_response = http::Response::builder()
+        .status(401)
+        .body(br#"{"error":"Unauthorized","status":401,"message":"Invalid OAuth token"}"#.to_vec())
+        .unwrap();
+    match req.parse_response_without_uri(http_response) {
+        Err(HelixRequestGetError::Error { uri, .. }) => assert_eq!(
+            uri.to_string(),
+            "https://api.twitch.tv/helix/moderation/moderators?broadcaster_id=1234"
+        ),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_errors_are_clone() {
+    fn is_clone<T: Clone>() {}
+    is_clone::<ClientRequestError<std::io::Error>>();
+    is_clone::<HelixRequestPutError>();
+    is_clone::<HelixRequestPatchError>();
+    is_clone::<HelixRequestDeleteError>();
+
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let http_response = http::Response::builder()
+        .body(b"{\"data\":".to_vec())
+        .unwrap();
+    let error = req.parse_response_without_uri(http_response).unwrap_err();
+    let cloned = error.clone();
+    assert!(matches!(cloned, HelixRequestGetError::DeserializeError(_)));
+    assert_eq!(error.to_string(), cloned.to_string());
+}
+
+#[cfg(all(feature = "raw_json", not(feature = "deny_unknown_fields")))]
+#[test]
+fn test_raw_json() {
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let http_response = http::Response::builder()
+        .body(
+            br#"{"data":[{"user_id":"1","user_login":"one","user_name":"One","preview":true}],"pagination":{}}"#
+                .to_vec(),
+        )
+        .unwrap();
+    let response = req.parse_response_without_uri(http_response).unwrap();
+    assert_eq!(response.raw.unwrap()["data"][0]["preview"], true);
+}
+
+#[test]
+fn test_parse_response_parts() {
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id("1234")
+        .build();
+    let uri = req.get_uri().unwrap();
+    let http_response = http::Response::builder()
+        .body(
+            br#"{"data":[{"user_id":"1","user_login":"one","user_name":"One"}],"pagination":{"cursor":"abc"}}"#
+                .to_vec(),
+        )
+        .unwrap();
+    let (data, cursor) = req.parse_response_parts(&uri, http_response).unwrap();
+    assert_eq!(data[0].user_id, "1");
+    assert_eq!(cursor, Some(Cursor::new("abc")));
+}
+
+#[test]
+fn test_has_more() {
+    let response = |cursor: Option<&str>| Response {
+        data: vec![],
+        pagination: Pagination {
+            cursor: cursor.map(Cursor::new),
+        },
+        request: moderation::GetModeratorsRequest::builder()
+            .broadcaster_id("1234")
+            .build(),
+        request_id: None,
+        status: http::StatusCode::OK,
+        #[cfg(feature = "raw_json")]
+        raw: None,
+    };
+    assert!(response(Some("eyJiIjpudWxsfQ")).has_more());
+    assert!(!response(Some("")).has_more());
+    assert!(!response(None).has_more());
+}
+
+/// Element of `data` for endpoints where every element can fail on its own, e.g. one item of a batch action
+///
+/// Endpoints opt in with `type Response = Vec<BatchItem<T>>`, so that a partial failure does not fail the whole response.
+/// Use [`Response::into_results`] to get a [`Result`] per element.
+#[derive(PartialEq, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum BatchItem<T> {
+    /// Twitch could not do this item
+    Err(TwitchErrorResponse),
+    /// Twitch did this item
+    Ok(T),
+}
+
+impl<T> BatchItem<T> {
+    /// Returns `true` if the item succeeded
+    pub fn is_ok(&self) -> bool { matches!(self, BatchItem::Ok(_)) }
+
+    /// Convert into a [`Result`]
+    pub fn into_result(self) -> Result<T, TwitchErrorResponse> {
+        match self {
+            BatchItem::Ok(item) => Ok(item),
+            BatchItem::Err(e) => Err(e),
+        }
+    }
+}
+
+impl<T> From<BatchItem<T>> for Result<T, TwitchErrorResponse> {
+    fn from(item: BatchItem<T>) -> Self { item.into_result() }
+}
+
+impl<R, T> Response<R, Vec<BatchItem<T>>>
+where
+    R: Request<Response = Vec<BatchItem<T>>>,
+    T: serde::de::DeserializeOwned + PartialEq,
+{
+    /// Get the result of every element of [`data`](Response::data), in the order Twitch returned them
+    pub fn into_results(self) -> Vec<Result<T, TwitchErrorResponse>> {
+        self.data.into_iter().map(BatchItem::into_result).collect()
+    }
+
+    /// Get the elements of [`data`](Response::data) that failed, with their position
+    pub fn failures(&self) -> Vec<(usize, &TwitchErrorResponse)> {
+        self.data
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| match item {
+                BatchItem::Err(e) => Some((i, e)),
+                BatchItem::Ok(_) => None,
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_batch_item() {
+    #[derive(PartialEq, Deserialize, Debug)]
+    struct Item {
+        user_id: String,
+    }
+
+    let data = r#"[
+        {"user_id": "1"},
+        {"error": "Bad Request", "status": 400, "message": "user is already banned"},
+        {"user_id": "3"}
+    ]"#;
+    let items: Vec<BatchItem<Item>> = serde_json::from_str(data).unwrap();
+    assert!(items[0].is_ok());
+    assert!(!items[1].is_ok());
+    let results: Vec<Result<Item, TwitchErrorResponse>> =
+        items.into_iter().map(Into::into).collect();
+    assert_eq!(results[2].as_ref().unwrap().user_id, "3");
+    assert_eq!(
+        results[1].as_ref().unwrap_err().message,
+        "user is already banned"
+    );
 }
 
 #[cfg(feature = "client")]
@@ -545,15 +3607,17 @@ where
     D: serde::de::DeserializeOwned + std::fmt::Debug + PartialEq,
 {
     /// Get the next page in the responses.
-    pub async fn get_next<'a, C: crate::HttpClient<'a>>(
+    pub async fn get_next<'a, 'c, C>(
         self,
-        client: &'a HelixClient<'a, C>,
+        client: &'c HelixClient<'a, C>,
         token: &impl TwitchToken,
-    ) -> Result<Option<Response<R, D>>, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
+    ) -> Result<Option<Response<R, D>>, ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        C: for<'b> crate::HttpClient<'b>,
     {
         let mut req = self.request.clone();
-        if self.pagination.is_some() {
-            req.set_pagination(self.pagination);
+        if self.pagination.cursor.is_some() {
+            req.set_pagination(self.pagination.cursor);
             let res = client.req_get(req, token).await.map(Some);
             if let Ok(Some(r)) = res {
                 if r.data == self.data {
@@ -570,6 +3634,66 @@ where
     }
 }
 
+/// A stream of pages from a [`Paginated`] request, see [`HelixClient::paginate`]
+#[cfg(feature = "client")]
+pub struct PageStream<'a, R, D, E>
+where
+    R: Request<Response = D>,
+    D: serde::de::DeserializeOwned + PartialEq,
+    E: std::error::Error + Send + Sync + 'static, {
+    stream: futures::stream::BoxStream<'a, Result<Response<R, D>, ClientRequestError<E>>>,
+}
+
+#[cfg(feature = "client")]
+impl<'a, R, D, E> futures::Stream for PageStream<'a, R, D, E>
+where
+    R: Request<Response = D>,
+    D: serde::de::DeserializeOwned + PartialEq,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    type Item = Result<Response<R, D>, ClientRequestError<E>>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        futures::Stream::poll_next(self.stream.as_mut(), cx)
+    }
+}
+
+#[cfg(feature = "client")]
+impl<'a, R, T, E> PageStream<'a, R, Vec<T>, E>
+where
+    R: Request<Response = Vec<T>> + 'a,
+    T: serde::de::DeserializeOwned + PartialEq + Send + 'a,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    /// Flatten the pages into a stream of the individual items, buffering one page at a time
+    pub fn items(self) -> futures::stream::BoxStream<'a, Result<T, ClientRequestError<E>>> {
+        use futures::StreamExt;
+
+        self.stream
+            .flat_map(|page| {
+                futures::stream::iter(match page {
+                    Ok(page) => page.data.into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                })
+            })
+            .boxed()
+    }
+}
+
+/// Where a [`PageStream`] is in the pagination
+#[cfg(feature = "client")]
+enum PageState<R, D>
+where
+    R: Request<Response = D>,
+    D: serde::de::DeserializeOwned + PartialEq, {
+    First(R),
+    Next(Response<R, D>),
+    Done,
+}
+
 /// Request can be paginated with a cursor
 pub trait Paginated: Request {
     /// Should returns the current pagination cursor.
@@ -580,67 +3704,429 @@ pub trait Paginated: Request {
     fn set_pagination(&mut self, cursor: Option<Cursor>);
 }
 
+/// An event in the moderation history of a channel, e.g. a [`BannedEvent`](moderation::BannedEvent) or a [`ModeratorEvent`](moderation::ModeratorEvent)
+///
+/// `D` is the type of [`event_data`](Event::event_data), which depends on the endpoint.
+#[derive(PartialEq, serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Event<D> {
+    /// Event ID
+    pub id: String,
+    /// Type of the event, e.g. `moderation.user.ban`
+    pub event_type: String,
+    /// RFC3339 formatted timestamp for events.
+    pub event_timestamp: crate::types::Timestamp,
+    /// Returns the version of the endpoint.
+    pub version: String,
+    /// Data of the event
+    pub event_data: D,
+}
+
+/// `2019-03-13T15:55:14Z moderation.user.ban: ` followed by the [`event_data`](Event::event_data)
+impl<D: std::fmt::Display> std::fmt::Display for Event<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}: {}",
+            self.event_timestamp.as_str(),
+            self.event_type,
+            self.event_data
+        )
+    }
+}
+
 /// A cursor for pagination. This is needed because of how pagination is represented in the [New Twitch API](https://dev.twitch.tv/docs/api)
 #[derive(PartialEq, Deserialize, Debug, Clone, Default)]
-struct Pagination {
+#[non_exhaustive]
+pub struct Pagination {
+    /// Cursor for the next page, `None` if there are no more pages
     #[serde(default)]
-    cursor: Option<Cursor>,
+    pub cursor: Option<Cursor>,
+}
+
+impl Pagination {
+    /// Cursor for the next page, `None` if there are no more pages
+    pub fn cursor(&self) -> Option<&Cursor> { self.cursor.as_ref() }
 }
 
-/// A cursor is a pointer to the current "page" in the twitch api pagination
-pub type Cursor = String;
+newtype_string! {
+    /// A cursor is a pointer to the current "page" in the twitch api pagination
+    ///
+    /// Use [`Cursor::to_checkpoint`] and [`Cursor::from_checkpoint`] to store the position of a crawl and resume it later.
+    /// Twitch does not document how long a cursor stays valid, so a stored cursor may be rejected after some time.
+    ///
+    /// ```rust
+    /// # use twitch_api2::helix::Cursor;
+    /// let cursor = Cursor::new("eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6MjB9fQ");
+    /// let checkpoint = cursor.to_checkpoint();
+    /// // store `checkpoint` in a database, then after a restart
+    /// assert_eq!(Cursor::from_checkpoint(&checkpoint), cursor);
+    /// ```
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    #[serde(transparent)]
+    pub struct Cursor;
+}
+
+impl Cursor {
+    /// Get a string to store this cursor with, see [`Cursor::from_checkpoint`]
+    pub fn to_checkpoint(&self) -> String { self.0.clone() }
+
+    /// Restore a cursor stored with [`Cursor::to_checkpoint`]
+    pub fn from_checkpoint(checkpoint: &str) -> Cursor { Cursor(checkpoint.to_string()) }
+}
 
 /// Errors for [`HelixClient::req_get`] and similar functions.
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
+///
+/// All errors are [`Clone`], errors from other crates are kept in an [`Arc`](std::sync::Arc).
+#[derive(Debug, displaydoc::Display)]
 pub enum ClientRequestError<RE: std::error::Error + Send + Sync + 'static> {
     /// request failed from reqwests side
-    RequestError(RE),
+    RequestError(std::sync::Arc<RE>),
     /// no pagination found
     NoPage,
+    /// request timed out after {0:?}
+    Timeout(std::time::Duration),
     /// Could not create request
-    CreateRequestError(#[from] CreateRequestError),
+    CreateRequestError(CreateRequestError),
     /// Could not parse GET response
-    #[error(transparent)]
-    HelixRequestGetError(#[from] HelixRequestGetError),
-    //#[error(transparent)]
+    HelixRequestGetError(HelixRequestGetError),
     /// Could not parse PUT response
-    HelixRequestPutError(#[from] HelixRequestPutError),
+    HelixRequestPutError(HelixRequestPutError),
     /// Could not parse POST response
-    #[error(transparent)]
-    HelixRequestPostError(#[from] HelixRequestPostError),
-    //#[error(transparent)]
+    HelixRequestPostError(HelixRequestPostError),
     /// Could not parse PATCH response
-    HelixRequestPatchError(#[from] HelixRequestPatchError),
+    HelixRequestPatchError(HelixRequestPatchError),
     /// Could not parse DELETE response
-    #[error(transparent)]
-    HelixRequestDeleteError(#[from] HelixRequestDeleteError),
+    HelixRequestDeleteError(HelixRequestDeleteError),
     /// {0}
     Custom(std::borrow::Cow<'static, str>),
 }
+
+// Not derived, `Arc<E>` only implements `Error` since Rust 1.52
+impl<RE: std::error::Error + Send + Sync + 'static> std::error::Error for ClientRequestError<RE> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientRequestError::RequestError(e) => Some(&**e),
+            ClientRequestError::CreateRequestError(e) => Some(e),
+            ClientRequestError::HelixRequestGetError(e) => Some(e),
+            ClientRequestError::HelixRequestPutError(e) => Some(e),
+            ClientRequestError::HelixRequestPostError(e) => Some(e),
+            ClientRequestError::HelixRequestPatchError(e) => Some(e),
+            ClientRequestError::HelixRequestDeleteError(e) => Some(e),
+            ClientRequestError::NoPage
+            | ClientRequestError::Timeout(_)
+            | ClientRequestError::Custom(_) => None,
+        }
+    }
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> From<CreateRequestError>
+    for ClientRequestError<RE>
+{
+    fn from(e: CreateRequestError) -> Self { ClientRequestError::CreateRequestError(e) }
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> From<HelixRequestGetError>
+    for ClientRequestError<RE>
+{
+    fn from(e: HelixRequestGetError) -> Self { ClientRequestError::HelixRequestGetError(e) }
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> From<HelixRequestPutError>
+    for ClientRequestError<RE>
+{
+    fn from(e: HelixRequestPutError) -> Self { ClientRequestError::HelixRequestPutError(e) }
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> From<HelixRequestPostError>
+    for ClientRequestError<RE>
+{
+    fn from(e: HelixRequestPostError) -> Self { ClientRequestError::HelixRequestPostError(e) }
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> From<HelixRequestPatchError>
+    for ClientRequestError<RE>
+{
+    fn from(e: HelixRequestPatchError) -> Self { ClientRequestError::HelixRequestPatchError(e) }
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> From<HelixRequestDeleteError>
+    for ClientRequestError<RE>
+{
+    fn from(e: HelixRequestDeleteError) -> Self { ClientRequestError::HelixRequestDeleteError(e) }
+}
+
+// Not derived, that would require `RE: Clone`
+impl<RE: std::error::Error + Send + Sync + 'static> Clone for ClientRequestError<RE> {
+    fn clone(&self) -> Self {
+        match self {
+            ClientRequestError::RequestError(e) => ClientRequestError::RequestError(e.clone()),
+            ClientRequestError::NoPage => ClientRequestError::NoPage,
+            ClientRequestError::Timeout(d) => ClientRequestError::Timeout(*d),
+            ClientRequestError::CreateRequestError(e) => {
+                ClientRequestError::CreateRequestError(e.clone())
+            }
+            ClientRequestError::HelixRequestGetError(e) => {
+                ClientRequestError::HelixRequestGetError(e.clone())
+            }
+            ClientRequestError::HelixRequestPutError(e) => {
+                ClientRequestError::HelixRequestPutError(e.clone())
+            }
+            ClientRequestError::HelixRequestPostError(e) => {
+                ClientRequestError::HelixRequestPostError(e.clone())
+            }
+            ClientRequestError::HelixRequestPatchError(e) => {
+                ClientRequestError::HelixRequestPatchError(e.clone())
+            }
+            ClientRequestError::HelixRequestDeleteError(e) => {
+                ClientRequestError::HelixRequestDeleteError(e.clone())
+            }
+            ClientRequestError::Custom(c) => ClientRequestError::Custom(c.clone()),
+        }
+    }
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> ClientRequestError<RE> {
+    /// The [request id](REQUEST_ID_HEADER) Twitch gave the failed request, if any
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ClientRequestError::HelixRequestGetError(e) => e.request_id(),
+            ClientRequestError::HelixRequestPutError(e) => e.request_id(),
+            ClientRequestError::HelixRequestPostError(e) => e.request_id(),
+            ClientRequestError::HelixRequestPatchError(e) => e.request_id(),
+            ClientRequestError::HelixRequestDeleteError(e) => e.request_id(),
+            _ => None,
+        }
+    }
+}
+
 /// Could not create request
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[derive(Debug, Clone, displaydoc::Display)]
 pub enum CreateRequestError {
     /// http crate returned an error
-    HttpError(#[from] http::Error),
+    HttpError(std::sync::Arc<http::Error>),
     /// serialization of body failed
-    SerializeError(#[from] serde_json::Error),
+    SerializeError(std::sync::Arc<serde_json::Error>),
     /// Could not assemble URI for request
-    InvalidUri(#[from] InvalidUri),
+    InvalidUri(InvalidUri),
+    /// request is invalid: {0}
+    ValidationError(RequestValidationError),
+    /// client id {client_id} does not match the client id {token_client_id} of the token
+    ClientIdMismatch {
+        /// Client id set with `HelixClient::with_client_id`
+        client_id: String,
+        /// Client id of the token
+        token_client_id: String,
+    },
     /// {0}
     Custom(std::borrow::Cow<'static, str>),
 }
 
+impl std::error::Error for CreateRequestError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CreateRequestError::HttpError(e) => Some(&**e),
+            CreateRequestError::SerializeError(e) => Some(&**e),
+            CreateRequestError::InvalidUri(e) => Some(e),
+            CreateRequestError::ValidationError(e) => Some(e),
+            CreateRequestError::ClientIdMismatch { .. } | CreateRequestError::Custom(_) => None,
+        }
+    }
+}
+
+impl From<InvalidUri> for CreateRequestError {
+    fn from(e: InvalidUri) -> Self { CreateRequestError::InvalidUri(e) }
+}
+
+impl From<RequestValidationError> for CreateRequestError {
+    fn from(e: RequestValidationError) -> Self { CreateRequestError::ValidationError(e) }
+}
+
+impl From<http::Error> for CreateRequestError {
+    fn from(e: http::Error) -> Self { CreateRequestError::HttpError(std::sync::Arc::new(e)) }
+}
+
+impl From<serde_json::Error> for CreateRequestError {
+    fn from(e: serde_json::Error) -> Self {
+        CreateRequestError::SerializeError(std::sync::Arc::new(e))
+    }
+}
+
+/// Request did not pass client-side validation
+#[derive(thiserror::Error, Debug, Clone, displaydoc::Display)]
+#[non_exhaustive]
+pub enum RequestValidationError {
+    /// timeout duration of {0} seconds is out of range, must be between 1 and 1209600 seconds
+    TimeoutDurationOutOfRange(u32),
+    /// invalid chat color: {0}
+    InvalidChatColor(#[from] crate::types::ChatColorParseError),
+    /// invalid announcement color: {0}
+    InvalidAnnouncementColor(#[from] crate::types::AnnouncementColorError),
+    /// {0} user ids given, at most 100 are allowed
+    TooManyUserIds(usize),
+    /// message of {0} characters is too long, at most {1} are allowed
+    MessageTooLong(usize, usize),
+    /// `first` of {0} is too large, at most {1} are allowed
+    FirstTooLarge(usize, usize),
+    /// `msg_id` {0} is used by more than one message
+    DuplicateMsgId(crate::types::MsgId),
+    /// `winning_outcome_id` is required when resolving a prediction
+    MissingWinningOutcome,
+    /// `winning_outcome_id` is only allowed when resolving a prediction
+    UnexpectedWinningOutcome,
+    /// required parameter `{field}` is empty
+    MissingRequiredParameter {
+        /// Name of the parameter
+        field: &'static str,
+    },
+    /// {0}
+    #[cfg(feature = "twitch_oauth2")]
+    ScopeError(#[from] ScopeError),
+}
+
+/// Query parameters that Twitch rejects with `400 Missing required parameter` when they are empty
+const REQUIRED_PARAMETERS: &[&str] = &["broadcaster_id", "moderator_id"];
+
+/// Reject `uri` if any of [`REQUIRED_PARAMETERS`] is given but empty, e.g. `broadcaster_id` set to `""`
+fn validate_required_parameters(uri: &http::Uri) -> Result<(), RequestValidationError> {
+    for pair in uri.query().unwrap_or_default().split('&') {
+        let mut pair = pair.splitn(2, '=');
+        let (key, value) = (pair.next().unwrap_or_default(), pair.next());
+        if let Some(field) = REQUIRED_PARAMETERS
+            .iter()
+            .copied()
+            .find(|field| *field == key)
+        {
+            if value.map_or(true, str::is_empty) {
+                return Err(RequestValidationError::MissingRequiredParameter { field });
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_validate_required_parameters() {
+    let check = |uri: &str| validate_required_parameters(&uri.parse().unwrap());
+    assert!(check("https://api.twitch.tv/helix/moderation/banned?broadcaster_id=1234").is_ok());
+    assert!(check("https://api.twitch.tv/helix/moderation/automod/message?").is_ok());
+    assert!(matches!(
+        check("https://api.twitch.tv/helix/moderation/banned?broadcaster_id=&user_id=1"),
+        Err(RequestValidationError::MissingRequiredParameter {
+            field: "broadcaster_id"
+        })
+    ));
+    assert!(matches!(
+        check("https://api.twitch.tv/helix/moderation/bans?broadcaster_id=1&moderator_id="),
+        Err(RequestValidationError::MissingRequiredParameter {
+            field: "moderator_id"
+        })
+    ));
+}
+
+/// Reject `uri` if it asks for more than `max_first` items per page, which Twitch answers with `400 Bad Request`
+fn validate_first(uri: &http::Uri, max_first: usize) -> Result<(), RequestValidationError> {
+    for pair in uri.query().unwrap_or_default().split('&') {
+        let mut pair = pair.splitn(2, '=');
+        if pair.next() != Some("first") {
+            continue;
+        }
+        if let Some(first) = pair.next().and_then(|first| first.parse::<usize>().ok()) {
+            if first > max_first {
+                return Err(RequestValidationError::FirstTooLarge(first, max_first));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_validate_first() {
+    let check = |uri: &str| validate_first(&uri.parse().unwrap(), 100);
+    assert!(check("https://api.twitch.tv/helix/moderation/banned/events?broadcaster_id=1").is_ok());
+    assert!(check("https://api.twitch.tv/helix/moderation/banned/events?first=100").is_ok());
+    assert!(matches!(
+        check("https://api.twitch.tv/helix/moderation/banned/events?broadcaster_id=1&first=101"),
+        Err(RequestValidationError::FirstTooLarge(101, 100))
+    ));
+}
+
+/// Token can not be used for a request, see [`Request::can_execute`]
+#[cfg(feature = "twitch_oauth2")]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ScopeError {
+    /// Token is missing these scopes needed by the endpoint
+    MissingScopes(Vec<twitch_oauth2::Scope>),
+    /// Endpoint needs a user token, but an app access token was given
+    UserTokenRequired,
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl std::fmt::Display for ScopeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScopeError::MissingScopes(scopes) => {
+                write!(f, "token is missing required scopes: ")?;
+                for (i, scope) in scopes.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", scope)?;
+                }
+                Ok(())
+            }
+            ScopeError::UserTokenRequired => write!(
+                f,
+                "endpoint needs a user token, but an app access token was given"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl std::error::Error for ScopeError {}
+
 /// Errors that can happen when creating [`http::Uri`] for [`Request`]
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[derive(Debug, Clone, displaydoc::Display)]
 pub enum InvalidUri {
     /// URI could not be parsed
-    UriParseError(#[from] http::uri::InvalidUri),
+    UriParseError(std::sync::Arc<http::uri::InvalidUri>),
     /// Could not serialize request to query
-    QuerySerializeError(#[from] ser::Error),
+    QuerySerializeError(ser::Error),
+}
+
+impl std::error::Error for InvalidUri {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidUri::UriParseError(e) => Some(&**e),
+            InvalidUri::QuerySerializeError(e) => Some(e),
+        }
+    }
+}
+
+impl From<ser::Error> for InvalidUri {
+    fn from(e: ser::Error) -> Self { InvalidUri::QuerySerializeError(e) }
+}
+
+impl From<http::uri::InvalidUri> for InvalidUri {
+    fn from(e: http::uri::InvalidUri) -> Self { InvalidUri::UriParseError(std::sync::Arc::new(e)) }
 }
 
 /// Could not parse GET response
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[derive(Debug, Clone, displaydoc::Display)]
 pub enum HelixRequestGetError {
     /// helix returned error {status:?} - {error}: {message:?} when calling `GET {uri}`
     Error {
@@ -652,15 +4138,67 @@ pub enum HelixRequestGetError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Id of the request, see [`REQUEST_ID_HEADER`]
+        request_id: Option<String>,
+    },
+    /// endpoint `GET {uri}` has been removed by Twitch (410 Gone)
+    Gone {
+        /// URI to the endpoint
+        uri: http::Uri,
+        /// Id of the request, see [`REQUEST_ID_HEADER`]
+        request_id: Option<String>,
     },
     /// could not parse body as utf8: {1}
     Utf8Error(Vec<u8>, std::str::Utf8Error),
     /// deserialization failed when processing request result
-    DeserializeError(#[from] serde_json::Error),
+    DeserializeError(std::sync::Arc<serde_json::Error>),
+}
+
+impl std::error::Error for HelixRequestGetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HelixRequestGetError::DeserializeError(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for HelixRequestGetError {
+    fn from(e: serde_json::Error) -> Self {
+        HelixRequestGetError::DeserializeError(std::sync::Arc::new(e))
+    }
+}
+
+impl HelixRequestGetError {
+    /// The [request id](REQUEST_ID_HEADER) Twitch gave the failed request, if any
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            HelixRequestGetError::Error { request_id, .. }
+            | HelixRequestGetError::Gone { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The error returned by Twitch, if any
+    pub fn twitch_error(&self) -> Option<TwitchErrorResponse> {
+        match self {
+            HelixRequestGetError::Error {
+                error,
+                status,
+                message,
+                ..
+            } => Some(TwitchErrorResponse {
+                error: error.clone(),
+                status: status.as_u16(),
+                message: message.clone(),
+            }),
+            _ => None,
+        }
+    }
 }
 
 /// helix returned error {status:?} - {error}: {message:?} when calling `PUT {uri}` with a body
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[derive(thiserror::Error, Debug, Clone, displaydoc::Display)]
 pub struct HelixRequestPutError {
     /// Error message related to status code
     error: String,
@@ -670,12 +4208,28 @@ pub struct HelixRequestPutError {
     message: String,
     /// URI to the endpoint
     uri: http::Uri,
+    /// Id of the request, see [`REQUEST_ID_HEADER`]
+    request_id: Option<String>,
     /// Body sent with PUT
     body: Vec<u8>,
 }
 
+impl HelixRequestPutError {
+    /// The [request id](REQUEST_ID_HEADER) Twitch gave the failed request, if any
+    pub fn request_id(&self) -> Option<&str> { self.request_id.as_deref() }
+
+    /// The error returned by Twitch
+    pub fn twitch_error(&self) -> TwitchErrorResponse {
+        TwitchErrorResponse {
+            error: self.error.clone(),
+            status: self.status.as_u16(),
+            message: self.message.clone(),
+        }
+    }
+}
+
 /// Could not parse POST response
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[derive(Debug, Clone, displaydoc::Display)]
 pub enum HelixRequestPostError {
     /// helix returned error {status:?} - {error}: {message:?} when calling `POST {uri}` with a body
     Error {
@@ -687,17 +4241,61 @@ pub enum HelixRequestPostError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Id of the request, see [`REQUEST_ID_HEADER`]
+        request_id: Option<String>,
         /// Body sent with POST
         body: Vec<u8>,
     },
     /// could not parse body as utf8: {1}
     Utf8Error(Vec<u8>, std::str::Utf8Error),
     /// deserialization failed when processing request result
-    DeserializeError(#[from] serde_json::Error),
+    DeserializeError(std::sync::Arc<serde_json::Error>),
+}
+
+impl std::error::Error for HelixRequestPostError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HelixRequestPostError::DeserializeError(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for HelixRequestPostError {
+    fn from(e: serde_json::Error) -> Self {
+        HelixRequestPostError::DeserializeError(std::sync::Arc::new(e))
+    }
+}
+
+impl HelixRequestPostError {
+    /// The [request id](REQUEST_ID_HEADER) Twitch gave the failed request, if any
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            HelixRequestPostError::Error { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The error returned by Twitch, if any
+    pub fn twitch_error(&self) -> Option<TwitchErrorResponse> {
+        match self {
+            HelixRequestPostError::Error {
+                error,
+                status,
+                message,
+                ..
+            } => Some(TwitchErrorResponse {
+                error: error.clone(),
+                status: status.as_u16(),
+                message: message.clone(),
+            }),
+            _ => None,
+        }
+    }
 }
 
 /// helix returned error {status:?}: {message:?} when calling `PATCH {uri}` with a body
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[derive(thiserror::Error, Debug, Clone, displaydoc::Display)]
 pub struct HelixRequestPatchError {
     /// Status code of error, usually 400-499
     status: http::StatusCode,
@@ -705,12 +4303,28 @@ pub struct HelixRequestPatchError {
     message: String,
     /// URI to the endpoint
     uri: http::Uri,
+    /// Id of the request, see [`REQUEST_ID_HEADER`]
+    request_id: Option<String>,
     /// Body sent with PATCH
     body: Vec<u8>,
 }
 
+impl HelixRequestPatchError {
+    /// The [request id](REQUEST_ID_HEADER) Twitch gave the failed request, if any
+    pub fn request_id(&self) -> Option<&str> { self.request_id.as_deref() }
+
+    /// The error returned by Twitch
+    pub fn twitch_error(&self) -> TwitchErrorResponse {
+        TwitchErrorResponse {
+            error: self.status.canonical_reason().unwrap_or("").to_string(),
+            status: self.status.as_u16(),
+            message: self.message.clone(),
+        }
+    }
+}
+
 /// Could not parse DELETE response
-#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[derive(thiserror::Error, Debug, Clone, displaydoc::Display)]
 pub enum HelixRequestDeleteError {
     /// helix returned error {status:?}- {error}: {message:?} when calling `DELETE {uri}`
     Error {
@@ -722,7 +4336,36 @@ pub enum HelixRequestDeleteError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Id of the request, see [`REQUEST_ID_HEADER`]
+        request_id: Option<String>,
     },
     /// could not parse body as utf8: {1}
     Utf8Error(Vec<u8>, std::str::Utf8Error),
 }
+
+impl HelixRequestDeleteError {
+    /// The [request id](REQUEST_ID_HEADER) Twitch gave the failed request, if any
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            HelixRequestDeleteError::Error { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The error returned by Twitch, if any
+    pub fn twitch_error(&self) -> Option<TwitchErrorResponse> {
+        match self {
+            HelixRequestDeleteError::Error {
+                error,
+                status,
+                message,
+                ..
+            } => Some(TwitchErrorResponse {
+                error: error.clone(),
+                status: status.as_u16(),
+                message: message.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
@@ -2,18 +2,1119 @@
 
 use crate::{helix, types};
 #[doc(inline)]
+pub use add_channel_moderator::{
+    AddChannelModerator, AddChannelModeratorError, AddChannelModeratorRequest,
+};
+#[doc(inline)]
+pub use ban_user::{BanUser, BanUserBody, BanUserError, BanUserRequest};
+#[doc(inline)]
 pub use check_automod_status::{
-    CheckAutoModStatus, CheckAutoModStatusBody, CheckAutoModStatusRequest,
+    CheckAutoModStatus, CheckAutoModStatusBody, CheckAutoModStatusRequest, MissingAutoModStatus,
 };
 #[doc(inline)]
-pub use get_banned_events::{BannedEvent, GetBannedEventsRequest};
+pub use get_automod_settings::{AutoModSettings, GetAutoModSettingsRequest};
+#[doc(inline)]
+pub use get_banned_events::{BanAction, BannedEvent, BannedEventData, GetBannedEventsRequest};
 #[doc(inline)]
 pub use get_banned_users::{BannedUser, GetBannedUsersRequest};
 #[doc(inline)]
-pub use get_moderator_events::{GetModeratorEventsRequest, ModeratorEvent};
+pub use get_moderator_events::{GetModeratorEventsRequest, ModeratorEvent, ModeratorEventData};
 #[doc(inline)]
 pub use get_moderators::{GetModeratorsRequest, Moderator};
+#[doc(inline)]
+pub use manage_held_automod_messages::{
+    AutoModAction, HeldAutoModMessage, ManageHeldAutoModMessages, ManageHeldAutoModMessagesBody,
+    ManageHeldAutoModMessagesRequest,
+};
+#[doc(inline)]
+pub use remove_channel_moderator::{
+    RemoveChannelModerator, RemoveChannelModeratorError, RemoveChannelModeratorRequest,
+};
 use serde::{Deserialize, Serialize};
+#[doc(inline)]
+pub use unban_user::{UnbanUser, UnbanUserError, UnbanUserRequest};
+
+/// Most endpoints filtering by `user_id` accept at most 100 of them
+fn validate_user_ids(user_ids: &[types::UserId]) -> Result<(), helix::RequestValidationError> {
+    if user_ids.len() > 100 {
+        return Err(helix::RequestValidationError::TooManyUserIds(
+            user_ids.len(),
+        ));
+    }
+    Ok(())
+}
+
+/// Parse a list endpoint whose `data` Twitch may send as a single object, see [`types::deserialize_vec_or_single`]
+fn parse_vec_or_single<R, T>(
+    request: R,
+    uri: &http::Uri,
+    response: http::Response<Vec<u8>>,
+) -> Result<helix::Response<R, Vec<T>>, helix::HelixRequestGetError>
+where
+    R: helix::Request<Response = Vec<T>>,
+    T: serde::de::DeserializeOwned + PartialEq, {
+    #[derive(Deserialize)]
+    #[serde(bound(deserialize = "T: Deserialize<'de>"))]
+    struct InnerResponse<T> {
+        #[serde(deserialize_with = "types::deserialize_vec_or_single")]
+        data: Vec<T>,
+        #[serde(default)]
+        pagination: helix::Pagination,
+    }
+
+    let (text, request_id) = helix::get_response_text(uri, &response)?;
+    let status = response.status();
+    let response: InnerResponse<T> = helix::parse_json(text)?;
+    Ok(helix::Response {
+        data: response.data,
+        pagination: response.pagination,
+        request,
+        request_id,
+        status,
+        #[cfg(feature = "raw_json")]
+        raw: helix::raw_json(text),
+    })
+}
+
+/// [Check AutoMod Status](check_automod_status) accepts at most 100 messages per call
+#[cfg(feature = "client")]
+const MAX_AUTOMOD_BODIES: usize = 100;
+
+/// Every `msg_id` sent to [Check AutoMod Status](check_automod_status) must be unique, or the statuses can't be told apart
+fn validate_msg_ids(
+    bodies: &[CheckAutoModStatusBody],
+) -> Result<(), helix::RequestValidationError> {
+    let mut msg_ids = std::collections::HashSet::new();
+    match bodies.iter().find(|body| !msg_ids.insert(&body.msg_id)) {
+        Some(body) => Err(helix::RequestValidationError::DuplicateMsgId(
+            body.msg_id.clone(),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Metadata of an endpoint in this module, see [`endpoints`]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub struct EndpointInfo {
+    /// Name of the endpoint in the Twitch API reference, e.g. `Get Moderators`
+    pub name: &'static str,
+    /// Path of the endpoint, relative to `helix/`
+    pub path: &'static str,
+    /// HTTP method used to call the endpoint
+    pub method: http::Method,
+    /// Scopes needed to call the endpoint
+    #[cfg(feature = "twitch_oauth2")]
+    pub scopes: &'static [twitch_oauth2::Scope],
+    /// Whether the response can be paginated
+    pub paginated: bool,
+}
+
+impl EndpointInfo {
+    fn of<R: helix::Request>(name: &'static str, paginated: bool) -> Self {
+        EndpointInfo {
+            name,
+            path: R::PATH,
+            method: R::METHOD,
+            #[cfg(feature = "twitch_oauth2")]
+            scopes: R::SCOPE,
+            paginated,
+        }
+    }
+}
+
+/// Metadata of every endpoint in this module. Add new endpoints here to keep [`required_scopes`] correct.
+///
+/// ```rust
+/// let endpoints = twitch_api2::helix::moderation::endpoints();
+/// assert!(endpoints.iter().any(|e| e.path == "moderation/banned" && e.paginated));
+/// ```
+pub fn endpoints() -> Vec<EndpointInfo> {
+    vec![
+        EndpointInfo::of::<AddChannelModeratorRequest>("Add Channel Moderator", false),
+        EndpointInfo::of::<BanUserRequest>("Ban User", false),
+        EndpointInfo::of::<CheckAutoModStatusRequest>("Check AutoMod Status", false),
+        EndpointInfo::of::<GetAutoModSettingsRequest>("Get AutoMod Settings", false),
+        EndpointInfo::of::<GetBannedEventsRequest>("Get Banned Events", true),
+        EndpointInfo::of::<GetBannedUsersRequest>("Get Banned Users", true),
+        EndpointInfo::of::<GetModeratorEventsRequest>("Get Moderator Events", true),
+        EndpointInfo::of::<GetModeratorsRequest>("Get Moderators", true),
+        EndpointInfo::of::<ManageHeldAutoModMessagesRequest>("Manage Held AutoMod Messages", false),
+        EndpointInfo::of::<RemoveChannelModeratorRequest>("Remove Channel Moderator", false),
+        EndpointInfo::of::<UnbanUserRequest>("Unban User", false),
+    ]
+}
+
+/// All scopes needed to use every endpoint in this module, without duplicates.
+///
+/// ```rust
+/// let scopes = twitch_api2::helix::moderation::required_scopes();
+/// assert!(scopes.contains(&twitch_oauth2::Scope::ModerationRead));
+/// ```
+#[cfg(feature = "twitch_oauth2")]
+pub fn required_scopes() -> Vec<twitch_oauth2::Scope> {
+    let mut scopes: Vec<twitch_oauth2::Scope> = vec![];
+    for scope in endpoints().iter().flat_map(|e| e.scopes) {
+        if !scopes.contains(scope) {
+            scopes.push(scope.clone());
+        }
+    }
+    scopes
+}
+
+/// Names of the endpoints in this module that need `scope`, e.g. to explain what granting a scope enables.
+///
+/// ```rust
+/// let endpoints =
+///     twitch_api2::helix::moderation::endpoints_for_scope(&twitch_oauth2::Scope::ModerationRead);
+/// assert!(endpoints.contains(&"Get Moderators"));
+/// ```
+#[cfg(feature = "twitch_oauth2")]
+pub fn endpoints_for_scope(scope: &twitch_oauth2::Scope) -> Vec<&'static str> {
+    endpoints()
+        .into_iter()
+        .filter(|e| e.scopes.contains(scope))
+        .map(|e| e.name)
+        .collect()
+}
+
+/// A user in a channel, e.g. a [moderator](Moderator) or a [banned user](BannedUser)
+///
+/// Lets code that only needs to show who a user is accept any of these with `&impl ChannelUserRef`.
+pub trait ChannelUserRef {
+    /// ID of the user
+    fn user_id(&self) -> &types::UserId;
+    /// Display name of the user
+    fn display_name(&self) -> &types::DisplayName;
+}
+
+impl ChannelUserRef for Moderator {
+    fn user_id(&self) -> &types::UserId { &self.user_id }
+
+    fn display_name(&self) -> &types::DisplayName { &self.user_name }
+}
+
+impl ChannelUserRef for BannedUser {
+    fn user_id(&self) -> &types::UserId { &self.user_id }
+
+    fn display_name(&self) -> &types::DisplayName { &self.user_name }
+}
+
+/// `User (1234)`
+impl std::fmt::Display for Moderator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.user_name, self.user_id)
+    }
+}
+
+/// `User (1234)`, or `User (1234) until 2021-01-01T12:00:00Z` for timeouts
+impl std::fmt::Display for BannedUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.user_name, self.user_id)?;
+        match &self.expires_at {
+            Some(expires_at) if self.is_timeout() => write!(f, " until {}", expires_at.as_str()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// `User (1234)`, or `User (1234) until 2021-01-01T12:00:00Z` for timeouts
+impl std::fmt::Display for BannedEventData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.user_name, self.user_id)?;
+        match &self.expires_at {
+            Some(expires_at) => write!(f, " until {}", expires_at.as_str()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// `User (1234)`
+impl std::fmt::Display for ModeratorEventData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.user_name, self.user_id)
+    }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+#[test]
+fn test_endpoints_for_scope() {
+    assert_eq!(
+        endpoints_for_scope(&twitch_oauth2::Scope::ModeratorManageBannedUsers),
+        vec!["Ban User", "Unban User"]
+    );
+    assert_eq!(
+        endpoints_for_scope(&twitch_oauth2::Scope::ModerationRead).len(),
+        5
+    );
+    assert!(endpoints_for_scope(&twitch_oauth2::Scope::UserReadEmail).is_empty());
+}
+
+#[test]
+fn test_endpoints() {
+    let endpoints = endpoints();
+    assert_eq!(endpoints.len(), 11);
+    let bans: Vec<_> = endpoints
+        .iter()
+        .filter(|e| e.path == "moderation/bans")
+        .map(|e| e.method.clone())
+        .collect();
+    assert_eq!(bans, vec![http::Method::POST, http::Method::DELETE]);
+    assert!(endpoints
+        .iter()
+        .filter(|e| e.paginated)
+        .all(|e| e.method == http::Method::GET));
+    assert_eq!(
+        <UnbanUserRequest as helix::Request>::METHOD,
+        http::Method::DELETE
+    );
+}
+
+#[test]
+fn test_moderator_set() {
+    use std::collections::HashSet;
+
+    let moderator = |id: &str| Moderator {
+        user_id: id.to_string(),
+        user_login: format!("user{}", id).into(),
+        user_name: format!("User{}", id),
+    };
+    let set: HashSet<Moderator> = vec![moderator("1"), moderator("2"), moderator("1")]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&moderator("2")));
+}
+
+#[test]
+fn test_channel_user_ref() {
+    fn describe(user: &impl ChannelUserRef) -> String {
+        format!("{} ({})", user.display_name(), user.user_id())
+    }
+
+    let moderator = Moderator {
+        user_id: "1".to_string(),
+        user_login: "user1".into(),
+        user_name: "User1".to_string(),
+    };
+    assert_eq!(describe(&moderator), "User1 (1)");
+
+    let banned = BannedUser {
+        user_id: "2".to_string(),
+        user_name: "User2".to_string(),
+        expires_at: None,
+    };
+    assert_eq!(describe(&banned), "User2 (2)");
+}
+
+#[test]
+fn test_display() {
+    let moderator = Moderator {
+        user_id: "424596340".to_string(),
+        user_login: "quotrok".into(),
+        user_name: "quotrok".to_string(),
+    };
+    assert_eq!(moderator.to_string(), "quotrok (424596340)");
+
+    let mut banned = BannedUser {
+        user_id: "424596340".to_string(),
+        user_name: "quotrok".to_string(),
+        expires_at: None,
+    };
+    assert_eq!(banned.to_string(), "quotrok (424596340)");
+    banned.expires_at = Some("2021-01-01T12:00:00Z".into());
+    assert_eq!(
+        banned.to_string(),
+        "quotrok (424596340) until 2021-01-01T12:00:00Z"
+    );
+
+    let event = ModeratorEvent {
+        id: "1IVBTnDSUDApiBQW4UBcVTK4hPr".to_string(),
+        event_type: "moderation.moderator.remove".to_string(),
+        event_timestamp: "2019-03-15T18:18:14Z".into(),
+        version: "1.0".to_string(),
+        event_data: ModeratorEventData {
+            broadcaster_id: "198704263".to_string(),
+            broadcaster_name: "aan22209".to_string(),
+            user_id: "423374343".to_string(),
+            user_name: "glowillig".to_string(),
+        },
+    };
+    assert_eq!(
+        event.to_string(),
+        "2019-03-15T18:18:14Z moderation.moderator.remove: glowillig (423374343)"
+    );
+}
+
+/// Counts of [banned users](BannedUser) by kind of ban, see [`summarize_banned`]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct BanSummary {
+    /// Users banned permanently
+    pub permanent: usize,
+    /// Users in a timeout that has not ended yet
+    pub timeouts: usize,
+    /// Users in a timeout that has ended
+    pub expired: usize,
+}
+
+/// Count permanent bans, active timeouts and expired timeouts in a [Get Banned Users](get_banned_users) list
+///
+/// Timeouts are checked against `now`, see [`BannedUser::is_expired`].
+pub fn summarize_banned(users: &[BannedUser], now: &types::Timestamp) -> BanSummary {
+    let mut summary = BanSummary::default();
+    for user in users {
+        if !user.is_timeout() {
+            summary.permanent += 1;
+        } else if user.is_expired(now) {
+            summary.expired += 1;
+        } else {
+            summary.timeouts += 1;
+        }
+    }
+    summary
+}
+
+#[test]
+fn test_summarize_banned() {
+    let banned = |id: &str, expires_at: Option<&str>| BannedUser {
+        user_id: id.to_string(),
+        user_name: format!("User{}", id),
+        expires_at: expires_at.map(types::Timestamp::new),
+    };
+    let users = vec![
+        banned("1", None),
+        banned("2", Some("")),
+        banned("3", Some("2021-01-01T12:00:00Z")),
+        banned("4", Some("2021-01-01T11:00:00Z")),
+        banned("5", Some("2021-01-01T10:00:00Z")),
+    ];
+
+    assert_eq!(
+        summarize_banned(&users, &"2021-01-01T11:00:00Z".into()),
+        BanSummary {
+            permanent: 2,
+            timeouts: 1,
+            expired: 2,
+        }
+    );
+    assert_eq!(
+        summarize_banned(&[], &"2021-01-01T11:00:00Z".into()),
+        BanSummary::default()
+    );
+
+    // Timestamps are compared as instants
+    let now = "2021-01-01T11:00:00.000Z".into();
+    assert!(banned("6", Some("2021-01-01T11:00:00Z")).is_expired(&now));
+    assert!(banned("7", Some("2021-01-01T11:30:00+01:00")).is_expired(&now));
+    assert!(!banned("8", Some("2021-01-01T11:00:00.5Z")).is_expired(&now));
+    assert!(!banned("9", Some("tomorrow")).is_expired(&now));
+}
+
+/// Difference between two lists of [moderators](Moderator), see [`diff_moderators`]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ModeratorDiff {
+    /// Moderators in the new list that are not in the old list
+    pub added: Vec<Moderator>,
+    /// Moderators in the old list that are not in the new list
+    pub removed: Vec<Moderator>,
+}
+
+/// Compute which moderators were added and removed between two lists, e.g. two [Get Moderators](get_moderators) snapshots.
+///
+/// Moderators are compared by [`user_id`](Moderator::user_id). Order of each list is kept.
+pub fn diff_moderators(old: &[Moderator], new: &[Moderator]) -> ModeratorDiff {
+    use std::collections::HashSet;
+
+    let old_ids: HashSet<&types::UserId> = old.iter().map(|m| &m.user_id).collect();
+    let new_ids: HashSet<&types::UserId> = new.iter().map(|m| &m.user_id).collect();
+    ModeratorDiff {
+        added: new
+            .iter()
+            .filter(|m| !old_ids.contains(&m.user_id))
+            .cloned()
+            .collect(),
+        removed: old
+            .iter()
+            .filter(|m| !new_ids.contains(&m.user_id))
+            .cloned()
+            .collect(),
+    }
+}
+
+#[test]
+fn test_diff_moderators() {
+    let moderator = |id: &str| Moderator {
+        user_id: id.to_string(),
+        user_login: format!("user{}", id).into(),
+        user_name: format!("User{}", id),
+    };
+    let old = vec![moderator("1"), moderator("2"), moderator("3")];
+    let new = vec![
+        moderator("2"),
+        moderator("4"),
+        moderator("3"),
+        moderator("5"),
+    ];
+
+    let diff = diff_moderators(&old, &new);
+    assert_eq!(diff.added, vec![moderator("4"), moderator("5")]);
+    assert_eq!(diff.removed, vec![moderator("1")]);
+
+    assert_eq!(diff_moderators(&old, &old), ModeratorDiff::default());
+    assert_eq!(diff_moderators(&[], &old).added, old);
+    assert_eq!(diff_moderators(&old, &[]).removed, old);
+}
+
+/// Bans and unbans per hour, see [`moderation_rate`]
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ModerationRate {
+    /// Bans and timeouts per hour, keyed by the start of the hour
+    pub bans: Vec<(types::Timestamp, u32)>,
+    /// Unbans per hour, keyed by the start of the hour
+    pub unbans: Vec<(types::Timestamp, u32)>,
+}
+
+/// Count [Get Banned Events](get_banned_events) per hour, split by [action](BannedEvent::action)
+///
+/// Events are bucketed by the hour of their [`event_timestamp`](helix::Event::event_timestamp) in UTC.
+/// Buckets are sorted oldest first, hours without events and events with invalid timestamps are left out.
+pub fn moderation_rate(events: &[BannedEvent]) -> ModerationRate {
+    use std::collections::BTreeMap;
+
+    let mut bans = BTreeMap::new();
+    let mut unbans = BTreeMap::new();
+    for event in events {
+        // `2019-03-13T15:55:14Z` is in the hour starting at `2019-03-13T15:00:00Z`
+        let hour = match event.event_timestamp.to_unix() {
+            Some((secs, _)) => types::Timestamp::from_unix(secs - secs.rem_euclid(3600)),
+            None => continue,
+        };
+        let buckets = match event.action() {
+            BanAction::Unban => &mut unbans,
+            _ => &mut bans,
+        };
+        *buckets.entry(hour).or_insert(0) += 1;
+    }
+    ModerationRate {
+        bans: bans.into_iter().collect(),
+        unbans: unbans.into_iter().collect(),
+    }
+}
+
+#[test]
+fn test_moderation_rate() {
+    let event = |event_type: &str, timestamp: &str| BannedEvent {
+        id: timestamp.to_string(),
+        event_type: event_type.to_string(),
+        event_timestamp: timestamp.into(),
+        version: "1.0".to_string(),
+        event_data: BannedEventData {
+            broadcaster_id: "1234".to_string(),
+            broadcaster_name: "Broadcaster".to_string(),
+            user_id: "5678".to_string(),
+            user_name: "User".to_string(),
+            expires_at: None,
+        },
+    };
+    // Newest first, like Twitch returns them
+    let events = vec![
+        event("moderation.user.unban", "2021-01-01T12:30:00Z"),
+        event("moderation.user.ban", "2021-01-01T12:10:00Z"),
+        event("moderation.user.ban", "2021-01-01T10:59:59Z"),
+        event("moderation.user.unban", "2021-01-01T10:20:00Z"),
+        event("moderation.user.ban", "2021-01-01T10:00:00Z"),
+    ];
+
+    let rate = moderation_rate(&events);
+    let hour = types::Timestamp::new;
+    assert_eq!(
+        rate.bans,
+        vec![
+            (hour("2021-01-01T10:00:00Z"), 2),
+            (hour("2021-01-01T12:00:00Z"), 1),
+        ]
+    );
+    assert_eq!(
+        rate.unbans,
+        vec![
+            (hour("2021-01-01T10:00:00Z"), 1),
+            (hour("2021-01-01T12:00:00Z"), 1),
+        ]
+    );
+    assert_eq!(moderation_rate(&[]), ModerationRate::default());
+
+    // Offsets are bucketed by the hour in UTC
+    let events = vec![
+        event("moderation.user.ban", "2021-01-01T00:10:00.5+01:00"),
+        event("moderation.user.ban", "2020-12-31T23:59:59Z"),
+    ];
+    assert_eq!(
+        moderation_rate(&events).bans,
+        vec![(hour("2020-12-31T23:00:00Z"), 2)]
+    );
+}
+
+/// Result of [`HelixClient::get_banned_users_by_login`](helix::HelixClient::get_banned_users_by_login)
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct BannedUsersByLogin {
+    /// Users that are banned in the channel
+    pub banned: Vec<BannedUser>,
+    /// Logins that did not match any user
+    pub unresolved: Vec<types::UserName>,
+}
+
+#[cfg(feature = "client")]
+impl<'a, C> helix::HelixClient<'a, C>
+where C: for<'c> crate::HttpClient<'c>
+{
+    /// Get all moderators of multiple channels, following pagination for each channel.
+    ///
+    /// At most `concurrency` channels are fetched at the same time. A failure for one channel is
+    /// reported in its entry and does not affect the other channels. Channels that are in
+    /// `broadcaster_ids` more than once are only fetched once.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::HelixClient;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// let client = HelixClient::new();
+    /// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+    /// let channels = vec!["1234".to_string(), "5678".to_string()];
+    /// for (channel, moderators) in client.get_moderators_multi(&channels, &token, 4).await {
+    ///     match moderators {
+    ///         Ok(moderators) => println!("{}: {} moderators", channel, moderators.len()),
+    ///         Err(e) => println!("{}: failed: {}", channel, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_moderators_multi<'c, T>(
+        &'c self,
+        broadcaster_ids: &[types::UserId],
+        token: &T,
+        concurrency: usize,
+    ) -> std::collections::HashMap<
+        types::UserId,
+        Result<Vec<Moderator>, helix::ClientRequestError<<C as crate::HttpClient<'c>>::Error>>,
+    >
+    where
+        T: twitch_oauth2::TwitchToken,
+    {
+        use futures::stream::{self, StreamExt};
+
+        let mut seen = std::collections::HashSet::new();
+        let broadcaster_ids = broadcaster_ids.iter().filter(|id| seen.insert(*id));
+        stream::iter(broadcaster_ids)
+            .map(|broadcaster_id| async move {
+                let request = GetModeratorsRequest::builder()
+                    .broadcaster_id(broadcaster_id.clone())
+                    .build();
+                (
+                    broadcaster_id.clone(),
+                    self.req_get_all_pages(request, token).await,
+                )
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Follow the pagination of `request`, moving the data of every page into one `Vec`
+    async fn req_get_all_pages<'c, R, D, T>(
+        &'c self,
+        mut request: R,
+        token: &T,
+    ) -> Result<Vec<D>, helix::ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        R: helix::Request<Response = Vec<D>> + helix::RequestGet + helix::Paginated + Clone,
+        D: serde::de::DeserializeOwned + PartialEq,
+        T: twitch_oauth2::TwitchToken + ?Sized,
+    {
+        let mut data: Vec<D> = vec![];
+        let mut last_page = None;
+        loop {
+            let response = self.req_get(request.clone(), token).await?;
+            // Twitch can answer the cursor of the last page with that page again, see `Response::get_next`
+            if last_page == Some(response.data.len()) && data.ends_with(&response.data) {
+                return Ok(data);
+            }
+            last_page = Some(response.data.len());
+            data.extend(response.data);
+            match response.pagination.cursor {
+                Some(cursor) => request.set_pagination(Some(cursor)),
+                None => return Ok(data),
+            }
+        }
+    }
+
+    /// Get all ban and unban events in a channel that happened at or after `since`.
+    ///
+    /// Twitch returns events newest first, so pagination stops at the first event older than `since`
+    /// instead of fetching the entire history.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::HelixClient;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// let client = HelixClient::new();
+    /// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+    /// let events = client
+    ///     .banned_events_since("1234", "2020-11-01T00:00:00Z".into(), &token)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn banned_events_since<'c, T>(
+        &'c self,
+        broadcaster_id: impl Into<types::UserId>,
+        since: types::Timestamp,
+        token: &T,
+    ) -> Result<Vec<BannedEvent>, helix::ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        T: twitch_oauth2::TwitchToken,
+    {
+        let request = GetBannedEventsRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .first(100)
+            .build();
+        let mut response = self.req_get(request, token).await?;
+        let mut events = vec![];
+        loop {
+            for event in response.data.iter() {
+                if event.event_timestamp.cmp_instant(&since) == Some(std::cmp::Ordering::Less) {
+                    return Ok(events);
+                }
+                events.push(event.clone());
+            }
+            match response.get_next(self, token).await? {
+                Some(next) => response = next,
+                None => return Ok(events),
+            }
+        }
+    }
+
+    /// Get the [moderation rate](moderation_rate) of a channel between `since` and `until`
+    ///
+    /// Fetches the events with [`banned_events_since`](helix::HelixClient::banned_events_since) and leaves out events at or after `until`.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::HelixClient;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// let client = HelixClient::new();
+    /// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+    /// let rate = client
+    ///     .moderation_rate(
+    ///         "1234",
+    ///         "2020-11-01T00:00:00Z".into(),
+    ///         "2020-11-02T00:00:00Z".into(),
+    ///         &token,
+    ///     )
+    ///     .await?;
+    /// for (hour, bans) in rate.bans {
+    ///     println!("{}: {} bans", hour.as_str(), bans);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn moderation_rate<'c, T>(
+        &'c self,
+        broadcaster_id: impl Into<types::UserId>,
+        since: types::Timestamp,
+        until: types::Timestamp,
+        token: &T,
+    ) -> Result<ModerationRate, helix::ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        T: twitch_oauth2::TwitchToken,
+    {
+        let mut events = self
+            .banned_events_since(broadcaster_id, since, token)
+            .await?;
+        events.retain(|event| event.event_timestamp < until);
+        Ok(moderation_rate(&events))
+    }
+
+    /// Allow or deny a message held by AutoMod, straight from an EventSub `automod.message.hold` event.
+    ///
+    /// `moderator_id` is the user resolving the message and must match the user in `token`.
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::HelixClient;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// use twitch_api2::helix::moderation::{AutoModAction, HeldAutoModMessage};
+    /// let client = HelixClient::new();
+    /// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+    /// # let event_json = "";
+    /// let held: HeldAutoModMessage = serde_json::from_str(event_json)?;
+    /// client
+    ///     .resolve_held_message("9327994", &held, AutoModAction::Allow, &token)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_held_message<'c, T>(
+        &'c self,
+        moderator_id: impl Into<types::UserId>,
+        held_event: &HeldAutoModMessage,
+        action: AutoModAction,
+        token: &T,
+    ) -> Result<
+        ManageHeldAutoModMessages,
+        helix::ClientRequestError<<C as crate::HttpClient<'c>>::Error>,
+    >
+    where
+        T: twitch_oauth2::TwitchToken,
+    {
+        let request = ManageHeldAutoModMessagesRequest::builder().build();
+        let body = held_event.to_body(moderator_id, action);
+        Ok(self.req_post(request, body, token).await?.data)
+    }
+
+    /// Get the banned users in a channel among `logins`, resolving the logins to user ids with [Get Users](helix::users::get_users) first.
+    ///
+    /// Logins are looked up 100 at a time. Logins that do not match any user are returned in [`BannedUsersByLogin::unresolved`].
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::HelixClient;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// let client = HelixClient::new();
+    /// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+    /// let logins = vec!["justintv".into(), "twitchdev".into()];
+    /// let result = client
+    ///     .get_banned_users_by_login("1234", &logins, &token)
+    ///     .await?;
+    /// for user in result.banned {
+    ///     println!("{} is banned", user.user_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_banned_users_by_login<'c, T>(
+        &'c self,
+        broadcaster_id: impl Into<types::UserId>,
+        logins: &[types::UserName],
+        token: &T,
+    ) -> Result<BannedUsersByLogin, helix::ClientRequestError<<C as crate::HttpClient<'c>>::Error>>
+    where
+        T: twitch_oauth2::TwitchToken,
+    {
+        let broadcaster_id = broadcaster_id.into();
+        let mut result = BannedUsersByLogin::default();
+        let mut user_ids = vec![];
+        for logins in logins.chunks(100) {
+            let request = helix::users::GetUsersRequest::builder()
+                .login(logins.to_vec())
+                .build();
+            let users = self.req_get(request, token).await?.data;
+            result.unresolved.extend(
+                logins
+                    .iter()
+                    .filter(|login| {
+                        !users
+                            .iter()
+                            .any(|user| user.login.eq_ignore_ascii_case(login.as_str()))
+                    })
+                    .cloned(),
+            );
+            user_ids.extend(users.into_iter().map(|user| user.id));
+        }
+        for user_ids in user_ids.chunks(100) {
+            let request = GetBannedUsersRequest::builder()
+                .broadcaster_id(broadcaster_id.clone())
+                .user_id(user_ids.to_vec())
+                .build();
+            result
+                .banned
+                .extend(self.req_get_all_pages(request, token).await?);
+        }
+        Ok(result)
+    }
+
+    /// Check any number of messages with [Check AutoMod Status](check_automod_status), which takes at most 100 messages per call.
+    ///
+    /// The messages are sent 100 at a time, at most four calls at the same time. The statuses are returned in the order of `bodies`.
+    /// Every `msg_id` must be unique across all of `bodies`, otherwise nothing is sent and [`RequestValidationError::DuplicateMsgId`](helix::RequestValidationError::DuplicateMsgId) is returned.
+    /// A message Twitch returned no status for only makes its own entry a [`MissingAutoModStatus`], like [`in_body_order`](helix::Response::in_body_order).
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # use twitch_api2::helix::HelixClient;
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+    /// use twitch_api2::helix::moderation::CheckAutoModStatusBody;
+    /// let client = HelixClient::new();
+    /// # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+    /// let bodies: Vec<_> = (0..250)
+    ///     .map(|i| {
+    ///         CheckAutoModStatusBody::builder()
+    ///             .msg_id(i.to_string())
+    ///             .msg_text("hello")
+    ///             .user_id("5678")
+    ///             .build()
+    ///     })
+    ///     .collect();
+    /// let statuses = client
+    ///     .check_automod_status_batched("1234", &bodies, &token)
+    ///     .await?;
+    /// for (body, status) in bodies.iter().zip(statuses) {
+    ///     match status {
+    ///         Ok(status) => println!("{}: {}", body.msg_text, status.is_permitted),
+    ///         Err(e) => println!("{}: {}", body.msg_text, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_automod_status_batched<'c, T>(
+        &'c self,
+        broadcaster_id: impl Into<types::UserId>,
+        bodies: &[CheckAutoModStatusBody],
+        token: &T,
+    ) -> Result<
+        Vec<Result<CheckAutoModStatus, MissingAutoModStatus>>,
+        helix::ClientRequestError<<C as crate::HttpClient<'c>>::Error>,
+    >
+    where
+        T: twitch_oauth2::TwitchToken + ?Sized,
+    {
+        use futures::stream::{self, StreamExt, TryStreamExt};
+
+        const CONCURRENCY: usize = 4;
+
+        validate_msg_ids(bodies).map_err(helix::CreateRequestError::from)?;
+        let request = &CheckAutoModStatusRequest::builder()
+            .broadcaster_id(broadcaster_id)
+            .build();
+        let responses: Vec<_> = stream::iter(bodies.chunks(MAX_AUTOMOD_BODIES))
+            .map(|chunk| async move { self.req_post(request.clone(), chunk.to_vec(), token).await })
+            .buffered(CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        Ok(responses
+            .iter()
+            .zip(bodies.chunks(MAX_AUTOMOD_BODIES))
+            .flat_map(|(response, chunk)| response.in_body_order(chunk))
+            .map(|status| Ok(status?.clone()))
+            .collect())
+    }
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_get_moderators_multi() {
+    let token = helix::test::token(None);
+    // Channel 1 has two pages, the moderators of channel 3 can not be fetched
+    let client = helix::HelixClient::with_client(helix::test::MockClient::new(|_, req| {
+        let query = req.uri().query().unwrap_or_default();
+        let channel = query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("broadcaster_id="))
+            .unwrap();
+        let moderator =
+            |id: &str| serde_json::json!({"user_id": id, "user_login": id, "user_name": id});
+        Some(match channel {
+            "1" if !query.contains("after=") => helix::test::response(
+                200,
+                &serde_json::json!({"data": [moderator("10")], "pagination": {"cursor": "page2"}})
+                    .to_string(),
+            ),
+            "3" => helix::test::response(
+                400,
+                r#"{"error":"Bad Request","status":400,"message":"Invalid broadcaster_id"}"#,
+            ),
+            channel => helix::test::response(
+                200,
+                &serde_json::json!({"data": [moderator(&format!("{}1", channel))], "pagination": {}})
+                    .to_string(),
+            ),
+        })
+    }));
+    let channels: Vec<types::UserId> = vec!["1", "2", "3", "4", "5", "2"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let moderators = futures::executor::block_on(client.get_moderators_multi(&channels, &token, 2));
+    assert_eq!(moderators.len(), 5);
+    // Both pages of channel 1, one request per channel for the others, channel 2 only once
+    assert_eq!(client.client.requests().len(), 6);
+    assert_eq!(client.client.max_in_flight(), 2);
+    let ids = |channel: &str| -> Vec<String> {
+        moderators[channel]
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|m| m.user_id.clone())
+            .collect()
+    };
+    assert_eq!(ids("1"), ["10", "11"]);
+    assert_eq!(ids("2"), ["21"]);
+    assert_eq!(ids("5"), ["51"]);
+    match &moderators["3"] {
+        Err(helix::ClientRequestError::HelixRequestGetError(
+            helix::HelixRequestGetError::Error { status, .. },
+        )) => assert_eq!(*status, http::StatusCode::BAD_REQUEST),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_banned_events_since() {
+    let token = helix::test::token(None);
+    // Three pages of two events, newest first
+    let client = helix::HelixClient::with_client(helix::test::MockClient::new(|_, req| {
+        let query = req.uri().query().unwrap_or_default();
+        let (hours, cursor): (&[u32], _) = if query.contains("after=page3") {
+            (&[8, 7], None)
+        } else if query.contains("after=page2") {
+            (&[10, 9], Some("page3"))
+        } else {
+            (&[12, 11], Some("page2"))
+        };
+        let data: Vec<_> = hours
+            .iter()
+            .map(|hour| {
+                serde_json::json!({
+                    "id": hour.to_string(),
+                    "event_type": "moderation.user.ban",
+                    "event_timestamp": format!("2021-01-01T{:02}:00:00Z", hour),
+                    "version": "1.0",
+                    "event_data": {
+                        "broadcaster_id": "1234",
+                        "broadcaster_name": "broadcaster",
+                        "user_id": "5678",
+                        "user_name": "user",
+                        "expires_at": ""
+                    }
+                })
+            })
+            .collect();
+        Some(helix::test::response(
+            200,
+            &serde_json::json!({"data": data, "pagination": {"cursor": cursor}}).to_string(),
+        ))
+    }));
+    let ids =
+        |events: Vec<BannedEvent>| -> Vec<String> { events.into_iter().map(|e| e.id).collect() };
+
+    // Stops at the first event before the cutoff, without fetching the third page
+    let events = futures::executor::block_on(client.banned_events_since(
+        "1234",
+        "2021-01-01T09:30:00Z".into(),
+        &token,
+    ))
+    .unwrap();
+    assert_eq!(ids(events), ["12", "11", "10"]);
+    assert_eq!(client.client.requests().len(), 2);
+
+    // An event at the cutoff is kept, all pages are fetched
+    let events = futures::executor::block_on(client.banned_events_since(
+        "1234",
+        "2021-01-01T07:00:00Z".into(),
+        &token,
+    ))
+    .unwrap();
+    assert_eq!(ids(events), ["12", "11", "10", "9", "8", "7"]);
+    assert_eq!(client.client.requests().len(), 5);
+    let requests = client.client.requests();
+    assert!(requests[2].uri().query().unwrap().contains("first=100"));
+    assert!(requests[4].uri().query().unwrap().contains("after=page3"));
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_get_banned_users_by_login() {
+    let token = helix::test::token(None);
+    // Logins ending in 7 do not exist, users with an id divisible by 10 are banned.
+    // Banned users are returned on two pages, those below 50 on the first one.
+    let client = helix::HelixClient::with_client(helix::test::MockClient::new(|_, req| {
+        let query = req.uri().query().unwrap_or_default();
+        let values = |key: &str| -> Vec<String> {
+            query
+                .split('&')
+                .filter_map(|pair| pair.strip_prefix(key))
+                .map(String::from)
+                .collect()
+        };
+        let body = if req.uri().path().ends_with("/users") {
+            let users: Vec<_> = values("login=")
+                .iter()
+                .map(|login| login.to_lowercase())
+                .filter(|login| !login.ends_with('7'))
+                .map(|login| {
+                    let id = login.trim_start_matches("user").to_string();
+                    serde_json::json!({"id": id, "login": login, "display_name": login, "view_count": 0})
+                })
+                .collect();
+            serde_json::json!({ "data": users })
+        } else {
+            let first_page = !query.contains("after=page2");
+            let banned: Vec<_> = values("user_id=")
+                .iter()
+                .map(|id| id.parse::<u32>().unwrap())
+                .filter(|id| id % 10 == 0 && (*id < 50) == first_page)
+                .map(|id| {
+                    serde_json::json!({"user_id": id.to_string(), "user_login": "user", "expires_at": ""})
+                })
+                .collect();
+            let cursor = if first_page { Some("page2") } else { None };
+            serde_json::json!({"data": banned, "pagination": {"cursor": cursor}})
+        };
+        Some(helix::test::response(200, &body.to_string()))
+    }));
+    let logins: Vec<types::UserName> = (0..150).map(|i| format!("User{}", i).into()).collect();
+
+    let result =
+        futures::executor::block_on(client.get_banned_users_by_login("1234", &logins, &token))
+            .unwrap();
+    let unresolved: Vec<types::UserName> = (0..150)
+        .filter(|i| i % 10 == 7)
+        .map(|i| format!("User{}", i).into())
+        .collect();
+    assert_eq!(result.unresolved, unresolved);
+    let banned: Vec<_> = result
+        .banned
+        .iter()
+        .map(|user| user.user_id.clone())
+        .collect();
+    let expected: Vec<_> = (0..150).step_by(10).map(|i| i.to_string()).collect();
+    assert_eq!(banned, expected);
+
+    // 100 and 50 logins, then two pages each for 100 and 35 users
+    let requests = client.client.requests();
+    let sizes: Vec<_> = requests
+        .iter()
+        .map(|req| {
+            req.uri()
+                .query()
+                .unwrap()
+                .split('&')
+                .filter(|pair| pair.starts_with("login=") || pair.starts_with("user_id="))
+                .count()
+        })
+        .collect();
+    assert_eq!(sizes, [100, 50, 100, 100, 35, 35]);
+}
 
 /// Returns all moderators in a channel.
 /// [`get-moderators`](https://dev.twitch.tv/docs/api/reference#get-moderators)
@@ -42,7 +1143,7 @@ use serde::{Deserialize, Serialize};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_moderators::GetModeratorsRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -65,6 +1166,7 @@ pub mod get_moderators {
     /// [`get-moderators`](https://dev.twitch.tv/docs/api/reference#get-moderators)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetModeratorsRequest {
         /// Must match the User ID in the Bearer token.
         #[builder(setter(into))]
@@ -74,32 +1176,113 @@ pub mod get_moderators {
         pub after: Option<helix::Cursor>,
     }
 
+    impl GetModeratorsRequest {
+        /// Get the moderators of `broadcaster_id`, the common case of [`GetModeratorsRequest::builder()`]
+        ///
+        /// ```rust
+        /// # use twitch_api2::helix::moderation::GetModeratorsRequest;
+        /// assert_eq!(
+        ///     GetModeratorsRequest::for_broadcaster("1234"),
+        ///     GetModeratorsRequest::builder().broadcaster_id("1234").build()
+        /// );
+        /// ```
+        pub fn for_broadcaster(broadcaster_id: impl Into<types::UserId>) -> Self {
+            Self::builder().broadcaster_id(broadcaster_id).build()
+        }
+    }
+
     /// Return Values for [Get Moderators](super::get_moderators)
     ///
     /// [`get-moderators`](https://dev.twitch.tv/docs/api/reference#get-moderators)
-    #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    ///
+    /// Twitch has sent the login and display name as either `user_login` or `user_name`,
+    /// so if only one of them is present it is used for both fields.
+    #[derive(PartialEq, Eq, Hash, Deserialize, Serialize, Debug, Clone)]
+    #[serde(try_from = "RawModerator")]
     #[non_exhaustive]
     pub struct Moderator {
         /// User ID of moderator
         ///
         /// Twitch says: `User ID of a user who has been banned.` but this seems wrong.
         pub user_id: types::UserId,
+        /// Login of moderator
+        pub user_login: types::UserName,
         /// Display name of moderator
         ///
         /// Twitch says: `Display name of a user who has been banned.` but this seems wrong.
         pub user_name: types::DisplayName,
     }
 
+    /// [`Moderator`] as sent by Twitch, with either or both of `user_login` and `user_name`
+    #[derive(Deserialize)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    struct RawModerator {
+        user_id: types::UserId,
+        #[serde(default)]
+        user_login: Option<types::UserName>,
+        #[serde(default)]
+        user_name: Option<types::DisplayName>,
+    }
+
+    impl std::convert::TryFrom<RawModerator> for Moderator {
+        type Error = &'static str;
+
+        fn try_from(raw: RawModerator) -> Result<Self, Self::Error> {
+            let (user_login, user_name) = match (raw.user_login, raw.user_name) {
+                (Some(login), Some(name)) => (login, name),
+                (Some(login), None) => (login.clone(), login.into()),
+                (None, Some(name)) => (name.clone().into(), name),
+                (None, None) => return Err("missing field `user_login`"),
+            };
+            Ok(Moderator {
+                user_id: raw.user_id,
+                user_login,
+                user_name,
+            })
+        }
+    }
+
+    #[cfg(feature = "test-util")]
+    #[cfg_attr(nightly, doc(cfg(feature = "test-util")))]
+    impl Moderator {
+        /// Create a moderator, e.g. as a fixture in tests
+        pub fn new(
+            user_id: impl Into<types::UserId>,
+            user_login: impl Into<types::UserName>,
+            user_name: impl Into<types::DisplayName>,
+        ) -> Self {
+            Moderator {
+                user_id: user_id.into(),
+                user_login: user_login.into(),
+                user_name: user_name.into(),
+            }
+        }
+    }
+
     impl helix::Request for GetModeratorsRequest {
         type Response = Vec<Moderator>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "moderation/moderators";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
     }
 
-    impl helix::RequestGet for GetModeratorsRequest {}
+    impl helix::RequestGet for GetModeratorsRequest {
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestGetError,
+        >
+        where
+            Self: Sized,
+        {
+            super::parse_vec_or_single(self, uri, response)
+        }
+    }
 
     impl helix::Paginated for GetModeratorsRequest {
         fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
@@ -118,10 +1301,12 @@ pub mod get_moderators {
     "data": [
         {
             "user_id": "424596340",
+            "user_login": "quotrok",
             "user_name": "quotrok"
         },
         {
             "user_id": "424596340",
+            "user_login": "quotrok",
             "user_name": "quotrok"
         }
     ],
@@ -132,15 +1317,74 @@ pub mod get_moderators {
 "#
         .to_vec();
 
-        let http_response = http::Response::builder().body(data).unwrap();
+        let http_response = http::Response::builder()
+            .header("Twitch-Trace-Id", "0123456789abcdef")
+            .body(data)
+            .unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/moderation/moderators?broadcaster_id=198704263"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/moderators?broadcaster_id=198704263",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let res = dbg!(req.parse_response(&uri, http_response).unwrap());
+        assert_eq!(res.request_id.as_deref(), Some("0123456789abcdef"));
+
+        #[cfg(feature = "test-fixtures")]
+        helix::assert_fixture(&res.data, "moderation/get_moderators.json");
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn test_new() {
+        let moderator: Moderator = serde_json::from_str(
+            r#"{"user_id":"424596340","user_login":"quotrok","user_name":"Quotrok"}"#,
+        )
+        .unwrap();
+        assert_eq!(Moderator::new("424596340", "quotrok", "Quotrok"), moderator);
+    }
+
+    #[test]
+    fn test_user_login_or_user_name() {
+        let moderator: Moderator = serde_json::from_str(
+            r#"{"user_id":"424596340","user_login":"quotrok","user_name":"Quotrok"}"#,
+        )
+        .unwrap();
+        assert_eq!(moderator.user_login, "quotrok");
+        assert_eq!(moderator.user_name, "Quotrok");
+
+        let moderator: Moderator =
+            serde_json::from_str(r#"{"user_id":"424596340","user_name":"quotrok"}"#).unwrap();
+        assert_eq!(moderator.user_login, "quotrok");
+        assert_eq!(moderator.user_name, "quotrok");
+
+        let moderator: Moderator =
+            serde_json::from_str(r#"{"user_id":"424596340","user_login":"quotrok"}"#).unwrap();
+        assert_eq!(moderator.user_login, "quotrok");
+        assert_eq!(moderator.user_name, "quotrok");
+
+        assert!(serde_json::from_str::<Moderator>(r#"{"user_id":"424596340"}"#).is_err());
+    }
+
+    #[test]
+    fn test_single_data() {
+        use helix::*;
+        let req = GetModeratorsRequest::builder()
+            .broadcaster_id("198704263".to_string())
+            .build();
+
+        let data =
+            br#"{"data":{"user_id":"424596340","user_login":"quotrok","user_name":"quotrok"}}"#
+                .to_vec();
+        let http_response = http::Response::builder().body(data).unwrap();
+        let uri = req.get_uri().unwrap();
+        let res = req.clone().parse_response(&uri, http_response).unwrap();
+        assert_eq!(res.data.len(), 1);
+        assert_eq!(res.data[0].user_id, "424596340");
+
+        let data = br#"{"data":{"user_login":"quotrok"}}"#.to_vec();
+        let http_response = http::Response::builder().body(data).unwrap();
+        assert!(req.parse_response(&uri, http_response).is_err());
     }
 }
 
@@ -171,7 +1415,7 @@ pub mod get_moderators {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_moderator_events::GetModeratorEventsRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -184,13 +1428,13 @@ pub mod get_moderators {
 /// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
 pub mod get_moderator_events {
     use super::*;
-    use std::collections::HashMap;
 
     /// Query Parameters for [Get Moderators Events](super::get_moderator_events)
     ///
     /// [`get-moderator-events`](https://dev.twitch.tv/docs/api/reference#get-moderator-events)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetModeratorEventsRequest {
         /// Must match the User ID in the Bearer token.
         #[builder(setter(into))]
@@ -199,7 +1443,13 @@ pub mod get_moderator_events {
         /// Filters the results and only returns a status object for users who are moderators in this channel and have a matching user_id.
         /// Format: Repeated Query Parameter, eg. /moderation/moderators?broadcaster_id=1&user_id=2&user_id=3
         /// Maximum: 100
-        #[builder(default)]
+        #[builder(
+            default,
+            setter(transform = |user_id: impl IntoIterator<Item = impl Into<types::UserId>>| {
+                user_id.into_iter().map(Into::into).collect()
+            })
+        )]
+        #[serde(default)]
         pub user_id: Vec<types::UserId>,
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
         #[builder(default)]
@@ -208,33 +1458,54 @@ pub mod get_moderator_events {
 
     /// Return Values for [Get Moderators Events](super::get_moderator_events)
     ///
+    /// [`event_type`](helix::Event::event_type) is `moderation.moderator.add` or `moderation.moderator.remove`.
+    ///
     /// [`get-moderator-events`](https://dev.twitch.tv/docs/api/reference#get-moderator-events)
-    #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    pub type ModeratorEvent = helix::Event<ModeratorEventData>;
+
+    /// [`event_data`](helix::Event::event_data) of a [`ModeratorEvent`]
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
-    pub struct ModeratorEvent {
-        /// Event ID
-        pub id: String,
-        // FIXME: Twitch docs sucks...
-        /// Displays `moderation.moderator.add` or `moderation.moderator.remove`
-        pub event_type: String,
-        /// RFC3339 formatted timestamp for events.
-        pub event_timestamp: types::Timestamp,
-        /// Returns the version of the endpoint.
-        pub version: String,
-        /// Returns `broadcaster_id`, `broadcaster_name`, `user_id`, `user_name`, and `expires_at`.
-        pub event_data: HashMap<String, String>,
+    pub struct ModeratorEventData {
+        /// User ID of the broadcaster
+        pub broadcaster_id: types::UserId,
+        /// Display name of the broadcaster
+        pub broadcaster_name: types::DisplayName,
+        /// User ID of the added or removed moderator
+        pub user_id: types::UserId,
+        /// Display name of the added or removed moderator
+        pub user_name: types::DisplayName,
     }
 
     impl helix::Request for GetModeratorEventsRequest {
         type Response = Vec<ModeratorEvent>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "moderation/moderators/events";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+
+        fn validate(&self) -> Result<(), helix::RequestValidationError> {
+            validate_user_ids(&self.user_id)
+        }
     }
 
-    impl helix::RequestGet for GetModeratorEventsRequest {}
+    impl helix::RequestGet for GetModeratorEventsRequest {
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestGetError,
+        >
+        where
+            Self: Sized,
+        {
+            super::parse_vec_or_single(self, uri, response)
+        }
+    }
 
     impl helix::Paginated for GetModeratorEventsRequest {
         fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
@@ -297,13 +1568,16 @@ pub mod get_moderator_events {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/moderation/moderators/events?broadcaster_id=198704263"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/moderators/events?broadcaster_id=198704263",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let res = dbg!(req.parse_response(&uri, http_response).unwrap());
+        assert_eq!(res.data.len(), 3);
+
+        #[cfg(feature = "test-fixtures")]
+        helix::assert_fixture(&res.data, "moderation/get_moderator_events.json");
     }
 }
 
@@ -334,7 +1608,7 @@ pub mod get_moderator_events {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_banned_users::GetBannedUsersRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -353,46 +1627,211 @@ pub mod get_banned_users {
     /// [`get-banned-users`](https://dev.twitch.tv/docs/api/reference#get-banned-users)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetBannedUsersRequest {
-        /// Must match the User ID in the Bearer token.
+        /// Must match the User ID in the Bearer token, unless [`moderator_id`](GetBannedUsersRequest::moderator_id) is set.
         #[builder(setter(into))]
         pub broadcaster_id: types::UserId,
         /// Filters the results and only returns a status object for users who are banned in this channel and have a matching user_id.
         /// Format: Repeated Query Parameter, eg. /moderation/banned?broadcaster_id=1&user_id=2&user_id=3
         /// Maximum: 100
-        #[builder(default)]
+        #[builder(
+            default,
+            setter(transform = |user_id: impl IntoIterator<Item = impl Into<types::UserId>>| {
+                user_id.into_iter().map(Into::into).collect()
+            })
+        )]
+        #[serde(default)]
         pub user_id: Vec<types::UserId>,
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
         #[builder(default)]
         pub after: Option<helix::Cursor>,
+        /// The ID of a user that has permission to moderate the broadcaster’s chat room. Must match the User ID in the Bearer token if set.
+        ///
+        /// When not set, the token must belong to the broadcaster and have the `moderation:read` scope.
+        /// When set, the token belongs to the moderator, who needs the `moderator:manage:banned_users` scope instead,
+        /// which is what [`scopes`](helix::Request::scopes) returns then.
+        #[builder(default, setter(into))]
+        pub moderator_id: Option<types::UserId>,
+    }
+
+    impl GetBannedUsersRequest {
+        /// Get the banned users of `broadcaster_id`, the common case of [`GetBannedUsersRequest::builder()`]
+        ///
+        /// ```rust
+        /// # use twitch_api2::helix::moderation::GetBannedUsersRequest;
+        /// assert_eq!(
+        ///     GetBannedUsersRequest::for_broadcaster("1234"),
+        ///     GetBannedUsersRequest::builder().broadcaster_id("1234").build()
+        /// );
+        /// ```
+        pub fn for_broadcaster(broadcaster_id: impl Into<types::UserId>) -> Self {
+            Self::builder().broadcaster_id(broadcaster_id).build()
+        }
     }
 
     /// Return Values for [Get Banned Users](super::get_banned_users)
     ///
     /// [`get-banned-users`](https://dev.twitch.tv/docs/api/reference#get-banned-users)
-    #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    ///
+    /// Twitch has sent the name as either `user_name` or `user_login`, both are accepted.
+    #[derive(PartialEq, Eq, Hash, Deserialize, Serialize, Debug, Clone)]
+    #[serde(try_from = "RawBannedUser")]
     #[non_exhaustive]
     pub struct BannedUser {
         /// User ID of a user who has been banned.
         pub user_id: types::UserId,
         /// Display name of a user who has been banned.
         pub user_name: types::DisplayName,
-        /// RFC3339 formatted timestamp for timeouts; empty string for bans.
+        /// RFC3339 formatted timestamp for timeouts; `None` for bans.
         pub expires_at: Option<types::Timestamp>,
     }
 
-    impl helix::Request for GetBannedUsersRequest {
-        type Response = Vec<BannedUser>;
-
-        const PATH: &'static str = "moderation/banned";
-        #[cfg(feature = "twitch_oauth2")]
-        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+    /// [`BannedUser`] as sent by Twitch, with either or both of `user_name` and `user_login`
+    #[derive(Deserialize)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    struct RawBannedUser {
+        user_id: types::UserId,
+        #[serde(default)]
+        user_name: Option<types::DisplayName>,
+        #[serde(default)]
+        user_login: Option<types::UserName>,
+        #[serde(default, deserialize_with = "types::deserialize_optional_timestamp")]
+        expires_at: Option<types::Timestamp>,
     }
 
-    impl helix::RequestGet for GetBannedUsersRequest {}
+    impl std::convert::TryFrom<RawBannedUser> for BannedUser {
+        type Error = &'static str;
 
-    impl helix::Paginated for GetBannedUsersRequest {
+        fn try_from(raw: RawBannedUser) -> Result<Self, Self::Error> {
+            let RawBannedUser {
+                user_id,
+                user_name,
+                user_login,
+                expires_at,
+            } = raw;
+            Ok(BannedUser {
+                user_id,
+                user_name: user_name
+                    .or_else(|| user_login.map(Into::into))
+                    .ok_or("missing field `user_name`")?,
+                expires_at,
+            })
+        }
+    }
+
+    #[cfg(feature = "test-util")]
+    #[cfg_attr(nightly, doc(cfg(feature = "test-util")))]
+    impl BannedUser {
+        /// Create a banned user, e.g. as a fixture in tests. `expires_at` is `None` for a permanent ban
+        pub fn new(
+            user_id: impl Into<types::UserId>,
+            user_name: impl Into<types::DisplayName>,
+            expires_at: Option<types::Timestamp>,
+        ) -> Self {
+            BannedUser {
+                user_id: user_id.into(),
+                user_name: user_name.into(),
+                expires_at,
+            }
+        }
+    }
+
+    impl BannedUser {
+        /// Returns `true` if the user is in a timeout, `false` if they are banned permanently
+        pub fn is_timeout(&self) -> bool {
+            self.expires_at
+                .as_ref()
+                .map_or(false, |expires_at| !expires_at.as_str().is_empty())
+        }
+
+        /// Returns `true` if the user was in a timeout that has ended at `now`
+        ///
+        /// Returns `false` if either timestamp is not valid RFC3339.
+        pub fn is_expired(&self, now: &types::Timestamp) -> bool {
+            match &self.expires_at {
+                Some(expires_at) if self.is_timeout() => matches!(
+                    expires_at.cmp_instant(now),
+                    Some(std::cmp::Ordering::Less) | Some(std::cmp::Ordering::Equal)
+                ),
+                _ => false,
+            }
+        }
+
+        /// Time left of the timeout at `now`, zero if it has ended
+        ///
+        /// Returns `None` for permanent bans, which never end, or if either timestamp is not valid RFC3339.
+        ///
+        /// ```rust
+        /// # use twitch_api2::helix::moderation::BannedUser;
+        /// # let user: BannedUser = serde_json::from_str(r#"{"user_id":"1","user_name":"one","expires_at":"2021-01-01T12:00:00Z"}"#).unwrap();
+        /// assert_eq!(
+        ///     user.expires_in(&"2021-01-01T11:59:30Z".into()),
+        ///     Some(std::time::Duration::from_secs(30))
+        /// );
+        /// ```
+        #[cfg(feature = "chrono")]
+        #[cfg_attr(nightly, doc(cfg(feature = "chrono")))]
+        pub fn expires_in(&self, now: &types::Timestamp) -> Option<std::time::Duration> {
+            if !self.is_timeout() {
+                return None;
+            }
+            let expires_at = self.expires_at.as_ref()?.to_chrono().ok()?;
+            let left = expires_at.signed_duration_since(now.to_chrono().ok()?);
+            Some(left.to_std().unwrap_or_default())
+        }
+
+        /// Sleep until the timeout has ended at `now`, e.g. to act on the user again without polling [Get Banned Users](super::get_banned_users)
+        ///
+        /// Returns `None` for permanent bans, see [`expires_in`](BannedUser::expires_in). The delay completes at once if the timeout has already ended.
+        #[cfg(all(feature = "chrono", feature = "tokio"))]
+        #[cfg_attr(nightly, doc(cfg(all(feature = "chrono", feature = "tokio"))))]
+        pub fn delay_until_expired(&self, now: &types::Timestamp) -> Option<tokio::time::Delay> {
+            self.expires_in(now).map(tokio::time::delay_for)
+        }
+    }
+
+    impl helix::Request for GetBannedUsersRequest {
+        type Response = Vec<BannedUser>;
+
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "moderation/banned";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+        #[cfg(feature = "twitch_oauth2")]
+        const OPT_SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ModeratorManageBannedUsers];
+
+        fn validate(&self) -> Result<(), helix::RequestValidationError> {
+            validate_user_ids(&self.user_id)
+        }
+
+        #[cfg(feature = "twitch_oauth2")]
+        fn scopes(&self) -> &[twitch_oauth2::Scope] {
+            match self.moderator_id {
+                Some(_) => Self::OPT_SCOPE,
+                None => Self::SCOPE,
+            }
+        }
+    }
+
+    impl helix::RequestGet for GetBannedUsersRequest {
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestGetError,
+        >
+        where
+            Self: Sized,
+        {
+            super::parse_vec_or_single(self, uri, response)
+        }
+    }
+
+    impl helix::Paginated for GetBannedUsersRequest {
         fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
     }
 
@@ -427,13 +1866,213 @@ pub mod get_banned_users {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=198704263",
+        );
+
+        let res = dbg!(req.parse_response(&uri, http_response).unwrap());
+        assert_eq!(
+            res.pagination.cursor().map(Cursor::as_str),
+            Some("eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6IjEwMDQ3MzA2NDo4NjQwNjU3MToxSVZCVDFKMnY5M1BTOXh3d1E0dUdXMkJOMFcifX0")
+        );
+
+        #[cfg(feature = "test-fixtures")]
+        helix::assert_fixture(&res.data, "moderation/get_banned_users.json");
+    }
+
+    #[test]
+    fn test_permanent_ban() {
+        let user: BannedUser =
+            serde_json::from_str(r#"{"user_id":"1","user_name":"one","expires_at":""}"#).unwrap();
+        assert_eq!(user.expires_at, None);
+        assert!(!user.is_timeout());
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn test_new() {
+        let user: BannedUser = serde_json::from_str(
+            r#"{"user_id":"1","user_name":"one","expires_at":"2021-01-01T12:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            BannedUser::new("1", "one", Some("2021-01-01T12:00:00Z".into())),
+            user
+        );
+        assert!(!BannedUser::new("1", "one", None).is_timeout());
+    }
+
+    #[test]
+    fn test_user_name_or_user_login() {
+        let user: BannedUser = serde_json::from_str(
+            r#"{"user_id":"1","user_login":"one","user_name":"One","expires_at":""}"#,
+        )
+        .unwrap();
+        assert_eq!(user.user_name, "One");
+
+        let user: BannedUser =
+            serde_json::from_str(r#"{"user_id":"1","user_name":"One","expires_at":""}"#).unwrap();
+        assert_eq!(user.user_name, "One");
+
+        let user: BannedUser =
+            serde_json::from_str(r#"{"user_id":"1","user_login":"one","expires_at":""}"#).unwrap();
+        assert_eq!(user.user_name, "one");
+
+        assert!(serde_json::from_str::<BannedUser>(r#"{"user_id":"1","expires_at":""}"#).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_expires_in() {
+        use std::time::Duration;
+
+        let user = |expires_at: &str| BannedUser {
+            user_id: "1".to_string(),
+            user_name: "one".to_string(),
+            expires_at: Some(expires_at.into()),
+        };
+        let now = "2021-01-01T11:00:00Z".into();
+        assert_eq!(
+            user("2021-01-01T12:00:00Z").expires_in(&now),
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(
+            user("2021-01-01T12:00:00+01:00").expires_in(&now),
+            Some(Duration::from_secs(0))
+        );
+        assert_eq!(user("").expires_in(&now), None);
+        assert_eq!(user("tomorrow").expires_in(&now), None);
+    }
+
+    #[cfg(all(feature = "chrono", feature = "tokio"))]
+    #[tokio::test]
+    async fn test_delay_until_expired() {
+        let user = |expires_at: Option<&str>| BannedUser {
+            user_id: "1".to_string(),
+            user_name: "one".to_string(),
+            expires_at: expires_at.map(Into::into),
+        };
+        let now = "2021-01-01T11:00:00Z".into();
+        assert!(user(None).delay_until_expired(&now).is_none());
+        user(Some("2021-01-01T10:00:00Z"))
+            .delay_until_expired(&now)
+            .unwrap()
+            .await;
+    }
+
+    #[test]
+    fn test_request_from_json() {
+        use helix::*;
+        let req: GetBannedUsersRequest =
+            serde_json::from_str(r#"{"broadcaster_id": "198704263"}"#).unwrap();
         assert_eq!(
-            uri.to_string(),
+            req,
+            GetBannedUsersRequest::builder()
+                .broadcaster_id("198704263".to_string())
+                .build()
+        );
+        assert_eq!(
+            req.get_uri().unwrap().to_string(),
             "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=198704263"
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let req: GetBannedUsersRequest = serde_json::from_str(
+            r#"{"broadcaster_id": "198704263", "user_id": ["423374343", "424596340"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            req,
+            GetBannedUsersRequest::builder()
+                .broadcaster_id("198704263".to_string())
+                .user_id(vec!["423374343", "424596340"])
+                .build()
+        );
+        assert_eq!(
+            req.get_uri().unwrap().to_string(),
+            "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=198704263&user_id=423374343&user_id=424596340"
+        );
+    }
+
+    #[cfg(feature = "twitch_oauth2")]
+    #[test]
+    fn test_dry_run() {
+        use helix::*;
+        let token = |scopes| helix::test::token(Some(scopes));
+        let req = GetBannedUsersRequest::builder()
+            .broadcaster_id("198704263")
+            .user_id(vec!["423374343"])
+            .build();
+
+        let request = req
+            .dry_run(&token(vec![twitch_oauth2::Scope::ModerationRead]))
+            .unwrap();
+        assert_eq!(request.method(), http::Method::GET);
+        assert_eq!(
+            request.uri().to_string(),
+            "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=198704263&user_id=423374343"
+        );
+        assert_eq!(request.headers()["Client-ID"], "clientid");
+
+        match req.dry_run(&token(vec![])) {
+            Err(CreateRequestError::ValidationError(RequestValidationError::ScopeError(
+                ScopeError::MissingScopes(missing),
+            ))) => assert_eq!(missing, vec![twitch_oauth2::Scope::ModerationRead]),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(req
+            .can_execute(&token(vec![twitch_oauth2::Scope::ModerationRead]))
+            .is_ok());
+        assert_eq!(
+            req.can_execute(&token(vec![])).unwrap_err().to_string(),
+            "token is missing required scopes: moderation:read"
+        );
+
+        let req = GetBannedUsersRequest::builder()
+            .broadcaster_id("198704263")
+            .user_id((0..101).map(|id| id.to_string()))
+            .build();
+        assert!(matches!(
+            req.dry_run(&token(vec![twitch_oauth2::Scope::ModerationRead])),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::TooManyUserIds(101)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_request_moderator() {
+        use helix::*;
+        let req = GetBannedUsersRequest::builder()
+            .broadcaster_id("198704263")
+            .moderator_id("5678".to_string())
+            .build();
+        assert_eq!(
+            req.get_uri().unwrap().to_string(),
+            "https://api.twitch.tv/helix/moderation/banned?broadcaster_id=198704263&moderator_id=5678"
+        );
+    }
+
+    #[cfg(feature = "twitch_oauth2")]
+    #[test]
+    fn test_scopes() {
+        use helix::*;
+        let broadcaster = helix::test::token(Some(vec![twitch_oauth2::Scope::ModerationRead]));
+        let moderator =
+            helix::test::token(Some(vec![twitch_oauth2::Scope::ModeratorManageBannedUsers]));
+
+        let req = GetBannedUsersRequest::for_broadcaster("198704263");
+        assert_eq!(req.scopes(), &[twitch_oauth2::Scope::ModerationRead]);
+        assert!(req.can_execute(&broadcaster).is_ok());
+        assert!(req.can_execute(&moderator).is_err());
+
+        let req = req.modify(|req| req.moderator_id = Some("5678".to_string()));
+        assert_eq!(
+            req.scopes(),
+            &[twitch_oauth2::Scope::ModeratorManageBannedUsers]
+        );
+        assert!(req.can_execute(&broadcaster).is_err());
+        assert!(req.can_execute(&moderator).is_ok());
     }
 }
 
@@ -464,7 +2103,7 @@ pub mod get_banned_users {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_banned_events::GetBannedEventsRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -477,13 +2116,13 @@ pub mod get_banned_users {
 /// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
 pub mod get_banned_events {
     use super::*;
-    use std::collections::HashMap;
 
     /// Query Parameters for [Get Banned Events](super::get_banned_events)
     ///
     /// [`get-banned-events`](https://dev.twitch.tv/docs/api/reference#get-banned-events)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetBannedEventsRequest {
         /// Must match the User ID in the Bearer token.
         #[builder(setter(into))]
@@ -491,7 +2130,13 @@ pub mod get_banned_events {
         /// Filters the results and only returns a status object for users who are banned in this channel and have a matching user_id.
         /// Format: Repeated Query Parameter, eg. /moderation/banned?broadcaster_id=1&user_id=2&user_id=3
         /// Maximum: 100
-        #[builder(default)]
+        #[builder(
+            default,
+            setter(transform = |user_id: impl IntoIterator<Item = impl Into<types::UserId>>| {
+                user_id.into_iter().map(Into::into).collect()
+            })
+        )]
+        #[serde(default)]
         pub user_id: Vec<types::UserId>,
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
         #[builder(default)]
@@ -501,35 +2146,104 @@ pub mod get_banned_events {
         pub first: Option<usize>,
     }
 
+    impl GetBannedEventsRequest {
+        /// Get the ban and unban events of `broadcaster_id`, the common case of [`GetBannedEventsRequest::builder()`]
+        ///
+        /// ```rust
+        /// # use twitch_api2::helix::moderation::GetBannedEventsRequest;
+        /// assert_eq!(
+        ///     GetBannedEventsRequest::for_broadcaster("1234"),
+        ///     GetBannedEventsRequest::builder().broadcaster_id("1234").build()
+        /// );
+        /// ```
+        pub fn for_broadcaster(broadcaster_id: impl Into<types::UserId>) -> Self {
+            Self::builder().broadcaster_id(broadcaster_id).build()
+        }
+    }
+
     /// Return Values for [Get Banned Events](super::get_banned_events)
     ///
+    /// [`event_type`](helix::Event::event_type) is `moderation.user.ban` or `moderation.user.unban`.
+    ///
     /// [`get-banned-events`](https://dev.twitch.tv/docs/api/reference#get-banned-events)
-    #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
-    #[non_exhaustive]
-    pub struct BannedEvent {
-        /// Event ID
-        pub id: String,
-        /// Displays `moderation.user.ban` or `moderation.user.unban`
-        pub event_type: String,
-        /// RFC3339 formatted timestamp for events.
-        pub event_timestamp: types::Timestamp,
-        /// Returns the version of the endpoint.
-        pub version: String,
-        // FIXME: Should be a struct, maybe
-        /// Returns `broadcaster_id`, `broadcaster_name`, `user_id`, `user_name`, and `expires_at`.
-        pub event_data: HashMap<String, String>,
+    pub type BannedEvent = helix::Event<BannedEventData>;
+
+    /// [`event_data`](helix::Event::event_data) of a [`BannedEvent`]
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct BannedEventData {
+        /// User ID of the broadcaster
+        pub broadcaster_id: types::UserId,
+        /// Display name of the broadcaster
+        pub broadcaster_name: types::DisplayName,
+        /// User ID of the banned or unbanned user
+        pub user_id: types::UserId,
+        /// Display name of the banned or unbanned user
+        pub user_name: types::DisplayName,
+        /// RFC3339 formatted timestamp for when a timeout expires, `None` if the ban is permanent or this is an unban
+        #[serde(default, deserialize_with = "types::deserialize_optional_timestamp")]
+        pub expires_at: Option<types::Timestamp>,
+    }
+
+    /// What a [`BannedEvent`] did to the user, see [`BannedEvent::action`](helix::Event::action)
+    #[derive(PartialEq, Eq, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum BanAction {
+        /// User was banned permanently
+        Ban,
+        /// User was timed out
+        Timeout {
+            /// When the timeout expires
+            until: types::Timestamp,
+        },
+        /// User was unbanned, or their timeout was removed
+        Unban,
+    }
+
+    impl helix::Event<BannedEventData> {
+        /// Combine [`event_type`](helix::Event::event_type) and [`expires_at`](BannedEventData::expires_at) into a single [`BanAction`]
+        ///
+        /// Any `event_type` other than `moderation.user.unban` is a ban, which is a timeout if it expires.
+        pub fn action(&self) -> BanAction {
+            match (self.event_type.as_str(), &self.event_data.expires_at) {
+                ("moderation.user.unban", _) => BanAction::Unban,
+                (_, Some(until)) => BanAction::Timeout {
+                    until: until.clone(),
+                },
+                (_, None) => BanAction::Ban,
+            }
+        }
     }
 
     impl helix::Request for GetBannedEventsRequest {
         type Response = Vec<BannedEvent>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "moderation/banned/events";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+
+        fn validate(&self) -> Result<(), helix::RequestValidationError> {
+            validate_user_ids(&self.user_id)
+        }
     }
 
-    impl helix::RequestGet for GetBannedEventsRequest {}
+    impl helix::RequestGet for GetBannedEventsRequest {
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestGetError,
+        >
+        where
+            Self: Sized,
+        {
+            super::parse_vec_or_single(self, uri, response)
+        }
+    }
 
     impl helix::Paginated for GetBannedEventsRequest {
         fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
@@ -595,13 +2309,84 @@ pub mod get_banned_events {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/banned/events?broadcaster_id=198704263",
+        );
+
+        let from_reader = req
+            .clone()
+            .parse_response_reader(&uri, http_response.status(), &http_response.body()[..])
+            .unwrap();
+        let res = dbg!(req.clone().parse_response(&uri, http_response).unwrap());
+        assert_eq!(from_reader.data, res.data);
+        assert_eq!(from_reader.pagination, res.pagination);
+
+        #[cfg(feature = "test-fixtures")]
+        helix::assert_fixture(&res.data, "moderation/get_banned_events.json");
+
+        let data = &res.data[0].event_data;
+        assert_eq!(data.broadcaster_id, "198704263");
+        assert_eq!(data.user_name, "quotrok");
+        assert_eq!(data.expires_at, None);
+        assert_eq!(res.data[0].action(), BanAction::Ban);
+        assert_eq!(res.data[1].action(), BanAction::Unban);
+
+        let error = br#"{"error":"Unauthorized","status":401,"message":"Invalid OAuth token"}"#;
+        match req.parse_response_reader(&uri, http::StatusCode::UNAUTHORIZED, &error[..]) {
+            Err(HelixRequestGetError::Error {
+                status, message, ..
+            }) => {
+                assert_eq!(status, http::StatusCode::UNAUTHORIZED);
+                assert_eq!(message, "Invalid OAuth token");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_gone() {
+        use helix::*;
+        let req = GetBannedEventsRequest::builder()
+            .broadcaster_id("198704263")
+            .build();
+
+        let http_response = http::Response::builder()
+            .status(410)
+            .header("Twitch-Trace-Id", "0123456789abcdef")
+            .body(vec![])
+            .unwrap();
+
         let uri = req.get_uri().unwrap();
+        let err = req.parse_response(&uri, http_response).unwrap_err();
+        assert!(matches!(err, HelixRequestGetError::Gone { .. }));
+        assert_eq!(err.request_id(), Some("0123456789abcdef"));
+    }
+
+    #[test]
+    fn test_action_timeout() {
+        let event: BannedEvent = serde_json::from_str(
+            r#"{
+                "id": "1IPFqAb0p0JncbPSTEPhx8JF1Sa",
+                "event_type": "moderation.user.ban",
+                "event_timestamp": "2019-03-13T15:55:14Z",
+                "version": "1.0",
+                "event_data": {
+                    "broadcaster_id": "198704263",
+                    "broadcaster_name": "aan22209",
+                    "user_id": "424596340",
+                    "user_name": "quotrok",
+                    "expires_at": "2019-03-13T16:05:14Z"
+                }
+            }"#,
+        )
+        .unwrap();
         assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/moderation/banned/events?broadcaster_id=198704263"
+            event.action(),
+            BanAction::Timeout {
+                until: types::Timestamp::new("2019-03-13T16:05:14Z")
+            }
         );
-
-        dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -647,7 +2432,7 @@ pub mod get_banned_events {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = check_automod_status::CheckAutoModStatusRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -670,6 +2455,7 @@ pub mod check_automod_status {
     /// [`check-automod-status`](https://dev.twitch.tv/docs/api/reference#check-automod-status)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct CheckAutoModStatusRequest {
         /// Must match the User ID in the Bearer token.
         #[builder(setter(into))]
@@ -686,7 +2472,7 @@ pub mod check_automod_status {
     pub struct CheckAutoModStatusBody {
         /// Developer-generated identifier for mapping messages to results.
         #[builder(setter(into))]
-        pub msg_id: String,
+        pub msg_id: types::MsgId,
         /// Message text.
         #[builder(setter(into))]
         pub msg_text: String,
@@ -699,11 +2485,11 @@ pub mod check_automod_status {
     ///
     /// [`check-automod-status`](https://dev.twitch.tv/docs/api/reference#check-automod-status)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct CheckAutoModStatus {
         /// The msg_id passed in the body of the POST message. Maps each message to its status.
-        pub msg_id: String,
+        pub msg_id: types::MsgId,
         /// Indicates if this message meets AutoMod requirements.
         pub is_permitted: bool,
     }
@@ -711,6 +2497,7 @@ pub mod check_automod_status {
     impl helix::Request for CheckAutoModStatusRequest {
         type Response = Vec<CheckAutoModStatus>;
 
+        const METHOD: http::Method = http::Method::POST;
         const PATH: &'static str = "moderation/enforcements/status";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
@@ -719,16 +2506,74 @@ pub mod check_automod_status {
     impl helix::RequestPost for CheckAutoModStatusRequest {
         type Body = Vec<CheckAutoModStatusBody>;
 
-        fn body(&self, body: &Self::Body) -> Result<String, serde_json::Error> {
-            #[derive(Serialize)]
-            struct InnerBody<'a> {
-                data: &'a Vec<CheckAutoModStatusBody>,
-            }
+        fn validate_body(&self, body: &Self::Body) -> Result<(), helix::RequestValidationError> {
+            validate_msg_ids(body)
+        }
+    }
+
+    impl helix::Response<CheckAutoModStatusRequest, Vec<CheckAutoModStatus>> {
+        /// Map the `msg_id` of every message to whether it [is permitted](CheckAutoModStatus::is_permitted)
+        pub fn permitted(&self) -> std::collections::HashMap<types::MsgId, bool> {
+            self.data
+                .iter()
+                .map(|status| (status.msg_id.clone(), status.is_permitted))
+                .collect()
+        }
 
-            serde_json::to_string(&InnerBody { data: &body })
+        /// Pair every body that was sent with whether it [is permitted](CheckAutoModStatus::is_permitted), in the order of `bodies`
+        ///
+        /// Results are matched by `msg_id`, so the order Twitch returns them in does not matter.
+        pub fn zip_bodies<'b>(
+            &self,
+            bodies: &'b [CheckAutoModStatusBody],
+        ) -> Result<Vec<(&'b CheckAutoModStatusBody, bool)>, MissingAutoModStatus> {
+            let permitted = self.permitted();
+            bodies
+                .iter()
+                .map(|body| match permitted.get(&body.msg_id) {
+                    Some(is_permitted) => Ok((body, *is_permitted)),
+                    None => Err(MissingAutoModStatus {
+                        msg_id: body.msg_id.clone(),
+                    }),
+                })
+                .collect()
+        }
+
+        /// The status of every body that was sent, in the order of `bodies`
+        ///
+        /// Unlike [`zip_bodies`](Self::zip_bodies), a message Twitch returned no status for
+        /// only makes its own entry an error, so a list of messages can still be shown with a verdict for each.
+        pub fn in_body_order(
+            &self,
+            bodies: &[CheckAutoModStatusBody],
+        ) -> Vec<Result<&CheckAutoModStatus, MissingAutoModStatus>> {
+            let statuses: std::collections::HashMap<_, _> = self
+                .data
+                .iter()
+                .map(|status| (&status.msg_id, status))
+                .collect();
+            bodies
+                .iter()
+                .map(|body| {
+                    statuses
+                        .get(&body.msg_id)
+                        .copied()
+                        .ok_or_else(|| MissingAutoModStatus {
+                            msg_id: body.msg_id.clone(),
+                        })
+                })
+                .collect()
         }
     }
 
+    /// twitch returned no status for message `{msg_id}`
+    #[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub struct MissingAutoModStatus {
+        /// The `msg_id` of the message
+        pub msg_id: types::MsgId,
+    }
+
     #[test]
     fn test_request() {
         use helix::*;
@@ -755,12 +2600,1658 @@ pub mod check_automod_status {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/enforcements/status?broadcaster_id=198704263",
+        );
+
+        let res = dbg!(req.parse_response(&uri, http_response).unwrap());
+        assert_eq!(res.len(), 2);
+        assert!(!res.is_empty());
+
+        let permitted: std::collections::HashMap<types::MsgId, bool> = res
+            .data
+            .into_iter()
+            .map(|status| (status.msg_id, status.is_permitted))
+            .collect();
+        assert!(permitted[&types::MsgId::new("123")]);
+        assert!(!permitted[&"393".into()]);
+    }
+
+    #[test]
+    fn test_request_empty() {
+        use helix::*;
+        let req = CheckAutoModStatusRequest::builder()
+            .broadcaster_id("198704263".to_string())
+            .build();
+
+        let data = br#"{"data": []}"#.to_vec();
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = req.get_uri().unwrap();
+        let res = req.parse_response(&uri, http_response).unwrap();
+        assert_eq!(res.len(), 0);
+        assert!(res.is_empty());
+        assert_eq!(res.first(), None);
+    }
+
+    #[test]
+    fn test_zip_bodies() {
+        use helix::*;
+        let req = CheckAutoModStatusRequest::builder()
+            .broadcaster_id("198704263")
+            .build();
+        let body = |msg_id: &str| {
+            CheckAutoModStatusBody::builder()
+                .msg_id(msg_id)
+                .msg_text("Hello world")
+                .user_id("23749")
+                .build()
+        };
+        let bodies = vec![body("123"), body("393")];
+
+        let data = br#"{"data":[{"msg_id":"393","is_permitted":false},{"msg_id":"123","is_permitted":true}]}"#.to_vec();
+        let http_response = http::Response::builder().body(data).unwrap();
         let uri = req.get_uri().unwrap();
+        let res = req.parse_response(&uri, http_response).unwrap();
+
+        let zipped = res.zip_bodies(&bodies).unwrap();
+        assert_eq!(zipped, vec![(&bodies[0], true), (&bodies[1], false)]);
+
+        let bodies = vec![body("123"), body("404")];
+        assert_eq!(
+            res.zip_bodies(&bodies),
+            Err(MissingAutoModStatus {
+                msg_id: "404".into()
+            })
+        );
+
+        let ordered = res.in_body_order(&bodies);
+        assert_eq!(ordered.len(), 2);
+        assert_eq!(
+            ordered[0].as_ref().map(|status| status.is_permitted),
+            Ok(true)
+        );
+        assert_eq!(
+            ordered[1],
+            Err(MissingAutoModStatus {
+                msg_id: "404".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_body() {
+        use helix::*;
+        let req = CheckAutoModStatusRequest::builder()
+            .broadcaster_id("198704263")
+            .build();
+
+        let body = vec![CheckAutoModStatusBody::builder()
+            .msg_id("123")
+            .msg_text("Hello world")
+            .user_id("23749")
+            .build()];
+        assert_eq!(
+            req.body(&body).unwrap(),
+            r#"{"data":[{"msg_id":"123","msg_text":"Hello world","user_id":"23749"}]}"#
+        );
+        assert_eq!(req.debug_body(&body), req.body(&body).unwrap());
+
+        let prebuilt = req
+            .create_request_prebuilt(req.body(&body).unwrap().into(), "token", &"clientid".into())
+            .unwrap();
+        let request = req
+            .create_request(body.clone(), "token", &"clientid".into())
+            .unwrap();
+        assert_eq!(prebuilt.body(), request.body());
+        assert_eq!(prebuilt.uri(), request.uri());
+
+        let body = vec![body[0].clone(), body[0].clone()];
+        assert!(matches!(
+            req.create_request(body, "token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::DuplicateMsgId(_)
+            ))
+        ));
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_batched() {
+        let token = helix::test::token(None);
+        // Answers every message but those with "lost" in them, in reverse order, permitting those without "bad" in them
+        let client = helix::HelixClient::with_client(helix::test::MockClient::new(|_, req| {
+            let body: serde_json::Value = serde_json::from_slice(req.body()).unwrap();
+            let statuses: Vec<_> = body["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|body| !body["msg_text"].as_str().unwrap().contains("lost"))
+                .map(|body| {
+                    serde_json::json!({
+                        "msg_id": body["msg_id"],
+                        "is_permitted": !body["msg_text"].as_str().unwrap().contains("bad"),
+                    })
+                })
+                .collect();
+            Some(helix::test::response(
+                200,
+                &serde_json::json!({ "data": statuses }).to_string(),
+            ))
+        }));
+        let body = |i: usize| {
+            CheckAutoModStatusBody::builder()
+                .msg_id(i.to_string())
+                .msg_text(if i % 3 == 0 { "bad" } else { "good" })
+                .user_id("23749")
+                .build()
+        };
+        let bodies: Vec<_> = (0..250).map(body).collect();
+
+        let statuses = futures::executor::block_on(client.check_automod_status_batched(
+            "198704263",
+            &bodies,
+            &token,
+        ))
+        .unwrap();
+        assert_eq!(client.client.requests().len(), 3);
+        assert_eq!(statuses.len(), 250);
+        for (i, status) in statuses.iter().enumerate() {
+            let status = status.as_ref().unwrap();
+            assert_eq!(status.msg_id, i.to_string());
+            assert_eq!(status.is_permitted, i % 3 != 0);
+        }
+
+        let bodies = vec![body(1), body(2), body(1)];
+        match futures::executor::block_on(client.check_automod_status_batched(
+            "198704263",
+            &bodies,
+            &token,
+        )) {
+            Err(helix::ClientRequestError::CreateRequestError(
+                helix::CreateRequestError::ValidationError(
+                    helix::RequestValidationError::DuplicateMsgId(msg_id),
+                ),
+            )) => assert_eq!(msg_id, "1"),
+            other => panic!("unexpected {:?}", other.map(|_| ())),
+        }
+        assert_eq!(client.client.requests().len(), 3);
+
+        let lost = CheckAutoModStatusBody::builder()
+            .msg_id("lost")
+            .msg_text("lost")
+            .user_id("23749")
+            .build();
+        let statuses = futures::executor::block_on(client.check_automod_status_batched(
+            "198704263",
+            &[body(1), lost, body(3)],
+            &token,
+        ))
+        .unwrap();
+        assert_eq!(statuses.len(), 3);
+        assert!(statuses[0].as_ref().unwrap().is_permitted);
         assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/moderation/enforcements/status?broadcaster_id=198704263"
+            statuses[1],
+            Err(MissingAutoModStatus {
+                msg_id: "lost".into()
+            })
+        );
+        assert!(!statuses[2].as_ref().unwrap().is_permitted);
+    }
+}
+
+/// Ban a user from participating in a broadcaster’s chat room, or put them in a timeout.
+/// [`ban-user`](https://dev.twitch.tv/docs/api/reference#ban-user)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [BanUserRequest]
+///
+/// To use this endpoint, construct a [`BanUserRequest`] with the [`BanUserRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::moderation::ban_user;
+/// let request = ban_user::BanUserRequest::builder()
+///     .broadcaster_id("1234")
+///     .moderator_id("5678")
+///     .build();
+/// ```
+///
+/// ## Body: [BanUserBody]
+///
+/// We also need to provide a body to the request containing who to ban, and for how long.
+///
+/// ```
+/// # use twitch_api2::helix::moderation::ban_user;
+/// let body = ban_user::BanUserBody::builder()
+///     .user_id("9876")
+///     .duration(std::time::Duration::from_secs(300))
+///     .reason("no reason")
+///     .build();
+/// ```
+///
+/// ## Response: [BanUser]
+///
+///
+/// Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+///
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, moderation::ban_user};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = ban_user::BanUserRequest::builder()
+///     .broadcaster_id("1234")
+///     .moderator_id("5678")
+///     .build();
+/// let body = ban_user::BanUserBody::builder()
+///     .user_id("9876")
+///     .reason("no reason")
+///     .build();
+/// let response: Vec<ban_user::BanUser> = client.req_post(request, body, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPost::parse_response())
+pub mod ban_user {
+    use super::*;
+
+    /// Longest timeout Twitch accepts, in seconds. Two weeks.
+    pub const MAX_TIMEOUT_DURATION: u32 = 1_209_600;
+
+    /// Query Parameters for [Ban User](super::ban_user)
+    ///
+    /// [`ban-user`](https://dev.twitch.tv/docs/api/reference#ban-user)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct BanUserRequest {
+        /// The ID of the broadcaster whose chat room the user is being banned from.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// The ID of a user that has permission to moderate the broadcaster’s chat room. Must match the User ID in the Bearer token.
+        ///
+        /// Defaults to [`broadcaster_id`](BanUserRequest::broadcaster_id) when not set, for when the broadcaster is also the moderator.
+        #[builder(default, setter(into, strip_option))]
+        pub moderator_id: Option<types::UserId>,
+    }
+
+    /// Body Parameters for [Ban User](super::ban_user)
+    ///
+    /// [`ban-user`](https://dev.twitch.tv/docs/api/reference#ban-user)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct BanUserBody {
+        /// The ID of the user to ban or put in a timeout.
+        #[builder(setter(into))]
+        pub user_id: types::UserId,
+        /// Duration of the timeout. `None` bans the user permanently.
+        ///
+        /// Sent as whole seconds, and must be between 1 and [`MAX_TIMEOUT_DURATION`] seconds (two weeks).
+        #[builder(default, setter(strip_option))]
+        #[serde(default, skip_serializing_if = "Option::is_none", with = "seconds")]
+        pub duration: Option<std::time::Duration>,
+        /// The reason the user is being banned or put in a timeout. Limited to 500 characters.
+        #[builder(default, setter(into, strip_option))]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+    }
+
+    impl BanUserBody {
+        /// Check that the body will be accepted by Twitch, i.e. that [`duration`](BanUserBody::duration) is within bounds.
+        pub fn validate(&self) -> Result<(), helix::RequestValidationError> {
+            match self.duration.map(|d| d.as_secs()) {
+                Some(secs) if !(1..=u64::from(MAX_TIMEOUT_DURATION)).contains(&secs) => {
+                    Err(helix::RequestValidationError::TimeoutDurationOutOfRange(
+                        std::convert::TryFrom::try_from(secs).unwrap_or(u32::MAX),
+                    ))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// (De)serialize [`BanUserBody::duration`] as whole seconds
+    mod seconds {
+        use serde::{Deserialize, Deserializer, Serializer};
+        use std::time::Duration;
+
+        pub fn serialize<S: Serializer>(
+            duration: &Option<Duration>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match duration {
+                Some(duration) => serializer.serialize_some(&duration.as_secs()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_secs))
+        }
+    }
+
+    /// Return Values for [Ban User](super::ban_user)
+    ///
+    /// [`ban-user`](https://dev.twitch.tv/docs/api/reference#ban-user)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct BanUser {
+        /// The broadcaster whose chat room the user was banned from chatting in.
+        pub broadcaster_id: types::UserId,
+        /// The moderator that banned or put the user in the timeout.
+        pub moderator_id: types::UserId,
+        /// The user that was banned or was put in a timeout.
+        pub user_id: types::UserId,
+        /// RFC3339 formatted timestamp for when the ban or timeout was created.
+        pub created_at: types::Timestamp,
+        /// RFC3339 formatted timestamp for when the timeout ends. `None` if the user was banned permanently.
+        #[serde(default, deserialize_with = "types::deserialize_optional_timestamp")]
+        pub end_time: Option<types::Timestamp>,
+    }
+
+    /// Errors specific to [Ban User](super::ban_user)
+    #[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum BanUserError {
+        /// user is already banned
+        AlreadyBanned,
+    }
+
+    impl BanUserError {
+        /// Interpret an error returned by [Ban User](super::ban_user) as a [`BanUserError`]
+        ///
+        /// Returns `None` if the error is not specific to this endpoint.
+        pub fn from_request_error(error: &helix::HelixRequestPostError) -> Option<BanUserError> {
+            match error {
+                helix::HelixRequestPostError::Error {
+                    status, message, ..
+                } if matches!(
+                    *status,
+                    http::StatusCode::BAD_REQUEST | http::StatusCode::CONFLICT
+                ) && message.contains("already banned") =>
+                {
+                    Some(BanUserError::AlreadyBanned)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    impl helix::Request for BanUserRequest {
+        type Response = Vec<BanUser>;
+
+        const METHOD: http::Method = http::Method::POST;
+        const PATH: &'static str = "moderation/bans";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ModeratorManageBannedUsers];
+
+        fn query(&self) -> Result<String, helix::SerializeError> {
+            helix::query_with_default_moderator(self, |r| &mut r.moderator_id, &self.broadcaster_id)
+        }
+    }
+
+    impl helix::RequestPost for BanUserRequest {
+        type Body = BanUserBody;
+
+        const BODY_WRAPPING: helix::BodyWrapping = helix::BodyWrapping::Data;
+
+        fn validate_body(&self, body: &Self::Body) -> Result<(), helix::RequestValidationError> {
+            body.validate()
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = BanUserRequest::builder()
+            .broadcaster_id("1234")
+            .moderator_id("5678")
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "broadcaster_id": "1234",
+      "moderator_id": "5678",
+      "user_id": "9876",
+      "created_at": "2021-09-28T19:27:31Z",
+      "end_time": "2021-09-28T19:22:31Z"
+    }
+  ]
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/bans?broadcaster_id=1234&moderator_id=5678",
+        );
+
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
+    }
+
+    #[test]
+    fn test_default_moderator_id() {
+        use helix::*;
+        let req = BanUserRequest::builder().broadcaster_id("1234").build();
+        assert_eq!(
+            req.get_uri().unwrap().to_string(),
+            "https://api.twitch.tv/helix/moderation/bans?broadcaster_id=1234&moderator_id=1234"
+        );
+    }
+
+    #[test]
+    fn test_duration_bounds() {
+        use helix::*;
+        let req = BanUserRequest::builder()
+            .broadcaster_id("1234")
+            .moderator_id("5678")
+            .build();
+
+        let body = BanUserBody::builder()
+            .user_id("9876")
+            .duration(std::time::Duration::from_secs(
+                u64::from(MAX_TIMEOUT_DURATION) + 1,
+            ))
+            .build();
+        assert!(matches!(
+            req.create_request(body, "token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::TimeoutDurationOutOfRange(1_209_601)
+            ))
+        ));
+
+        let body = BanUserBody::builder()
+            .user_id("9876")
+            .duration(std::time::Duration::from_millis(500))
+            .build();
+        assert!(body.validate().is_err());
+
+        let body = BanUserBody::builder().user_id("9876").build();
+        assert!(req
+            .create_request(body, "token", &"clientid".into())
+            .is_ok());
+    }
+
+    #[test]
+    fn test_body() {
+        use helix::*;
+        let req = BanUserRequest::builder()
+            .broadcaster_id("1234")
+            .moderator_id("5678")
+            .build();
+
+        let body = BanUserBody::builder()
+            .user_id("9876")
+            .duration(std::time::Duration::from_secs(600))
+            .build();
+        assert_eq!(
+            req.body(&body).unwrap(),
+            r#"{"data":{"user_id":"9876","duration":600}}"#
+        );
+        let parsed: BanUserBody =
+            serde_json::from_str(r#"{"user_id":"9876","duration":600}"#).unwrap();
+        assert_eq!(parsed, body);
+    }
+
+    #[test]
+    fn test_already_banned() {
+        use helix::*;
+        let req = BanUserRequest::builder()
+            .broadcaster_id("1234")
+            .moderator_id("5678")
+            .build();
+
+        let data = br#"
+{
+  "error": "Bad Request",
+  "status": 400,
+  "message": "The user specified in the user_id field is already banned."
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().status(400).body(data).unwrap();
+
+        let uri = req.get_uri().unwrap();
+        let err = req.parse_response(&uri, http_response).unwrap_err();
+        assert_eq!(
+            BanUserError::from_request_error(&err),
+            Some(BanUserError::AlreadyBanned)
+        );
+    }
+}
+
+/// Removes the ban or timeout that was placed on the specified user.
+/// [`unban-user`](https://dev.twitch.tv/docs/api/reference#unban-user)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [UnbanUserRequest]
+///
+/// To use this endpoint, construct a [`UnbanUserRequest`] with the [`UnbanUserRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::moderation::unban_user;
+/// let request = unban_user::UnbanUserRequest::builder()
+///     .broadcaster_id("1234")
+///     .moderator_id("5678")
+///     .user_id("9876")
+///     .build();
+/// ```
+///
+/// ## Response: [UnbanUser]
+///
+/// Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, moderation::unban_user};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = unban_user::UnbanUserRequest::builder()
+///     .broadcaster_id("1234")
+///     .moderator_id("5678")
+///     .user_id("9876")
+///     .build();
+/// let response: unban_user::UnbanUser = client.req_delete(request, &token).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestDelete::parse_response())
+pub mod unban_user {
+    use super::*;
+
+    /// Query Parameters for [Unban User](super::unban_user)
+    ///
+    /// [`unban-user`](https://dev.twitch.tv/docs/api/reference#unban-user)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct UnbanUserRequest {
+        /// The ID of the broadcaster whose chat room the user is banned from chatting in.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// The ID of a user that has permission to moderate the broadcaster’s chat room. Must match the User ID in the Bearer token.
+        ///
+        /// Defaults to [`broadcaster_id`](UnbanUserRequest::broadcaster_id) when not set, for when the broadcaster is also the moderator.
+        #[builder(default, setter(into, strip_option))]
+        pub moderator_id: Option<types::UserId>,
+        /// The ID of the user to remove the ban or timeout from.
+        #[builder(setter(into))]
+        pub user_id: types::UserId,
+    }
+
+    /// Return Values for [Unban User](super::unban_user)
+    ///
+    /// [`unban-user`](https://dev.twitch.tv/docs/api/reference#unban-user)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum UnbanUser {
+        /// 204 - Successfully removed the ban or timeout
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for UnbanUser {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT => Ok(UnbanUser::Success),
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    /// Errors specific to [Unban User](super::unban_user)
+    #[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum UnbanUserError {
+        /// user is not banned
+        NotBanned,
+    }
+
+    impl UnbanUserError {
+        /// Interpret an error returned by [Unban User](super::unban_user) as a [`UnbanUserError`]
+        ///
+        /// Returns `None` if the error is not specific to this endpoint.
+        pub fn from_request_error(
+            error: &helix::HelixRequestDeleteError,
+        ) -> Option<UnbanUserError> {
+            match error {
+                helix::HelixRequestDeleteError::Error {
+                    status, message, ..
+                } if matches!(
+                    *status,
+                    http::StatusCode::BAD_REQUEST | http::StatusCode::CONFLICT
+                ) && message.contains("not banned") =>
+                {
+                    Some(UnbanUserError::NotBanned)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    impl helix::Request for UnbanUserRequest {
+        type Response = UnbanUser;
+
+        const METHOD: http::Method = http::Method::DELETE;
+        const PATH: &'static str = "moderation/bans";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ModeratorManageBannedUsers];
+
+        fn query(&self) -> Result<String, helix::SerializeError> {
+            helix::query_with_default_moderator(self, |r| &mut r.moderator_id, &self.broadcaster_id)
+        }
+    }
+
+    impl helix::RequestDelete for UnbanUserRequest {}
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = UnbanUserRequest::builder()
+            .broadcaster_id("1234")
+            .moderator_id("5678")
+            .user_id("9876")
+            .build();
+
+        // From twitch docs
+        let data = br#""#.to_vec();
+
+        let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/bans?broadcaster_id=1234&moderator_id=5678&user_id=9876",
+        );
+
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
+    }
+
+    #[test]
+    fn test_default_moderator_id() {
+        let req = UnbanUserRequest::builder()
+            .broadcaster_id("1234")
+            .user_id("9876")
+            .build();
+        helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/bans?broadcaster_id=1234&moderator_id=1234&user_id=9876",
+        );
+    }
+
+    #[test]
+    fn test_not_banned() {
+        use helix::*;
+        let req = UnbanUserRequest::builder()
+            .broadcaster_id("1234")
+            .moderator_id("5678")
+            .user_id("9876")
+            .build();
+
+        let data = br#"
+{
+  "error": "Bad Request",
+  "status": 400,
+  "message": "The user specified in the user_id field is not banned."
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder()
+            .status(400)
+            .header("Twitch-Trace-Id", "0123456789abcdef")
+            .body(data)
+            .unwrap();
+
+        let uri = req.get_uri().unwrap();
+        let err = req.parse_response(&uri, http_response).unwrap_err();
+        assert_eq!(
+            UnbanUserError::from_request_error(&err),
+            Some(UnbanUserError::NotBanned)
+        );
+        assert_eq!(
+            err.twitch_error().unwrap().to_string(),
+            "400 Bad Request: The user specified in the user_id field is not banned."
+        );
+        assert_eq!(err.request_id(), Some("0123456789abcdef"));
+    }
+}
+
+/// Adds a moderator to the broadcaster’s chat room.
+/// [`add-channel-moderator`](https://dev.twitch.tv/docs/api/reference#add-channel-moderator)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [AddChannelModeratorRequest]
+///
+/// To use this endpoint, construct a [`AddChannelModeratorRequest`] with the [`AddChannelModeratorRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::moderation::add_channel_moderator;
+/// let request = add_channel_moderator::AddChannelModeratorRequest::builder()
+///     .broadcaster_id("1234")
+///     .user_id("5678")
+///     .build();
+/// ```
+///
+/// ## Response: [AddChannelModerator]
+///
+/// This endpoint has no body, send the request with `()` as the body with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, moderation::add_channel_moderator};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = add_channel_moderator::AddChannelModeratorRequest::builder()
+///     .broadcaster_id("1234")
+///     .user_id("5678")
+///     .build();
+/// let response: add_channel_moderator::AddChannelModerator =
+///     client.req_post(request, (), &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPost::parse_response())
+pub mod add_channel_moderator {
+    use super::*;
+    use std::convert::TryInto;
+
+    /// Query Parameters for [Add Channel Moderator](super::add_channel_moderator)
+    ///
+    /// [`add-channel-moderator`](https://dev.twitch.tv/docs/api/reference#add-channel-moderator)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct AddChannelModeratorRequest {
+        /// The ID of the broadcaster that owns the chat room. Must match the User ID in the Bearer token.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// The ID of the user to add as a moderator in the broadcaster’s chat room.
+        #[builder(setter(into))]
+        pub user_id: types::UserId,
+    }
+
+    /// Return Values for [Add Channel Moderator](super::add_channel_moderator)
+    ///
+    /// [`add-channel-moderator`](https://dev.twitch.tv/docs/api/reference#add-channel-moderator)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum AddChannelModerator {
+        /// 204 - Successfully added the moderator
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for AddChannelModerator {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT | http::StatusCode::OK => {
+                    Ok(AddChannelModerator::Success)
+                }
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    /// Errors specific to [Add Channel Moderator](super::add_channel_moderator)
+    #[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum AddChannelModeratorError {
+        /// user is already a moderator
+        AlreadyModerator,
+        /// user is a VIP and must be removed as VIP before being added as moderator
+        IsVip,
+    }
+
+    impl AddChannelModeratorError {
+        /// Interpret an error returned by [Add Channel Moderator](super::add_channel_moderator) as a [`AddChannelModeratorError`]
+        ///
+        /// Returns `None` if the error is not specific to this endpoint.
+        pub fn from_request_error(
+            error: &helix::HelixRequestPostError,
+        ) -> Option<AddChannelModeratorError> {
+            match error {
+                helix::HelixRequestPostError::Error {
+                    status, message, ..
+                } if matches!(
+                    *status,
+                    http::StatusCode::BAD_REQUEST | http::StatusCode::UNPROCESSABLE_ENTITY
+                ) =>
+                {
+                    let message = message.to_lowercase();
+                    if message.contains("vip") {
+                        Some(AddChannelModeratorError::IsVip)
+                    } else if message.contains("already") {
+                        Some(AddChannelModeratorError::AlreadyModerator)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        }
+    }
+
+    impl helix::Request for AddChannelModeratorRequest {
+        type Response = AddChannelModerator;
+
+        const METHOD: http::Method = http::Method::POST;
+        const PATH: &'static str = "moderation/moderators";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ChannelManageModerators];
+    }
+
+    impl helix::RequestPost for AddChannelModeratorRequest {
+        type Body = ();
+
+        fn body(&self, _: &Self::Body) -> Result<String, serde_json::Error> { Ok(String::new()) }
+
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestPostError,
+        >
+        where
+            Self: Sized,
+        {
+            #[cfg_attr(not(feature = "raw_json"), allow(unused_variables))]
+            let (text, request_id) = helix::post_response_text(uri, &response)?;
+            let data = response.status().try_into().map_err(|_| {
+                // This path should never be taken, but just to be sure we do this
+                helix::HelixRequestPostError::Error {
+                    status: response.status(),
+                    uri: uri.clone(),
+                    request_id: request_id.clone(),
+                    body: response.body().clone(),
+                    message: String::new(),
+                    error: String::new(),
+                }
+            })?;
+            Ok(helix::Response {
+                data,
+                pagination: <_>::default(),
+                request: self,
+                request_id,
+                status: response.status(),
+                #[cfg(feature = "raw_json")]
+                raw: helix::raw_json(text),
+            })
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = AddChannelModeratorRequest::builder()
+            .broadcaster_id("11111")
+            .user_id("44444")
+            .build();
+
+        // From twitch docs
+        let data = br#""#.to_vec();
+
+        let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/moderators?broadcaster_id=11111&user_id=44444",
+        );
+
+        assert_eq!(
+            req.parse_response(&uri, http_response).unwrap().data,
+            AddChannelModerator::Success
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        use helix::*;
+        let req = AddChannelModeratorRequest::builder()
+            .broadcaster_id("11111")
+            .user_id("44444")
+            .build();
+        let uri = req.get_uri().unwrap();
+
+        let data =
+            br#"{"error":"Unprocessable Entity","status":422,"message":"user is already a mod"}"#
+                .to_vec();
+        let http_response = http::Response::builder().status(422).body(data).unwrap();
+        let err = req.clone().parse_response(&uri, http_response).unwrap_err();
+        assert_eq!(
+            AddChannelModeratorError::from_request_error(&err),
+            Some(AddChannelModeratorError::AlreadyModerator)
+        );
+
+        let data = br#"{"error":"Unprocessable Entity","status":422,"message":"user is a vip. To make them a moderator, you must first remove them as a vip."}"#
+            .to_vec();
+        let http_response = http::Response::builder().status(422).body(data).unwrap();
+        let err = req.parse_response(&uri, http_response).unwrap_err();
+        assert_eq!(
+            AddChannelModeratorError::from_request_error(&err),
+            Some(AddChannelModeratorError::IsVip)
+        );
+    }
+}
+
+/// Removes a moderator from the broadcaster’s chat room.
+/// [`remove-channel-moderator`](https://dev.twitch.tv/docs/api/reference#remove-channel-moderator)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [RemoveChannelModeratorRequest]
+///
+/// To use this endpoint, construct a [`RemoveChannelModeratorRequest`] with the [`RemoveChannelModeratorRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::moderation::remove_channel_moderator;
+/// let request = remove_channel_moderator::RemoveChannelModeratorRequest::builder()
+///     .broadcaster_id("1234")
+///     .user_id("5678")
+///     .build();
+/// ```
+///
+/// ## Response: [RemoveChannelModerator]
+///
+/// Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, moderation::remove_channel_moderator};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = remove_channel_moderator::RemoveChannelModeratorRequest::builder()
+///     .broadcaster_id("1234")
+///     .user_id("5678")
+///     .build();
+/// let response: remove_channel_moderator::RemoveChannelModerator =
+///     client.req_delete(request, &token).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestDelete::parse_response())
+pub mod remove_channel_moderator {
+    use super::*;
+
+    /// Query Parameters for [Remove Channel Moderator](super::remove_channel_moderator)
+    ///
+    /// [`remove-channel-moderator`](https://dev.twitch.tv/docs/api/reference#remove-channel-moderator)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct RemoveChannelModeratorRequest {
+        /// The ID of the broadcaster that owns the chat room. Must match the User ID in the Bearer token.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// The ID of the user to remove as a moderator from the broadcaster’s chat room.
+        #[builder(setter(into))]
+        pub user_id: types::UserId,
+    }
+
+    /// Return Values for [Remove Channel Moderator](super::remove_channel_moderator)
+    ///
+    /// [`remove-channel-moderator`](https://dev.twitch.tv/docs/api/reference#remove-channel-moderator)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum RemoveChannelModerator {
+        /// 204 - Successfully removed the moderator
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for RemoveChannelModerator {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT => Ok(RemoveChannelModerator::Success),
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    /// Errors specific to [Remove Channel Moderator](super::remove_channel_moderator)
+    #[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum RemoveChannelModeratorError {
+        /// user is not a moderator
+        NotModerator,
+    }
+
+    impl RemoveChannelModeratorError {
+        /// Interpret an error returned by [Remove Channel Moderator](super::remove_channel_moderator) as a [`RemoveChannelModeratorError`]
+        ///
+        /// Returns `None` if the error is not specific to this endpoint.
+        pub fn from_request_error(
+            error: &helix::HelixRequestDeleteError,
+        ) -> Option<RemoveChannelModeratorError> {
+            match error {
+                helix::HelixRequestDeleteError::Error {
+                    status, message, ..
+                } if matches!(
+                    *status,
+                    http::StatusCode::BAD_REQUEST | http::StatusCode::UNPROCESSABLE_ENTITY
+                ) && message.to_lowercase().contains("not a mod") =>
+                {
+                    Some(RemoveChannelModeratorError::NotModerator)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    impl helix::Request for RemoveChannelModeratorRequest {
+        type Response = RemoveChannelModerator;
+
+        const METHOD: http::Method = http::Method::DELETE;
+        const PATH: &'static str = "moderation/moderators";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ChannelManageModerators];
+    }
+
+    impl helix::RequestDelete for RemoveChannelModeratorRequest {}
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = RemoveChannelModeratorRequest::builder()
+            .broadcaster_id("11111")
+            .user_id("44444")
+            .build();
+
+        // From twitch docs
+        let data = br#""#.to_vec();
+
+        let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/moderators?broadcaster_id=11111&user_id=44444",
+        );
+
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
+    }
+
+    #[test]
+    fn test_not_moderator() {
+        use helix::*;
+        let req = RemoveChannelModeratorRequest::builder()
+            .broadcaster_id("11111")
+            .user_id("44444")
+            .build();
+
+        let data =
+            br#"{"error":"Bad Request","status":400,"message":"user is not a mod"}"#.to_vec();
+
+        let http_response = http::Response::builder().status(400).body(data).unwrap();
+
+        let uri = req.get_uri().unwrap();
+        let err = req.parse_response(&uri, http_response).unwrap_err();
+        assert_eq!(
+            RemoveChannelModeratorError::from_request_error(&err),
+            Some(RemoveChannelModeratorError::NotModerator)
+        );
+    }
+}
+
+/// Allow or deny a message that was held for review by AutoMod.
+/// [`manage-held-automod-messages`](https://dev.twitch.tv/docs/api/reference#manage-held-automod-messages)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [ManageHeldAutoModMessagesRequest]
+///
+/// To use this endpoint, construct a [`ManageHeldAutoModMessagesRequest`] with the [`ManageHeldAutoModMessagesRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::moderation::manage_held_automod_messages;
+/// let request = manage_held_automod_messages::ManageHeldAutoModMessagesRequest::builder().build();
+/// ```
+///
+/// ## Body: [ManageHeldAutoModMessagesBody]
+///
+/// We also need to provide a body to the request containing what we want to do with the message.
+///
+/// ```
+/// # use twitch_api2::helix::moderation::manage_held_automod_messages;
+/// let body = manage_held_automod_messages::ManageHeldAutoModMessagesBody::builder()
+///     .user_id("9327994")
+///     .msg_id("836013710")
+///     .action(manage_held_automod_messages::AutoModAction::Allow)
+///     .build();
+/// ```
+///
+/// ## Response: [ManageHeldAutoModMessages]
+///
+/// Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, moderation::manage_held_automod_messages};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = manage_held_automod_messages::ManageHeldAutoModMessagesRequest::builder().build();
+/// let body = manage_held_automod_messages::ManageHeldAutoModMessagesBody::builder()
+///     .user_id("9327994")
+///     .msg_id("836013710")
+///     .action(manage_held_automod_messages::AutoModAction::Deny)
+///     .build();
+/// let response: manage_held_automod_messages::ManageHeldAutoModMessages =
+///     client.req_post(request, body, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPost::parse_response())
+pub mod manage_held_automod_messages {
+    use super::*;
+    use std::convert::TryInto;
+
+    /// Query Parameters for [Manage Held AutoMod Messages](super::manage_held_automod_messages)
+    ///
+    /// This endpoint has no query parameters, everything is sent in the [body](ManageHeldAutoModMessagesBody).
+    ///
+    /// [`manage-held-automod-messages`](https://dev.twitch.tv/docs/api/reference#manage-held-automod-messages)
+    #[derive(
+        PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default,
+    )]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct ManageHeldAutoModMessagesRequest {}
+
+    /// Body Parameters for [Manage Held AutoMod Messages](super::manage_held_automod_messages)
+    ///
+    /// [`manage-held-automod-messages`](https://dev.twitch.tv/docs/api/reference#manage-held-automod-messages)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct ManageHeldAutoModMessagesBody {
+        /// The moderator who is approving or denying the held message. Must match the User ID in the Bearer token.
+        #[builder(setter(into))]
+        pub user_id: types::UserId,
+        /// ID of the message to allow or deny.
+        #[builder(setter(into))]
+        pub msg_id: String,
+        /// What to do with the message.
+        pub action: AutoModAction,
+    }
+
+    /// What to do with a message held by AutoMod
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+    #[serde(rename_all = "UPPERCASE")]
+    #[non_exhaustive]
+    pub enum AutoModAction {
+        /// Let the message through to chat
+        Allow,
+        /// Drop the message
+        Deny,
+    }
+
+    /// Fields of an EventSub [`automod.message.hold`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#automodmessagehold) event needed to resolve it
+    ///
+    /// Deserialize the `event` object of the notification into this to pass it to [`HelixClient::resolve_held_message`](helix::HelixClient::resolve_held_message).
+    /// Other fields of the event are ignored.
+    #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct HeldAutoModMessage {
+        /// ID of the broadcaster in whose chat the message was held
+        pub broadcaster_user_id: types::UserId,
+        /// ID of the user who sent the message
+        pub user_id: types::UserId,
+        /// ID of the held message
+        pub message_id: String,
+    }
+
+    impl HeldAutoModMessage {
+        /// Create the [body](ManageHeldAutoModMessagesBody) that resolves this message as `moderator_id`
+        pub fn to_body(
+            &self,
+            moderator_id: impl Into<types::UserId>,
+            action: AutoModAction,
+        ) -> ManageHeldAutoModMessagesBody {
+            ManageHeldAutoModMessagesBody {
+                user_id: moderator_id.into(),
+                msg_id: self.message_id.clone(),
+                action,
+            }
+        }
+    }
+
+    /// Return Values for [Manage Held AutoMod Messages](super::manage_held_automod_messages)
+    ///
+    /// [`manage-held-automod-messages`](https://dev.twitch.tv/docs/api/reference#manage-held-automod-messages)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum ManageHeldAutoModMessages {
+        /// 204 - Successfully approved or denied the message
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for ManageHeldAutoModMessages {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT | http::StatusCode::OK => {
+                    Ok(ManageHeldAutoModMessages::Success)
+                }
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    impl helix::Request for ManageHeldAutoModMessagesRequest {
+        type Response = ManageHeldAutoModMessages;
+
+        const METHOD: http::Method = http::Method::POST;
+        const PATH: &'static str = "moderation/automod/message";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ModeratorManageAutoMod];
+    }
+
+    impl helix::RequestPost for ManageHeldAutoModMessagesRequest {
+        type Body = ManageHeldAutoModMessagesBody;
+
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestPostError,
+        >
+        where
+            Self: Sized,
+        {
+            #[cfg_attr(not(feature = "raw_json"), allow(unused_variables))]
+            let (text, request_id) = helix::post_response_text(uri, &response)?;
+            let data = response.status().try_into().map_err(|_| {
+                // This path should never be taken, but just to be sure we do this
+                helix::HelixRequestPostError::Error {
+                    status: response.status(),
+                    uri: uri.clone(),
+                    request_id: request_id.clone(),
+                    body: response.body().clone(),
+                    message: String::new(),
+                    error: String::new(),
+                }
+            })?;
+            Ok(helix::Response {
+                data,
+                pagination: <_>::default(),
+                request: self,
+                request_id,
+                status: response.status(),
+                #[cfg(feature = "raw_json")]
+                raw: helix::raw_json(text),
+            })
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = ManageHeldAutoModMessagesRequest::builder().build();
+
+        let body = ManageHeldAutoModMessagesBody::builder()
+            .user_id("9327994")
+            .msg_id("836013710")
+            .action(AutoModAction::Allow)
+            .build();
+
+        assert_eq!(
+            req.body(&body).unwrap(),
+            r#"{"user_id":"9327994","msg_id":"836013710","action":"ALLOW"}"#
+        );
+
+        // From twitch docs
+        let data = br#""#.to_vec();
+
+        let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/automod/message?",
+        );
+
+        assert_eq!(
+            req.parse_response(&uri, http_response).unwrap().data,
+            ManageHeldAutoModMessages::Success
+        );
+    }
+
+    #[test]
+    fn test_held_event() {
+        // From twitch docs, the `event` object of an `automod.message.hold` notification
+        let event = r#"
+{
+    "broadcaster_user_id": "1337",
+    "broadcaster_user_name": "blah",
+    "broadcaster_user_login": "blahblah",
+    "user_id": "456789012",
+    "user_name": "baduser",
+    "user_login": "baduserbla",
+    "message_id": "bad-message-id",
+    "message": "This is a bad message… ",
+    "level": 5,
+    "category": "aggressive",
+    "held_at": "2022-12-02T15:00:00.00Z",
+    "fragments": {
+        "emotes": [],
+        "cheermotes": []
+    }
+}
+"#;
+        let held: HeldAutoModMessage = serde_json::from_str(event).unwrap();
+        assert_eq!(held.user_id, "456789012");
+
+        let body = held.to_body("9327994", AutoModAction::Deny);
+        assert_eq!(body.user_id, "9327994");
+        assert_eq!(body.msg_id, "bad-message-id");
+        assert_eq!(body.action, AutoModAction::Deny);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_resolve_held_message() {
+        let token = helix::test::token(None);
+        // The third message was already resolved
+        let client = helix::HelixClient::with_client(helix::test::MockClient::new(|n, _| {
+            Some(match n {
+                0 | 1 => helix::test::response(204, ""),
+                _ => helix::test::response(
+                    400,
+                    r#"{"error":"Bad Request","status":400,"message":"message is not in a held state"}"#,
+                ),
+            })
+        }));
+        let held = HeldAutoModMessage {
+            broadcaster_user_id: "1337".to_string(),
+            user_id: "456789012".to_string(),
+            message_id: "bad-message-id".to_string(),
+        };
+        assert_eq!(
+            futures::executor::block_on(client.resolve_held_message(
+                "9327994",
+                &held,
+                AutoModAction::Allow,
+                &token,
+            ))
+            .unwrap(),
+            ManageHeldAutoModMessages::Success
+        );
+        assert_eq!(
+            futures::executor::block_on(client.resolve_held_message(
+                "9327994",
+                &held,
+                AutoModAction::Deny,
+                &token,
+            ))
+            .unwrap(),
+            ManageHeldAutoModMessages::Success
+        );
+        match futures::executor::block_on(client.resolve_held_message(
+            "9327994",
+            &held,
+            AutoModAction::Deny,
+            &token,
+        )) {
+            Err(helix::ClientRequestError::HelixRequestPostError(
+                helix::HelixRequestPostError::Error { status, .. },
+            )) => assert_eq!(status, http::StatusCode::BAD_REQUEST),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let requests = client.client.requests();
+        let bodies: Vec<_> = requests
+            .iter()
+            .map(|req| std::str::from_utf8(req.body()).unwrap())
+            .collect();
+        assert_eq!(
+            bodies,
+            [
+                r#"{"user_id":"9327994","msg_id":"bad-message-id","action":"ALLOW"}"#,
+                r#"{"user_id":"9327994","msg_id":"bad-message-id","action":"DENY"}"#,
+                r#"{"user_id":"9327994","msg_id":"bad-message-id","action":"DENY"}"#,
+            ]
+        );
+        assert_eq!(requests[0].method(), http::Method::POST);
+        assert_eq!(
+            requests[0].uri(),
+            "https://api.twitch.tv/helix/moderation/automod/message?"
+        );
+    }
+}
+
+/// Gets the broadcaster’s AutoMod settings, which are used to automatically block inappropriate or harassing messages from appearing in the broadcaster’s chat room.
+/// [`get-automod-settings`](https://dev.twitch.tv/docs/api/reference#get-automod-settings)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetAutoModSettingsRequest]
+///
+/// To use this endpoint, construct a [`GetAutoModSettingsRequest`] with the [`GetAutoModSettingsRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::moderation::get_automod_settings;
+/// let request = get_automod_settings::GetAutoModSettingsRequest::builder()
+///     .broadcaster_id("1234")
+///     .moderator_id("5678")
+///     .build();
+/// ```
+///
+/// ## Response: [AutoModSettings]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, moderation::get_automod_settings};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_automod_settings::GetAutoModSettingsRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let response: Vec<get_automod_settings::AutoModSettings> = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_automod_settings {
+    use super::*;
+
+    /// Query Parameters for [Get AutoMod Settings](super::get_automod_settings)
+    ///
+    /// [`get-automod-settings`](https://dev.twitch.tv/docs/api/reference#get-automod-settings)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetAutoModSettingsRequest {
+        /// The ID of the broadcaster whose AutoMod settings you want to get.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// The ID of the broadcaster or one of the broadcaster’s moderators. Must match the User ID in the Bearer token.
+        ///
+        /// Defaults to [`broadcaster_id`](GetAutoModSettingsRequest::broadcaster_id) when not set, for when the broadcaster is also the moderator.
+        #[builder(default, setter(into, strip_option))]
+        pub moderator_id: Option<types::UserId>,
+    }
+
+    /// Return Values for [Get AutoMod Settings](super::get_automod_settings)
+    ///
+    /// [`get-automod-settings`](https://dev.twitch.tv/docs/api/reference#get-automod-settings)
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct AutoModSettings {
+        /// The ID of the broadcaster whose AutoMod settings these are.
+        pub broadcaster_id: types::UserId,
+        /// The ID of the moderator who requested the settings.
+        pub moderator_id: types::UserId,
+        /// The default AutoMod level for the broadcaster. `None` if the broadcaster has set one or more of the individual levels.
+        pub overall_level: Option<types::AutoModLevel>,
+        /// The AutoMod level for discrimination against disability.
+        pub disability: types::AutoModLevel,
+        /// The AutoMod level for hostility involving aggression.
+        pub aggression: types::AutoModLevel,
+        /// The AutoMod level for discrimination based on sexuality, sex, or gender.
+        pub sexuality_sex_or_gender: types::AutoModLevel,
+        /// The AutoMod level for discrimination against women.
+        pub misogyny: types::AutoModLevel,
+        /// The AutoMod level for hostility involving name calling or insults.
+        pub bullying: types::AutoModLevel,
+        /// The AutoMod level for profanity.
+        pub swearing: types::AutoModLevel,
+        /// The AutoMod level for racial discrimination.
+        pub race_ethnicity_or_religion: types::AutoModLevel,
+        /// The AutoMod level for sexual content.
+        pub sex_based_terms: types::AutoModLevel,
+    }
+
+    impl helix::Request for GetAutoModSettingsRequest {
+        type Response = Vec<AutoModSettings>;
+
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "moderation/automod/settings";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ModeratorReadAutomodSettings];
+
+        fn query(&self) -> Result<String, helix::SerializeError> {
+            helix::query_with_default_moderator(self, |r| &mut r.moderator_id, &self.broadcaster_id)
+        }
+    }
+
+    impl helix::RequestGet for GetAutoModSettingsRequest {}
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetAutoModSettingsRequest::builder()
+            .broadcaster_id("1234")
+            .moderator_id("5678")
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+    "data": [
+        {
+            "broadcaster_id": "1234",
+            "moderator_id": "5678",
+            "overall_level": null,
+            "disability": 0,
+            "aggression": 0,
+            "sexuality_sex_or_gender": 0,
+            "misogyny": 0,
+            "bullying": 0,
+            "swearing": 0,
+            "race_ethnicity_or_religion": 0,
+            "sex_based_terms": 0
+        }
+    ]
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/automod/settings?broadcaster_id=1234&moderator_id=5678",
+        );
+
+        let settings = req.parse_response(&uri, http_response).unwrap().data;
+        assert_eq!(settings.len(), 1);
+        assert_eq!(settings[0].overall_level, None);
+        assert_eq!(settings[0].swearing.get(), 0);
+    }
+
+    #[test]
+    fn test_level_out_of_range() {
+        use helix::*;
+        let req = GetAutoModSettingsRequest::builder()
+            .broadcaster_id("1234")
+            .build();
+
+        let data = br#"
+{
+    "data": [
+        {
+            "broadcaster_id": "1234",
+            "moderator_id": "1234",
+            "overall_level": 3,
+            "disability": 3,
+            "aggression": 3,
+            "sexuality_sex_or_gender": 3,
+            "misogyny": 3,
+            "bullying": 3,
+            "swearing": 5,
+            "race_ethnicity_or_religion": 3,
+            "sex_based_terms": 3
+        }
+    ]
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/moderation/automod/settings?broadcaster_id=1234&moderator_id=1234",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        assert!(req.parse_response(&uri, http_response).is_err());
     }
 }
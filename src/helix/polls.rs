@@ -0,0 +1,465 @@
+//! Endpoints regarding polls
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, polls::GetPollsRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetPollsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+#[doc(inline)]
+pub use end_poll::{EndPoll, EndPollBody, EndPollRequest, EndPollStatus};
+#[doc(inline)]
+pub use get_polls::{GetPollsRequest, Poll, PollChoice};
+
+use crate::{helix, types};
+use serde::{Deserialize, Serialize};
+
+/// Status of a [Poll]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum PollStatus {
+    /// Poll is currently in progress.
+    Active,
+    /// Poll has reached its ended_at time.
+    Completed,
+    /// Poll has been manually terminated before its ended_at time.
+    Terminated,
+    /// Poll is no longer visible on the channel.
+    Archived,
+    /// Poll is no longer visible to any user on Twitch.
+    Moderated,
+    /// Something went wrong determining the state.
+    Invalid,
+}
+
+/// Get information about all polls or specific polls for a Twitch channel.
+/// [`get-polls`](https://dev.twitch.tv/docs/api/reference#get-polls)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetPollsRequest]
+///
+/// To use this endpoint, construct a [`GetPollsRequest`] with the [`GetPollsRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::polls::get_polls;
+/// let request = get_polls::GetPollsRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// ```
+///
+/// ## Response: [Poll]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, polls::get_polls};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_polls::GetPollsRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let response: Vec<get_polls::Poll> = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_polls {
+    use super::*;
+
+    /// Query Parameters for [Get Polls](super::get_polls)
+    ///
+    /// [`get-polls`](https://dev.twitch.tv/docs/api/reference#get-polls)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetPollsRequest {
+        /// The broadcaster running polls. Provided broadcaster_id must match the user_id in the user OAuth token.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// ID of a poll. Filters results to one or more specific polls. Not providing one or more IDs will return the full list of polls for the authenticated channel. Maximum: 100
+        #[builder(default)]
+        #[serde(default)]
+        pub id: Vec<String>,
+        /// Cursor for forward pagination: The cursor value specified here is from the pagination response field of a prior query.
+        #[builder(default)]
+        pub after: Option<helix::Cursor>,
+        /// Maximum number of objects to return. Maximum: 20. Default: 20.
+        #[builder(default, setter(into))]
+        pub first: Option<usize>,
+    }
+
+    /// Return Values for [Get Polls](super::get_polls)
+    ///
+    /// [`get-polls`](https://dev.twitch.tv/docs/api/reference#get-polls)
+    #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct Poll {
+        /// ID of the poll.
+        pub id: String,
+        /// ID of the broadcaster.
+        pub broadcaster_id: types::UserId,
+        /// Display name of the broadcaster.
+        pub broadcaster_name: types::DisplayName,
+        /// Login of the broadcaster.
+        pub broadcaster_login: types::UserName,
+        /// Question displayed for the poll.
+        pub title: String,
+        /// The poll choices.
+        pub choices: Vec<PollChoice>,
+        /// Indicates if Bits can be used for voting.
+        pub bits_voting_enabled: bool,
+        /// Number of Bits required to vote once with Bits.
+        pub bits_per_vote: i64,
+        /// Indicates if Channel Points can be used for voting.
+        pub channel_points_voting_enabled: bool,
+        /// Number of Channel Points required to vote once with Channel Points.
+        pub channel_points_per_vote: i64,
+        /// Poll status.
+        pub status: PollStatus,
+        /// Total duration for the poll (in seconds).
+        pub duration: i64,
+        /// UTC timestamp for the poll’s start time.
+        pub started_at: types::Timestamp,
+        /// UTC timestamp for the poll’s end time. Set to `None` if the poll is active.
+        #[serde(default)]
+        pub ended_at: Option<types::Timestamp>,
+    }
+
+    /// A choice in a [Poll]
+    #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct PollChoice {
+        /// ID for the choice.
+        pub id: String,
+        /// Text displayed for the choice.
+        pub title: String,
+        /// Total number of votes received for the choice across all methods of voting.
+        pub votes: i64,
+        /// Number of votes received via Channel Points.
+        pub channel_points_votes: i64,
+        /// Number of votes received via Bits.
+        pub bits_votes: i64,
+    }
+
+    impl helix::Request for GetPollsRequest {
+        type Response = Vec<Poll>;
+
+        const MAX_FIRST: usize = 20;
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "polls";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadPolls];
+    }
+
+    impl helix::RequestGet for GetPollsRequest {}
+
+    impl helix::Paginated for GetPollsRequest {
+        fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetPollsRequest::builder()
+            .broadcaster_id("55696719")
+            .id(vec!["ed961efd-8a3f-4cf5-a9d0-e616c590cd2a".to_string()])
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "id": "ed961efd-8a3f-4cf5-a9d0-e616c590cd2a",
+      "broadcaster_id": "55696719",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "title": "Heads or Tails?",
+      "choices": [
+        {
+          "id": "4c123012-1351-4f33-84b7-43856e7a0f47",
+          "title": "Heads",
+          "votes": 0,
+          "channel_points_votes": 0,
+          "bits_votes": 0
+        },
+        {
+          "id": "279087e3-54a7-467e-bcd0-c1393fcea4f0",
+          "title": "Tails",
+          "votes": 0,
+          "channel_points_votes": 0,
+          "bits_votes": 0
+        }
+      ],
+      "bits_voting_enabled": false,
+      "bits_per_vote": 0,
+      "channel_points_voting_enabled": false,
+      "channel_points_per_vote": 0,
+      "status": "ACTIVE",
+      "duration": 1800,
+      "started_at": "2021-03-19T06:08:33.871278372Z"
+    }
+  ],
+  "pagination": {}
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/polls?broadcaster_id=55696719&id=ed961efd-8a3f-4cf5-a9d0-e616c590cd2a",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap().data;
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].status, PollStatus::Active);
+        assert_eq!(res[0].choices.len(), 2);
+        assert_eq!(res[0].ended_at, None);
+    }
+
+    #[test]
+    fn test_max_first() {
+        use helix::*;
+        let req = |first| {
+            GetPollsRequest::builder()
+                .broadcaster_id("55696719")
+                .first(first)
+                .build()
+        };
+        assert!(req(20).create_request("token", &"clientid".into()).is_ok());
+        assert!(matches!(
+            req(21).create_request("token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::FirstTooLarge(21, 20)
+            ))
+        ));
+    }
+}
+
+/// End a poll that is currently active.
+/// [`end-poll`](https://dev.twitch.tv/docs/api/reference#end-poll)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [EndPollRequest]
+///
+/// To use this endpoint, construct an [`EndPollRequest`] with the [`EndPollRequest::new()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::polls::end_poll;
+/// let request = end_poll::EndPollRequest::new();
+/// ```
+///
+/// ## Body: [EndPollBody]
+///
+/// We also need to provide a body to the request containing what poll to end and how.
+///
+/// ```
+/// # use twitch_api2::helix::polls::end_poll;
+/// let body = end_poll::EndPollBody::builder()
+///     .broadcaster_id("1234")
+///     .id("ed961efd-8a3f-4cf5-a9d0-e616c590cd2a")
+///     .status(end_poll::EndPollStatus::Terminated)
+///     .build();
+/// ```
+///
+/// ## Response: [EndPoll]
+///
+/// Send the request to receive the response with [`HelixClient::req_patch()`](helix::HelixClient::req_patch).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, polls::end_poll};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = end_poll::EndPollRequest::new();
+/// let body = end_poll::EndPollBody::builder()
+///     .broadcaster_id("1234")
+///     .id("ed961efd-8a3f-4cf5-a9d0-e616c590cd2a")
+///     .status(end_poll::EndPollStatus::Terminated)
+///     .build();
+/// let response: end_poll::EndPoll = client.req_patch(request, body, &token).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPatch::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPatch::parse_response())
+pub mod end_poll {
+    use super::*;
+
+    impl EndPollRequest {
+        /// Create a new [`EndPollRequest`]
+        pub fn new() -> Self { EndPollRequest {} }
+    }
+
+    /// Query Parameters for [End Poll](super::end_poll)
+    ///
+    /// [`end-poll`](https://dev.twitch.tv/docs/api/reference#end-poll)
+    #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct EndPollRequest {}
+
+    impl Default for EndPollRequest {
+        fn default() -> Self { EndPollRequest::new() }
+    }
+
+    /// Body Parameters for [End Poll](super::end_poll)
+    ///
+    /// [`end-poll`](https://dev.twitch.tv/docs/api/reference#end-poll)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct EndPollBody {
+        /// The broadcaster running polls. Provided broadcaster_id must match the user_id in the user OAuth token.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// ID of the poll.
+        #[builder(setter(into))]
+        pub id: String,
+        /// The poll status to be set.
+        pub status: EndPollStatus,
+    }
+
+    /// Status to set when ending a poll with [End Poll](super::end_poll)
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+    #[serde(rename_all = "UPPERCASE")]
+    #[non_exhaustive]
+    pub enum EndPollStatus {
+        /// End the poll manually, but allow it to be viewed publicly.
+        Terminated,
+        /// End the poll manually and do not allow it to be viewed publicly.
+        Archived,
+    }
+
+    /// Return Values for [End Poll](super::end_poll)
+    ///
+    /// [`end-poll`](https://dev.twitch.tv/docs/api/reference#end-poll)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum EndPoll {
+        /// 200 - Poll ended successfully
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for EndPoll {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::OK | http::StatusCode::NO_CONTENT => Ok(EndPoll::Success),
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    impl helix::Request for EndPollRequest {
+        type Response = EndPoll;
+
+        const METHOD: http::Method = http::Method::PATCH;
+        const PATH: &'static str = "polls";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManagePolls];
+    }
+
+    impl helix::RequestPatch for EndPollRequest {
+        type Body = EndPollBody;
+    }
+
+    #[test]
+    fn test_body() {
+        let body = EndPollBody::builder()
+            .broadcaster_id("141981764")
+            .id("ed961efd-8a3f-4cf5-a9d0-e616c590cd2a")
+            .status(EndPollStatus::Terminated)
+            .build();
+
+        assert_eq!(
+            serde_json::to_string(&body).unwrap(),
+            r#"{"broadcaster_id":"141981764","id":"ed961efd-8a3f-4cf5-a9d0-e616c590cd2a","status":"TERMINATED"}"#
+        );
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = EndPollRequest::new();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "id": "ed961efd-8a3f-4cf5-a9d0-e616c590cd2a",
+      "broadcaster_id": "141981764",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "title": "Heads or Tails?",
+      "choices": [
+        {
+          "id": "4c123012-1351-4f33-84b7-43856e7a0f47",
+          "title": "Heads",
+          "votes": 0,
+          "channel_points_votes": 0,
+          "bits_votes": 0
+        },
+        {
+          "id": "279087e3-54a7-467e-bcd0-c1393fcea4f0",
+          "title": "Tails",
+          "votes": 0,
+          "channel_points_votes": 0,
+          "bits_votes": 0
+        }
+      ],
+      "bits_voting_enabled": false,
+      "bits_per_vote": 0,
+      "channel_points_voting_enabled": true,
+      "channel_points_per_vote": 100,
+      "status": "TERMINATED",
+      "duration": 1800,
+      "started_at": "2021-03-19T06:08:33.871278372Z",
+      "ended_at": "2021-03-19T06:11:26.746889614Z"
+    }
+  ]
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/polls?");
+
+        assert_eq!(
+            req.parse_response(&uri, http_response).unwrap(),
+            EndPoll::Success
+        );
+    }
+}
@@ -0,0 +1,536 @@
+//! Endpoints regarding predictions
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, predictions::GetPredictionsRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetPredictionsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+#[doc(inline)]
+pub use end_prediction::{
+    EndPrediction, EndPredictionBody, EndPredictionRequest, EndPredictionStatus,
+};
+#[doc(inline)]
+pub use get_predictions::{GetPredictionsRequest, Prediction, PredictionOutcome, TopPredictor};
+
+use crate::{helix, types};
+use serde::{Deserialize, Serialize};
+
+/// Status of a [Prediction]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum PredictionStatus {
+    /// The Prediction is active and viewers can make predictions.
+    Active,
+    /// A winning outcome has been chosen and the Channel Points have been distributed to the users who guessed the correct outcome.
+    Resolved,
+    /// The Prediction has been canceled and the Channel Points have been refunded to participants.
+    Canceled,
+    /// The Prediction has been locked and viewers can no longer make predictions.
+    Locked,
+}
+
+/// Color of a [PredictionOutcome]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum OutcomeColor {
+    /// Blue, the color of the first outcome
+    Blue,
+    /// Pink, the color of the second outcome
+    Pink,
+}
+
+/// Get information about all Channel Points Predictions or specific Channel Points Predictions for a Twitch channel.
+/// [`get-predictions`](https://dev.twitch.tv/docs/api/reference#get-predictions)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetPredictionsRequest]
+///
+/// To use this endpoint, construct a [`GetPredictionsRequest`] with the [`GetPredictionsRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::predictions::get_predictions;
+/// let request = get_predictions::GetPredictionsRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// ```
+///
+/// ## Response: [Prediction]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, predictions::get_predictions};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_predictions::GetPredictionsRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let response: Vec<get_predictions::Prediction> = client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_predictions {
+    use super::*;
+
+    /// Query Parameters for [Get Predictions](super::get_predictions)
+    ///
+    /// [`get-predictions`](https://dev.twitch.tv/docs/api/reference#get-predictions)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetPredictionsRequest {
+        /// The broadcaster running Predictions. Provided broadcaster_id must match the user_id in the user OAuth token.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// ID of a Prediction. Filters results to one or more specific Predictions. Not providing one or more IDs will return the full list of Predictions for the authenticated channel. Maximum: 25
+        #[builder(default)]
+        #[serde(default)]
+        pub id: Vec<String>,
+        /// Cursor for forward pagination: The cursor value specified here is from the pagination response field of a prior query.
+        #[builder(default)]
+        pub after: Option<helix::Cursor>,
+        /// Maximum number of objects to return. Maximum: 20. Default: 20.
+        #[builder(default, setter(into))]
+        pub first: Option<usize>,
+    }
+
+    /// Return Values for [Get Predictions](super::get_predictions)
+    ///
+    /// [`get-predictions`](https://dev.twitch.tv/docs/api/reference#get-predictions)
+    #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct Prediction {
+        /// ID of the Prediction.
+        pub id: String,
+        /// ID of the broadcaster.
+        pub broadcaster_id: types::UserId,
+        /// Display name of the broadcaster.
+        pub broadcaster_name: types::DisplayName,
+        /// Login of the broadcaster.
+        pub broadcaster_login: types::UserName,
+        /// Title for the Prediction.
+        pub title: String,
+        /// ID of the winning outcome. `None` unless the Prediction is resolved.
+        #[serde(default)]
+        pub winning_outcome_id: Option<String>,
+        /// The possible outcomes for the Prediction.
+        pub outcomes: Vec<PredictionOutcome>,
+        /// Total duration for the Prediction (in seconds).
+        pub prediction_window: i64,
+        /// Status of the Prediction.
+        pub status: PredictionStatus,
+        /// UTC timestamp for the Prediction’s start time.
+        pub created_at: types::Timestamp,
+        /// UTC timestamp for when the Prediction ended. `None` if the Prediction is active.
+        #[serde(default)]
+        pub ended_at: Option<types::Timestamp>,
+        /// UTC timestamp for when the Prediction was locked. `None` if the Prediction is not locked.
+        #[serde(default)]
+        pub locked_at: Option<types::Timestamp>,
+    }
+
+    /// An outcome of a [Prediction]
+    #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct PredictionOutcome {
+        /// ID for the outcome.
+        pub id: String,
+        /// Text displayed for outcome.
+        pub title: String,
+        /// Number of unique users that chose the outcome.
+        pub users: i64,
+        /// Number of Channel Points used for the outcome.
+        pub channel_points: i64,
+        /// The users who used the most Channel Points on this outcome. `None` if nobody has made a prediction yet.
+        #[serde(default)]
+        pub top_predictors: Option<Vec<TopPredictor>>,
+        /// Color for the outcome.
+        pub color: OutcomeColor,
+    }
+
+    /// One of the [top predictors](PredictionOutcome::top_predictors) of an outcome
+    #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct TopPredictor {
+        /// ID of the user.
+        pub user_id: types::UserId,
+        /// Display name of the user.
+        pub user_name: types::DisplayName,
+        /// Login of the user.
+        pub user_login: types::UserName,
+        /// Number of Channel Points used by the user.
+        pub channel_points_used: i64,
+        /// Number of Channel Points won by the user. `None` unless the Prediction is resolved.
+        #[serde(default)]
+        pub channel_points_won: Option<i64>,
+    }
+
+    impl helix::Request for GetPredictionsRequest {
+        type Response = Vec<Prediction>;
+
+        const MAX_FIRST: usize = 20;
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "predictions";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ChannelReadPredictions];
+    }
+
+    impl helix::RequestGet for GetPredictionsRequest {}
+
+    impl helix::Paginated for GetPredictionsRequest {
+        fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetPredictionsRequest::builder()
+            .broadcaster_id("55696719")
+            .id(vec!["d6676d5c-c86e-44d2-bfc4-100fb48f0656".to_string()])
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "id": "d6676d5c-c86e-44d2-bfc4-100fb48f0656",
+      "broadcaster_id": "55696719",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "title": "Will there be any leaks today?",
+      "winning_outcome_id": null,
+      "outcomes": [
+        {
+          "id": "021e9234-5893-49b4-982e-cfe9a0aaddd9",
+          "title": "Yes",
+          "users": 0,
+          "channel_points": 0,
+          "top_predictors": null,
+          "color": "BLUE"
+        },
+        {
+          "id": "ded84c26-13cb-4b48-8cb5-5bae3ec3a66e",
+          "title": "No",
+          "users": 1,
+          "channel_points": 500,
+          "top_predictors": [
+            {
+              "user_id": "141981764",
+              "user_name": "TwitchDev",
+              "user_login": "twitchdev",
+              "channel_points_used": 500,
+              "channel_points_won": null
+            }
+          ],
+          "color": "PINK"
+        }
+      ],
+      "prediction_window": 600,
+      "status": "ACTIVE",
+      "created_at": "2021-04-28T16:03:06.320848689Z",
+      "ended_at": null,
+      "locked_at": null
+    }
+  ],
+  "pagination": {}
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/predictions?broadcaster_id=55696719&id=d6676d5c-c86e-44d2-bfc4-100fb48f0656",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap().data;
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].status, PredictionStatus::Active);
+        assert_eq!(res[0].outcomes[0].top_predictors, None);
+        let top = res[0].outcomes[1].top_predictors.as_ref().unwrap();
+        assert_eq!(top[0].channel_points_used, 500);
+    }
+}
+
+/// Lock, resolve, or cancel a Channel Points Prediction.
+/// [`end-prediction`](https://dev.twitch.tv/docs/api/reference#end-prediction)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [EndPredictionRequest]
+///
+/// To use this endpoint, construct an [`EndPredictionRequest`] with the [`EndPredictionRequest::new()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::predictions::end_prediction;
+/// let request = end_prediction::EndPredictionRequest::new();
+/// ```
+///
+/// ## Body: [EndPredictionBody]
+///
+/// We also need to provide a body to the request containing what prediction to end and how.
+/// `winning_outcome_id` must be set when resolving the prediction, and only then.
+///
+/// ```
+/// # use twitch_api2::helix::predictions::end_prediction;
+/// let body = end_prediction::EndPredictionBody::builder()
+///     .broadcaster_id("1234")
+///     .id("d6676d5c-c86e-44d2-bfc4-100fb48f0656")
+///     .status(end_prediction::EndPredictionStatus::Resolved)
+///     .winning_outcome_id("021e9234-5893-49b4-982e-cfe9a0aaddd9".to_string())
+///     .build();
+/// ```
+///
+/// ## Response: [EndPrediction]
+///
+/// Send the request to receive the response with [`HelixClient::req_patch()`](helix::HelixClient::req_patch).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, predictions::end_prediction};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = end_prediction::EndPredictionRequest::new();
+/// let body = end_prediction::EndPredictionBody::builder()
+///     .broadcaster_id("1234")
+///     .id("d6676d5c-c86e-44d2-bfc4-100fb48f0656")
+///     .status(end_prediction::EndPredictionStatus::Canceled)
+///     .build();
+/// let response: end_prediction::EndPrediction = client.req_patch(request, body, &token).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPatch::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPatch::parse_response())
+pub mod end_prediction {
+    use super::*;
+
+    impl EndPredictionRequest {
+        /// Create a new [`EndPredictionRequest`]
+        pub fn new() -> Self { EndPredictionRequest {} }
+    }
+
+    /// Query Parameters for [End Prediction](super::end_prediction)
+    ///
+    /// [`end-prediction`](https://dev.twitch.tv/docs/api/reference#end-prediction)
+    #[derive(PartialEq, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct EndPredictionRequest {}
+
+    impl Default for EndPredictionRequest {
+        fn default() -> Self { EndPredictionRequest::new() }
+    }
+
+    /// Body Parameters for [End Prediction](super::end_prediction)
+    ///
+    /// [`end-prediction`](https://dev.twitch.tv/docs/api/reference#end-prediction)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct EndPredictionBody {
+        /// The broadcaster running prediction events. Provided broadcaster_id must match the user_id in the user OAuth token.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// ID of the Prediction.
+        #[builder(setter(into))]
+        pub id: String,
+        /// The Prediction status to be set.
+        pub status: EndPredictionStatus,
+        /// ID of the winning outcome for the Prediction. Required if status is [`Resolved`](EndPredictionStatus::Resolved), not allowed otherwise.
+        #[builder(default, setter(strip_option))]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub winning_outcome_id: Option<String>,
+    }
+
+    /// Status to set when ending a prediction with [End Prediction](super::end_prediction)
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+    #[serde(rename_all = "UPPERCASE")]
+    #[non_exhaustive]
+    pub enum EndPredictionStatus {
+        /// A winning outcome has been chosen and the Channel Points have been distributed to the users who predicted the correct outcome.
+        Resolved,
+        /// The Prediction has been canceled and the Channel Points have been refunded to participants.
+        Canceled,
+        /// The Prediction has been locked and viewers can no longer make predictions.
+        Locked,
+    }
+
+    /// Return Values for [End Prediction](super::end_prediction)
+    ///
+    /// [`end-prediction`](https://dev.twitch.tv/docs/api/reference#end-prediction)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum EndPrediction {
+        /// 200 - Prediction ended successfully
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for EndPrediction {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::OK | http::StatusCode::NO_CONTENT => Ok(EndPrediction::Success),
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    impl helix::Request for EndPredictionRequest {
+        type Response = EndPrediction;
+
+        const METHOD: http::Method = http::Method::PATCH;
+        const PATH: &'static str = "predictions";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ChannelManagePredictions];
+    }
+
+    impl helix::RequestPatch for EndPredictionRequest {
+        type Body = EndPredictionBody;
+
+        fn validate_body(&self, body: &Self::Body) -> Result<(), helix::RequestValidationError> {
+            match (body.status, &body.winning_outcome_id) {
+                (EndPredictionStatus::Resolved, None) => {
+                    Err(helix::RequestValidationError::MissingWinningOutcome)
+                }
+                (EndPredictionStatus::Resolved, Some(_)) | (_, None) => Ok(()),
+                (_, Some(_)) => Err(helix::RequestValidationError::UnexpectedWinningOutcome),
+            }
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = EndPredictionRequest::new();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "id": "bc637af0-7766-4525-9308-4112f4cbf178",
+      "broadcaster_id": "141981764",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "title": "Will we win all the games?",
+      "winning_outcome_id": "73085848-a94d-4040-9d21-2cb7a89374b7",
+      "outcomes": [
+        {
+          "id": "73085848-a94d-4040-9d21-2cb7a89374b7",
+          "title": "yes",
+          "users": 0,
+          "channel_points": 0,
+          "top_predictors": null,
+          "color": "BLUE"
+        },
+        {
+          "id": "906b70ba-1f12-47ea-9e95-e5f93d20e9cc",
+          "title": "no",
+          "users": 0,
+          "channel_points": 0,
+          "top_predictors": null,
+          "color": "PINK"
+        }
+      ],
+      "prediction_window": 120,
+      "status": "RESOLVED",
+      "created_at": "2021-04-28T21:48:19.480371331Z",
+      "ended_at": "2021-04-28T21:54:24.026833954Z",
+      "locked_at": "2021-04-28T21:48:34.636685705Z"
+    }
+  ]
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/predictions?");
+
+        assert_eq!(
+            req.parse_response(&uri, http_response).unwrap(),
+            EndPrediction::Success
+        );
+    }
+
+    #[test]
+    fn test_winning_outcome_validation() {
+        use helix::*;
+        let req = EndPredictionRequest::new();
+
+        let body = EndPredictionBody::builder()
+            .broadcaster_id("141981764")
+            .id("bc637af0-7766-4525-9308-4112f4cbf178")
+            .status(EndPredictionStatus::Resolved)
+            .winning_outcome_id("73085848-a94d-4040-9d21-2cb7a89374b7".to_string())
+            .build();
+        assert_eq!(
+            req.body(&body).unwrap(),
+            r#"{"broadcaster_id":"141981764","id":"bc637af0-7766-4525-9308-4112f4cbf178","status":"RESOLVED","winning_outcome_id":"73085848-a94d-4040-9d21-2cb7a89374b7"}"#
+        );
+        assert!(req
+            .create_request(body, "token", &"clientid".into())
+            .is_ok());
+
+        let body = EndPredictionBody::builder()
+            .broadcaster_id("141981764")
+            .id("bc637af0-7766-4525-9308-4112f4cbf178")
+            .status(EndPredictionStatus::Resolved)
+            .build();
+        assert!(matches!(
+            req.create_request(body, "token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::MissingWinningOutcome
+            ))
+        ));
+
+        let body = EndPredictionBody::builder()
+            .broadcaster_id("141981764")
+            .id("bc637af0-7766-4525-9308-4112f4cbf178")
+            .status(EndPredictionStatus::Locked)
+            .winning_outcome_id("73085848-a94d-4040-9d21-2cb7a89374b7".to_string())
+            .build();
+        assert!(matches!(
+            req.create_request(body, "token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::UnexpectedWinningOutcome
+            ))
+        ));
+    }
+}
@@ -0,0 +1,313 @@
+//! Endpoints regarding raids
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, raids::StartRaidRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = StartRaidRequest::builder()
+//!     .from_broadcaster_id("1234")
+//!     .to_broadcaster_id("5678")
+//!     .build();
+//!
+//! println!("{:?}", &client.req_post(req, (), &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+#[doc(inline)]
+pub use cancel_raid::{CancelRaid, CancelRaidRequest};
+#[doc(inline)]
+pub use start_raid::{StartRaid, StartRaidError, StartRaidRequest};
+
+use crate::{helix, types};
+use serde::{Deserialize, Serialize};
+
+/// Raid another channel by sending the broadcaster’s viewers to the targeted channel.
+/// [`start-a-raid`](https://dev.twitch.tv/docs/api/reference#start-a-raid)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [StartRaidRequest]
+///
+/// To use this endpoint, construct a [`StartRaidRequest`] with the [`StartRaidRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::raids::start_raid;
+/// let request = start_raid::StartRaidRequest::builder()
+///     .from_broadcaster_id("1234")
+///     .to_broadcaster_id("5678")
+///     .build();
+/// ```
+///
+/// ## Response: [StartRaid]
+///
+/// This endpoint has no body, send the request with `()` as the body with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, raids::start_raid};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = start_raid::StartRaidRequest::builder()
+///     .from_broadcaster_id("1234")
+///     .to_broadcaster_id("5678")
+///     .build();
+/// let response: Vec<start_raid::StartRaid> = client.req_post(request, (), &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPost::parse_response())
+pub mod start_raid {
+    use super::*;
+
+    /// Query Parameters for [Start a raid](super::start_raid)
+    ///
+    /// [`start-a-raid`](https://dev.twitch.tv/docs/api/reference#start-a-raid)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct StartRaidRequest {
+        /// The ID of the broadcaster that’s sending the raiding party. Must match the User ID in the Bearer token.
+        #[builder(setter(into))]
+        pub from_broadcaster_id: types::UserId,
+        /// The ID of the broadcaster to raid.
+        #[builder(setter(into))]
+        pub to_broadcaster_id: types::UserId,
+    }
+
+    /// Return Values for [Start a raid](super::start_raid)
+    ///
+    /// [`start-a-raid`](https://dev.twitch.tv/docs/api/reference#start-a-raid)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct StartRaid {
+        /// The UTC date and time, in RFC3339 format, of when the raid was requested.
+        pub created_at: types::Timestamp,
+        /// A Boolean value that indicates whether the channel being raided contains mature content.
+        pub is_mature: bool,
+    }
+
+    /// Errors specific to [Start a raid](super::start_raid)
+    #[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum StartRaidError {
+        /// broadcaster is already raiding another channel
+        AlreadyRaiding,
+        /// too many raids were requested in a short time
+        RateLimited,
+    }
+
+    impl StartRaidError {
+        /// Interpret an error returned by [Start a raid](super::start_raid) as a [`StartRaidError`]
+        ///
+        /// Returns `None` if the error is not specific to this endpoint.
+        pub fn from_request_error(error: &helix::HelixRequestPostError) -> Option<StartRaidError> {
+            match error {
+                helix::HelixRequestPostError::Error { status, .. } => match *status {
+                    http::StatusCode::CONFLICT => Some(StartRaidError::AlreadyRaiding),
+                    http::StatusCode::TOO_MANY_REQUESTS => Some(StartRaidError::RateLimited),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+    }
+
+    impl helix::Request for StartRaidRequest {
+        type Response = Vec<StartRaid>;
+
+        const METHOD: http::Method = http::Method::POST;
+        const PATH: &'static str = "raids";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageRaids];
+    }
+
+    impl helix::RequestPost for StartRaidRequest {
+        type Body = ();
+
+        fn body(&self, _: &Self::Body) -> Result<String, serde_json::Error> { Ok(String::new()) }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = StartRaidRequest::builder()
+            .from_broadcaster_id("12345678")
+            .to_broadcaster_id("87654321")
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": [
+    {
+      "created_at": "2022-02-18T07:20:50.52Z",
+      "is_mature": false
+    }
+  ]
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/raids?from_broadcaster_id=12345678&to_broadcaster_id=87654321",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap().data;
+        assert_eq!(res.len(), 1);
+        assert!(!res[0].is_mature);
+    }
+
+    #[test]
+    fn test_errors() {
+        use helix::*;
+        let req = StartRaidRequest::builder()
+            .from_broadcaster_id("12345678")
+            .to_broadcaster_id("87654321")
+            .build();
+        let uri = req.get_uri().unwrap();
+
+        let data =
+            br#"{"error":"Conflict","status":409,"message":"The channel is already raiding."}"#
+                .to_vec();
+        let http_response = http::Response::builder().status(409).body(data).unwrap();
+        let err = req.clone().parse_response(&uri, http_response).unwrap_err();
+        assert_eq!(
+            StartRaidError::from_request_error(&err),
+            Some(StartRaidError::AlreadyRaiding)
+        );
+
+        let data = br#"{"error":"Too Many Requests","status":429,"message":"The request exceeded the number of raid requests that may be made."}"#
+            .to_vec();
+        let http_response = http::Response::builder().status(429).body(data).unwrap();
+        let err = req.parse_response(&uri, http_response).unwrap_err();
+        assert_eq!(
+            StartRaidError::from_request_error(&err),
+            Some(StartRaidError::RateLimited)
+        );
+    }
+}
+
+/// Cancel a pending raid.
+/// [`cancel-a-raid`](https://dev.twitch.tv/docs/api/reference#cancel-a-raid)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [CancelRaidRequest]
+///
+/// To use this endpoint, construct a [`CancelRaidRequest`] with the [`CancelRaidRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::raids::cancel_raid;
+/// let request = cancel_raid::CancelRaidRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// ```
+///
+/// ## Response: [CancelRaid]
+///
+/// Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, raids::cancel_raid};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = cancel_raid::CancelRaidRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let response: cancel_raid::CancelRaid = client.req_delete(request, &token).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestDelete::parse_response())
+pub mod cancel_raid {
+    use super::*;
+
+    /// Query Parameters for [Cancel a raid](super::cancel_raid)
+    ///
+    /// [`cancel-a-raid`](https://dev.twitch.tv/docs/api/reference#cancel-a-raid)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct CancelRaidRequest {
+        /// The ID of the broadcaster that initiated the raid. Must match the User ID in the Bearer token.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+    }
+
+    /// Return Values for [Cancel a raid](super::cancel_raid)
+    ///
+    /// [`cancel-a-raid`](https://dev.twitch.tv/docs/api/reference#cancel-a-raid)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum CancelRaid {
+        /// 204 - Successfully cancelled the raid
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for CancelRaid {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT => Ok(CancelRaid::Success),
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    impl helix::Request for CancelRaidRequest {
+        type Response = CancelRaid;
+
+        const METHOD: http::Method = http::Method::DELETE;
+        const PATH: &'static str = "raids";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageRaids];
+    }
+
+    impl helix::RequestDelete for CancelRaidRequest {}
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = CancelRaidRequest::builder()
+            .broadcaster_id("12345678")
+            .build();
+
+        // From twitch docs
+        let data = br#""#.to_vec();
+
+        let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/raids?broadcaster_id=12345678",
+        );
+
+        assert_eq!(
+            req.parse_response(&uri, http_response).unwrap(),
+            CancelRaid::Success
+        );
+    }
+}
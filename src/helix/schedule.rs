@@ -0,0 +1,408 @@
+//! Endpoints regarding stream schedules
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, schedule::GetChannelStreamScheduleRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetChannelStreamScheduleRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+#[doc(inline)]
+pub use get_channel_stream_schedule::{
+    GetChannelStreamScheduleRequest, ScheduledBroadcasts, Segment, SegmentCategory, Vacation,
+};
+#[doc(inline)]
+pub use update_channel_stream_schedule::{
+    UpdateChannelStreamSchedule, UpdateChannelStreamScheduleRequest,
+};
+
+use crate::{helix, types};
+use serde::{Deserialize, Serialize};
+
+/// Gets all scheduled broadcasts or specific scheduled broadcasts from a channel’s stream schedule.
+/// [`get-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#get-channel-stream-schedule)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [GetChannelStreamScheduleRequest]
+///
+/// To use this endpoint, construct a [`GetChannelStreamScheduleRequest`] with the [`GetChannelStreamScheduleRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::schedule::get_channel_stream_schedule;
+/// let request = get_channel_stream_schedule::GetChannelStreamScheduleRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// ```
+///
+/// ## Response: [ScheduledBroadcasts]
+///
+/// Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, schedule::get_channel_stream_schedule};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = get_channel_stream_schedule::GetChannelStreamScheduleRequest::builder()
+///     .broadcaster_id("1234")
+///     .build();
+/// let response: get_channel_stream_schedule::ScheduledBroadcasts =
+///     client.req_get(request, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestGet::parse_response())
+pub mod get_channel_stream_schedule {
+    use super::*;
+
+    /// Query Parameters for [Get Channel Stream Schedule](super::get_channel_stream_schedule)
+    ///
+    /// [`get-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#get-channel-stream-schedule)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct GetChannelStreamScheduleRequest {
+        /// User ID of the broadcaster who owns the channel streaming schedule.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// The ID of the stream segment to return. Maximum: 100.
+        #[builder(default)]
+        #[serde(default)]
+        pub id: Vec<String>,
+        /// A timestamp in RFC3339 format to start returning stream segments from. If not specified, the current date and time is used.
+        #[builder(default, setter(into, strip_option))]
+        pub start_time: Option<types::Timestamp>,
+        /// A timezone offset for the requester specified in minutes. For example, a timezone that is +4 hours from GMT would be `240`.
+        #[builder(default, setter(into, strip_option))]
+        pub utc_offset: Option<String>,
+        /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+        #[builder(default)]
+        pub after: Option<helix::Cursor>,
+        /// Maximum number of stream segments to return. Maximum: 25. Default: 20.
+        #[builder(default, setter(into))]
+        pub first: Option<usize>,
+    }
+
+    /// Return Values for [Get Channel Stream Schedule](super::get_channel_stream_schedule)
+    ///
+    /// [`get-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#get-channel-stream-schedule)
+    #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct ScheduledBroadcasts {
+        /// Scheduled broadcasts for this stream schedule. `None` if the channel has no scheduled broadcasts.
+        pub segments: Option<Vec<Segment>>,
+        /// User ID of the broadcaster.
+        pub broadcaster_id: types::UserId,
+        /// Display name of the broadcaster.
+        pub broadcaster_name: types::DisplayName,
+        /// Login of the broadcaster.
+        pub broadcaster_login: types::UserName,
+        /// If Vacation Mode is enabled, this includes start and end dates for the vacation.
+        pub vacation: Option<Vacation>,
+    }
+
+    /// A scheduled broadcast in [ScheduledBroadcasts]
+    #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct Segment {
+        /// The ID for the scheduled broadcast.
+        pub id: String,
+        /// Scheduled start time for the scheduled broadcast in RFC3339 format.
+        pub start_time: types::Timestamp,
+        /// Scheduled end time for the scheduled broadcast in RFC3339 format.
+        pub end_time: types::Timestamp,
+        /// Title for the scheduled broadcast.
+        pub title: String,
+        /// Used with recurring scheduled broadcasts. Specifies the date of the next recurring broadcast in RFC3339 format if one or more specific broadcasts have been deleted in the series. Set to `None` otherwise.
+        pub canceled_until: Option<types::Timestamp>,
+        /// The category for the scheduled broadcast. Set to `None` if no category has been specified.
+        pub category: Option<SegmentCategory>,
+        /// Indicates if the scheduled broadcast is recurring weekly.
+        pub is_recurring: bool,
+    }
+
+    /// Category of a [Segment]
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct SegmentCategory {
+        /// Game/category ID.
+        pub id: types::CategoryId,
+        /// Game/category name.
+        pub name: String,
+    }
+
+    /// Vacation of a channel, see [`ScheduledBroadcasts::vacation`]
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+    #[non_exhaustive]
+    pub struct Vacation {
+        /// Start time for vacation specified in RFC3339 format.
+        pub start_time: types::Timestamp,
+        /// End time for vacation specified in RFC3339 format.
+        pub end_time: types::Timestamp,
+    }
+
+    impl helix::Request for GetChannelStreamScheduleRequest {
+        type Response = ScheduledBroadcasts;
+
+        const MAX_FIRST: usize = 25;
+        const METHOD: http::Method = http::Method::GET;
+        const PATH: &'static str = "schedule";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    }
+
+    impl helix::RequestGet for GetChannelStreamScheduleRequest {}
+
+    impl helix::Paginated for GetChannelStreamScheduleRequest {
+        fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = GetChannelStreamScheduleRequest::builder()
+            .broadcaster_id("141981764")
+            .build();
+
+        // From twitch docs
+        let data = br#"
+{
+  "data": {
+    "segments": [
+      {
+        "id": "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
+        "start_time": "2021-07-01T18:00:00Z",
+        "end_time": "2021-07-01T19:00:00Z",
+        "title": "TwitchDev Monthly Update // July 1, 2021",
+        "canceled_until": null,
+        "category": {
+            "id": "509670",
+            "name": "Science & Technology"
+        },
+        "is_recurring": false
+      }
+    ],
+    "broadcaster_id": "141981764",
+    "broadcaster_name": "TwitchDev",
+    "broadcaster_login": "twitchdev",
+    "vacation": null
+  },
+  "pagination": {}
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/schedule?broadcaster_id=141981764",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap().data;
+        let segments = res.segments.unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].category.as_ref().unwrap().id, "509670");
+        assert!(!segments[0].is_recurring);
+        assert_eq!(res.vacation, None);
+    }
+
+    #[test]
+    fn test_request_vacation() {
+        use helix::*;
+        let req = GetChannelStreamScheduleRequest::builder()
+            .broadcaster_id("141981764")
+            .start_time("2021-07-01T00:00:00Z")
+            .first(Some(25))
+            .build();
+
+        let data = br#"
+{
+  "data": {
+    "segments": null,
+    "broadcaster_id": "141981764",
+    "broadcaster_name": "TwitchDev",
+    "broadcaster_login": "twitchdev",
+    "vacation": {
+      "start_time": "2021-06-28T00:00:00Z",
+      "end_time": "2021-07-12T00:00:00Z"
+    }
+  },
+  "pagination": {}
+}
+"#
+        .to_vec();
+
+        let http_response = http::Response::builder().body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/schedule?broadcaster_id=141981764&start_time=2021-07-01T00%3A00%3A00Z&first=25",
+        );
+
+        let res = req.parse_response(&uri, http_response).unwrap().data;
+        assert_eq!(res.segments, None);
+        assert_eq!(
+            res.vacation.unwrap().end_time,
+            types::Timestamp::new("2021-07-12T00:00:00Z")
+        );
+    }
+}
+
+/// Update the settings for a channel’s stream schedule. This can be used for setting vacation details.
+/// [`update-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [UpdateChannelStreamScheduleRequest]
+///
+/// To use this endpoint, construct an [`UpdateChannelStreamScheduleRequest`] with the [`UpdateChannelStreamScheduleRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::schedule::update_channel_stream_schedule;
+/// let request = update_channel_stream_schedule::UpdateChannelStreamScheduleRequest::builder()
+///     .broadcaster_id("1234")
+///     .is_vacation_enabled(true)
+///     .vacation_start_time("2021-05-16T00:00:00Z")
+///     .vacation_end_time("2021-05-23T00:00:00Z")
+///     .timezone("America/New_York")
+///     .build();
+/// ```
+///
+/// ## Response: [UpdateChannelStreamSchedule]
+///
+/// Send the request to receive the response with [`HelixClient::req_patch()`](helix::HelixClient::req_patch).
+/// All settings are query parameters, so the body is `()`.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, schedule::update_channel_stream_schedule};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = update_channel_stream_schedule::UpdateChannelStreamScheduleRequest::builder()
+///     .broadcaster_id("1234")
+///     .is_vacation_enabled(false)
+///     .build();
+/// let response: update_channel_stream_schedule::UpdateChannelStreamSchedule =
+///     client.req_patch(request, (), &token).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request((), &token, &client_id)`](helix::RequestPatch::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPatch::parse_response())
+pub mod update_channel_stream_schedule {
+    use super::*;
+
+    /// Query Parameters for [Update Channel Stream Schedule](super::update_channel_stream_schedule)
+    ///
+    /// [`update-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct UpdateChannelStreamScheduleRequest {
+        /// User ID of the broadcaster who owns the channel streaming schedule. Provided broadcaster_id must match the user_id in the user OAuth token.
+        #[builder(setter(into))]
+        pub broadcaster_id: types::UserId,
+        /// Indicates if Vacation Mode is enabled. Set to `true` to add a vacation or `false` to remove vacation from the channel streaming schedule.
+        #[builder(default, setter(into, strip_option))]
+        pub is_vacation_enabled: Option<bool>,
+        /// Start time for vacation specified in RFC3339 format. Required if `is_vacation_enabled` is set to `true`.
+        #[builder(default, setter(into, strip_option))]
+        pub vacation_start_time: Option<types::Timestamp>,
+        /// End time for vacation specified in RFC3339 format. Required if `is_vacation_enabled` is set to `true`.
+        #[builder(default, setter(into, strip_option))]
+        pub vacation_end_time: Option<types::Timestamp>,
+        /// The timezone for when the vacation is being scheduled using the IANA time zone database format. Required if `is_vacation_enabled` is set to `true`.
+        #[builder(default, setter(into, strip_option))]
+        pub timezone: Option<String>,
+    }
+
+    /// Return Values for [Update Channel Stream Schedule](super::update_channel_stream_schedule)
+    ///
+    /// [`update-channel-stream-schedule`](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum UpdateChannelStreamSchedule {
+        /// 204 - Stream schedule settings updated successfully
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for UpdateChannelStreamSchedule {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT | http::StatusCode::OK => {
+                    Ok(UpdateChannelStreamSchedule::Success)
+                }
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    impl helix::Request for UpdateChannelStreamScheduleRequest {
+        type Response = UpdateChannelStreamSchedule;
+
+        const METHOD: http::Method = http::Method::PATCH;
+        const PATH: &'static str = "schedule/settings";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] =
+            &[twitch_oauth2::Scope::ChannelManageSchedule];
+    }
+
+    impl helix::RequestPatch for UpdateChannelStreamScheduleRequest {
+        type Body = ();
+
+        fn body(&self, _: &Self::Body) -> Result<String, serde_json::Error> { Ok(String::new()) }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = UpdateChannelStreamScheduleRequest::builder()
+            .broadcaster_id("141981764")
+            .is_vacation_enabled(true)
+            .vacation_start_time("2021-05-16T00:00:00Z")
+            .vacation_end_time("2021-05-23T00:00:00Z")
+            .timezone("America/New_York")
+            .build();
+
+        let http_response = http::Response::builder().status(204).body(vec![]).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/schedule/settings?broadcaster_id=141981764&is_vacation_enabled=true&vacation_start_time=2021-05-16T00%3A00%3A00Z&vacation_end_time=2021-05-23T00%3A00%3A00Z&timezone=America%2FNew_York",
+        );
+
+        assert_eq!(
+            req.parse_response(&uri, http_response).unwrap(),
+            UpdateChannelStreamSchedule::Success
+        );
+    }
+}
@@ -7,7 +7,7 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = SearchCategoriesRequest::builder()
@@ -55,7 +55,7 @@ use serde::{Deserialize, Serialize};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = search_categories::SearchCategoriesRequest::builder()
 ///     .query("hello")
 ///     .build();
@@ -75,6 +75,7 @@ pub mod search_categories {
     /// [`search-categories`](https://dev.twitch.tv/docs/api/reference#search-categories)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct SearchCategoriesRequest {
         /// URL encoded search query
         #[builder(setter(into))]
@@ -95,6 +96,7 @@ pub mod search_categories {
     impl helix::Request for SearchCategoriesRequest {
         type Response = Vec<Category>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "search/categories";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -135,13 +137,12 @@ pub mod search_categories {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/search/categories?query=fort"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/search/categories?query=fort",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -172,7 +173,7 @@ pub mod search_categories {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = search_channels::SearchChannelsRequest::builder()
 ///     .query("hello")
 ///     .build();
@@ -191,6 +192,7 @@ pub mod search_channels {
     /// [`search-channels`](https://dev.twitch.tv/docs/api/reference#search-channels)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct SearchChannelsRequest {
         /// URL encoded search query
         #[builder(setter(into))]
@@ -210,7 +212,7 @@ pub mod search_channels {
     ///
     /// [`search-channels`](https://dev.twitch.tv/docs/api/reference#search-channels)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct Channel {
         /// ID of the game being played on the stream
@@ -237,6 +239,7 @@ pub mod search_channels {
     impl helix::Request for SearchChannelsRequest {
         type Response = Vec<Channel>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "search/categories";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -278,12 +281,12 @@ pub mod search_channels {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/search/categories?query=fort"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/search/categories?query=fort",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let res = dbg!(req.parse_response(&uri, http_response).unwrap());
+        assert_eq!(res.pagination.cursor(), None);
     }
 }
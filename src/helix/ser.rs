@@ -23,7 +23,7 @@ impl<'input, 'output> Serializer<'input, 'output> {
     }
 }
 
-#[derive(Debug, thiserror::Error, displaydoc::Display)]
+#[derive(Debug, Clone, thiserror::Error, displaydoc::Display)]
 /// Errors from the query serializer
 pub enum Error {
     /// {0}
@@ -7,11 +7,11 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetStreamsRequest::builder()
-//!     .user_login(vec!["justinfan1337".to_string()])
+//!     .user_login(vec!["justinfan1337".into()])
 //!     .build();
 //!
 //! // If this doesn't return a result, that would mean the stream is not live.
@@ -66,7 +66,7 @@ impl StreamType {
 /// ```rust, no_run
 /// use twitch_api2::helix::streams::get_streams;
 /// let request = get_streams::GetStreamsRequest::builder()
-///     .user_login(vec!["justintvfan".to_string()])
+///     .user_login(vec!["justintvfan".into()])
 ///     .build();
 /// ```
 ///
@@ -81,9 +81,9 @@ impl StreamType {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_streams::GetStreamsRequest::builder()
-///     .user_login(vec!["justintvfan".to_string()])
+///     .user_login(vec!["justintvfan".into()])
 ///     .build();
 /// let response: Vec<get_streams::Stream> = client.req_get(request, &token).await?.data;
 /// # Ok(())
@@ -100,6 +100,7 @@ pub mod get_streams {
     /// [`get-streams`](https://dev.twitch.tv/docs/api/reference#get-streams)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetStreamsRequest {
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
         #[builder(default)]
@@ -113,15 +114,18 @@ pub mod get_streams {
         pub first: Option<usize>,
         /// Returns streams broadcasting a specified game ID. You can specify up to 10 IDs.
         #[builder(default)]
+        #[serde(default)]
         pub game_id: Vec<types::CategoryId>,
         /// Stream language. You can specify up to 100 languages.
         #[builder(default)]
         pub language: Option<String>,
         /// Returns streams broadcast by one or more specified user IDs. You can specify up to 100 IDs.
         #[builder(default, setter(into))]
+        #[serde(default)]
         pub user_id: Vec<types::UserId>,
         /// Returns streams broadcast by one or more specified user login names. You can specify up to 100 names.
         #[builder(default)]
+        #[serde(default)]
         pub user_login: Vec<types::UserName>,
     }
 
@@ -129,7 +133,7 @@ pub mod get_streams {
     ///
     /// [`get-streams`](https://dev.twitch.tv/docs/api/reference#get-streams)
     #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct Stream {
         /// ID of the game being played on the stream.
@@ -152,7 +156,7 @@ pub mod get_streams {
         /// ID of the user who is streaming.
         pub user_id: types::UserId,
         /// Display name corresponding to user_id.
-        pub user_name: types::UserName,
+        pub user_name: types::DisplayName,
         /// Number of viewers watching the stream at the time of the query.
         pub viewer_count: usize,
     }
@@ -160,6 +164,7 @@ pub mod get_streams {
     impl helix::Request for GetStreamsRequest {
         type Response = Vec<Stream>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "streams";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -220,10 +225,9 @@ pub mod get_streams {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(uri.to_string(), "https://api.twitch.tv/helix/streams?");
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/streams?");
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -254,7 +258,7 @@ pub mod get_streams {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_stream_tags::GetStreamTagsRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -273,6 +277,7 @@ pub mod get_stream_tags {
     /// [`get-stream-tags`](https://dev.twitch.tv/docs/api/reference#get-stream-tags)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetStreamTagsRequest {
         // FIXME: twitch docs sucks
         /// ID of the stream whose tags are going to be fetched
@@ -288,6 +293,7 @@ pub mod get_stream_tags {
     impl helix::Request for GetStreamTagsRequest {
         type Response = Vec<Tag>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "streams/tags";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -340,12 +346,11 @@ pub mod get_stream_tags {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/streams/tags?broadcaster_id=198704263"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/streams/tags?broadcaster_id=198704263",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
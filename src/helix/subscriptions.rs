@@ -7,7 +7,7 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetBroadcasterSubscriptionsRequest::builder()
@@ -54,7 +54,7 @@ use serde::{Deserialize, Serialize};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_broadcaster_subscriptions::GetBroadcasterSubscriptionsRequest::builder()
 ///     .broadcaster_id("1234")
 ///     .build();
@@ -72,12 +72,14 @@ pub mod get_broadcaster_subscriptions {
     /// [`get-broadcaster-subscriptions`](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetBroadcasterSubscriptionsRequest {
         /// User ID of the broadcaster. Must match the User ID in the Bearer token.
         #[builder(setter(into))]
         pub broadcaster_id: types::UserId,
         /// Unique identifier of account to get subscription status of. Accepts up to 100 values.
         #[builder(default)]
+        #[serde(default)]
         pub user_id: Vec<types::UserId>,
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
         #[builder(default)]
@@ -88,7 +90,7 @@ pub mod get_broadcaster_subscriptions {
     ///
     /// [`get-broadcaster-subscriptions`](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions)
     #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct BroadcasterSubscription {
         /// User ID of the broadcaster.
@@ -110,6 +112,7 @@ pub mod get_broadcaster_subscriptions {
     impl helix::Request for GetBroadcasterSubscriptionsRequest {
         type Response = Vec<BroadcasterSubscription>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "subscriptions";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] =
@@ -152,12 +155,11 @@ pub mod get_broadcaster_subscriptions {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/subscriptions?broadcaster_id=123"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/subscriptions?broadcaster_id=123",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
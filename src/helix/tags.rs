@@ -7,7 +7,7 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetAllStreamTagsRequest::builder()
@@ -103,7 +103,7 @@ pub struct TwitchTag {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_all_stream_tags::GetAllStreamTagsRequest::builder()
 ///     .build();
 /// let response: Vec<get_all_stream_tags::Tag> = client.req_get(request, &token).await?.data;
@@ -121,6 +121,7 @@ pub mod get_all_stream_tags {
     /// [`get-all-stream-tags`](https://dev.twitch.tv/docs/api/reference#get-all-stream-tags)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetAllStreamTagsRequest {
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
         #[builder(default)]
@@ -130,6 +131,7 @@ pub mod get_all_stream_tags {
         pub first: Option<usize>,
         /// ID of a tag. Multiple IDs can be specified. If provided, only the specified tag(s) is(are) returned. Maximum of 100.
         #[builder(default)]
+        #[serde(default)]
         pub tag_id: Vec<types::TagId>,
     }
 
@@ -141,6 +143,7 @@ pub mod get_all_stream_tags {
     impl helix::Request for GetAllStreamTagsRequest {
         type Response = Vec<Tag>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "tags/streams";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -218,12 +221,8 @@ pub mod get_all_stream_tags {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/tags/streams?first=3"
-        );
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/tags/streams?first=3");
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
@@ -0,0 +1,189 @@
+//! Tolerant parsing of `data`, keeping every element that could be parsed
+//!
+//! See [`RequestGet::parse_response_tolerant`](super::RequestGet::parse_response_tolerant)
+
+use super::{Pagination, Request, Response};
+use serde::de::{self, DeserializeOwned, DeserializeSeed, Deserializer, MapAccess, SeqAccess};
+use std::fmt;
+
+/// Response where some elements of `data` could not be parsed, see [`RequestGet::parse_response_tolerant`](super::RequestGet::parse_response_tolerant)
+#[derive(Debug)]
+#[must_use]
+#[non_exhaustive]
+pub struct PartialResponse<R, T>
+where
+    R: Request<Response = Vec<T>>,
+    T: DeserializeOwned + PartialEq, {
+    /// Response with the elements of `data` that could be parsed
+    pub response: Response<R, Vec<T>>,
+    /// Elements of `data` that could not be parsed
+    pub errors: Vec<ItemError>,
+}
+
+impl<R, T> PartialResponse<R, T>
+where
+    R: Request<Response = Vec<T>>,
+    T: DeserializeOwned + PartialEq,
+{
+    /// Returns `true` if every element of `data` was parsed
+    pub fn is_complete(&self) -> bool { self.errors.is_empty() }
+}
+
+/// An element of `data` that could not be parsed
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ItemError {
+    /// Position of the element in `data`
+    pub index: usize,
+    /// The element, `None` if it was not valid JSON, e.g. because the body was cut off
+    pub value: Option<serde_json::Value>,
+    /// Why the element could not be parsed
+    pub error: serde_json::Error,
+}
+
+/// Parse `text`, collecting the elements of `data` and errors for the ones that fail
+///
+/// Returns `Err` only if nothing could be salvaged.
+pub(crate) fn parse<T: DeserializeOwned>(
+    text: &str,
+) -> Result<(Vec<T>, Pagination, Vec<ItemError>), serde_json::Error> {
+    let mut salvage = Salvage {
+        items: Vec::new(),
+        errors: Vec::new(),
+        pagination: Pagination::default(),
+        seen: 0,
+        found_data: false,
+    };
+    let mut deserializer = serde_json::Deserializer::from_str(text);
+    let result = (&mut salvage)
+        .deserialize(&mut deserializer)
+        .and_then(|_| deserializer.end());
+    match result {
+        Ok(()) if salvage.found_data => {}
+        Ok(()) => return Err(de::Error::missing_field("data")),
+        Err(e) if !salvage.found_data => return Err(e),
+        Err(e) => salvage.errors.push(ItemError {
+            index: salvage.seen,
+            value: None,
+            error: e,
+        }),
+    }
+    Ok((salvage.items, salvage.pagination, salvage.errors))
+}
+
+struct Salvage<T> {
+    items: Vec<T>,
+    errors: Vec<ItemError>,
+    pagination: Pagination,
+    /// Number of elements of `data` read so far
+    seen: usize,
+    found_data: bool,
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for &mut Salvage<T> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, T: DeserializeOwned> de::Visitor<'de> for &mut Salvage<T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a response with a `data` array")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "data" => {
+                    self.found_data = true;
+                    map.next_value_seed(Items(&mut *self))?
+                }
+                "pagination" => self.pagination = map.next_value()?,
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Items<'a, T>(&'a mut Salvage<T>);
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for Items<'_, T> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: DeserializeOwned> de::Visitor<'de> for Items<'_, T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("an array") }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(value) = seq.next_element::<serde_json::Value>()? {
+            let index = self.0.seen;
+            self.0.seen += 1;
+            match serde_json::from_value(value.clone()) {
+                Ok(item) => self.0.items.push(item),
+                Err(error) => self.0.errors.push(ItemError {
+                    index,
+                    value: Some(value),
+                    error,
+                }),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_salvage_items() {
+    #[derive(serde::Deserialize, PartialEq, Debug)]
+    struct Item {
+        id: u32,
+    }
+
+    let text = r#"{"data":[{"id":1},{"id":"two"},{"id":3}],"pagination":{"cursor":"abc"}}"#;
+    let (items, pagination, errors) = parse::<Item>(text).unwrap();
+    assert_eq!(items, vec![Item { id: 1 }, Item { id: 3 }]);
+    assert_eq!(pagination.cursor().map(super::Cursor::as_str), Some("abc"));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].index, 1);
+    assert_eq!(errors[0].value, Some(serde_json::json!({"id": "two"})));
+
+    assert!(parse::<Item>(r#"{"pagination":{}}"#).is_err());
+    assert!(parse::<Item>("not json").is_err());
+}
+
+#[test]
+fn test_truncated() {
+    #[derive(serde::Deserialize, PartialEq, Debug)]
+    struct Item {
+        id: u32,
+    }
+
+    // Cut the body off at every position, parsing must never panic and only ever keep a prefix of the items
+    let text = r#"{"data":[{"id":1},{"id":2},{"id":3}],"pagination":{"cursor":"abc"}}"#;
+    let all = vec![Item { id: 1 }, Item { id: 2 }, Item { id: 3 }];
+    for end in 0..=text.len() {
+        match parse::<Item>(&text[..end]) {
+            Ok((items, _, errors)) => {
+                assert_eq!(&all[..items.len()], &items[..], "cut at {}", end);
+                assert!(
+                    end == text.len() || !errors.is_empty(),
+                    "cut at {} gave no error",
+                    end
+                );
+            }
+            Err(_) => assert!(end < text.len()),
+        }
+    }
+}
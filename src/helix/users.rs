@@ -7,11 +7,11 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetUsersRequest::builder()
-//!     .login(vec!["justinfan1337".to_string()])
+//!     .login(vec!["justinfan1337".into()])
 //!     .build();
 //!
 //! println!("{:?}", &client.req_get(req, &token).await?.data);
@@ -44,7 +44,7 @@ use serde::{Deserialize, Serialize};
 /// use twitch_api2::helix::users::get_users;
 /// let request = get_users::GetUsersRequest::builder()
 ///     .id(vec!["1234".to_string()])
-///     .login(vec!["justintvfan".to_string()])
+///     .login(vec!["justintvfan".into()])
 ///     .build();
 /// ```
 ///
@@ -59,10 +59,10 @@ use serde::{Deserialize, Serialize};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_users::GetUsersRequest::builder()
 ///     .id(vec!["1234".to_string()])
-///     .login(vec!["justintvfan".to_string()])
+///     .login(vec!["justintvfan".into()])
 ///     .build();
 /// let response: Vec<get_users::User> = client.req_get(request, &token).await?.data;
 /// # Ok(())
@@ -78,12 +78,15 @@ pub mod get_users {
     /// [`get-users`](https://dev.twitch.tv/docs/api/reference#get-users)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetUsersRequest {
         /// User ID. Multiple user IDs can be specified. Limit: 100.
         #[builder(default)]
+        #[serde(default)]
         pub id: Vec<types::UserId>,
         /// User login name. Multiple login names can be specified. Limit: 100.
         #[builder(default)]
+        #[serde(default)]
         pub login: Vec<types::UserName>,
     }
 
@@ -91,7 +94,7 @@ pub mod get_users {
     ///
     /// [`get-users`](https://dev.twitch.tv/docs/api/reference#get-users)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct User {
         /// User’s broadcaster type: "partner", "affiliate", or "".
@@ -120,6 +123,7 @@ pub mod get_users {
     impl helix::Request for GetUsersRequest {
         type Response = Vec<User>;
 
+        const METHOD: http::Method = http::Method::GET;
         #[cfg(feature = "twitch_oauth2")]
         const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadEmail];
         const PATH: &'static str = "users";
@@ -157,13 +161,9 @@ pub mod get_users {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/users?id=44322889"
-        );
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/users?id=44322889");
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -192,7 +192,7 @@ pub mod get_users {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_users_follows::GetUsersFollowsRequest::builder()
 ///     .to_id("1234".to_string())
 ///     .build();
@@ -210,6 +210,7 @@ pub mod get_users_follows {
     /// [`get-users-follows`](https://dev.twitch.tv/docs/api/reference#get-users-follows)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetUsersFollowsRequest {
         /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
         #[builder(default)]
@@ -229,7 +230,7 @@ pub mod get_users_follows {
     ///
     /// [`get-users-follows`](https://dev.twitch.tv/docs/api/reference#get-users-follows)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct UsersFollow {
         ///Date and time when the from_id user followed the to_id user.
@@ -254,6 +255,7 @@ pub mod get_users_follows {
     impl helix::Request for GetUsersFollowsRequest {
         type Response = Vec<UsersFollow>;
 
+        const METHOD: http::Method = http::Method::GET;
         #[cfg(feature = "twitch_oauth2")]
         const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
         const PATH: &'static str = "users/follows";
@@ -304,13 +306,12 @@ pub mod get_users_follows {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/users/follows?to_id=23161357"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/users/follows?to_id=23161357",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -345,7 +346,7 @@ pub mod get_users_follows {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = delete_user_follows::DeleteUserFollowsRequest::builder()
 ///     .from_id("1234").to_id("4321")
 ///     .build();
@@ -363,12 +364,15 @@ pub mod delete_user_follows {
     /// [`delete-user-follows`](https://dev.twitch.tv/docs/api/reference#delete-user-follows)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct DeleteUserFollowsRequest {
         /// User ID of the follower
         #[builder(default, setter(into))]
+        #[serde(default)]
         pub from_id: types::UserId,
         /// Channel to be unfollowed by the user
         #[builder(default, setter(into))]
+        #[serde(default)]
         pub to_id: types::UserId,
     }
     /// Return Values for [[Delete Users Follows](super::delete_user_follows)
@@ -401,6 +405,7 @@ pub mod delete_user_follows {
     impl helix::Request for DeleteUserFollowsRequest {
         type Response = DeleteUserFollow;
 
+        const METHOD: http::Method = http::Method::DELETE;
         #[cfg(feature = "twitch_oauth2")]
         const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
         const PATH: &'static str = "users/follows";
@@ -424,13 +429,12 @@ pub mod delete_user_follows {
         let http_response = http::Response::builder().status(204).body(data).unwrap();
         // FIXME: I have not tested this in production
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/users/follows?from_id=41245071&to_id=41245072"
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/users/follows?from_id=41245071&to_id=41245072",
         );
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
 
@@ -472,7 +476,7 @@ pub mod delete_user_follows {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = create_user_follows::CreateUserFollowsRequest::builder()
 ///     .build();
 /// let body = create_user_follows::CreateUserFollowsBody::builder()
@@ -495,6 +499,7 @@ pub mod create_user_follows {
         PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default,
     )]
     #[non_exhaustive]
+    #[must_use]
     pub struct CreateUserFollowsRequest {}
 
     /// Body Parameters for [Create User Follows](super::create_user_follows)
@@ -546,6 +551,7 @@ pub mod create_user_follows {
     impl helix::Request for CreateUserFollowsRequest {
         type Response = CreateUserFollows;
 
+        const METHOD: http::Method = http::Method::POST;
         #[cfg(feature = "twitch_oauth2")]
         const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[];
         const PATH: &'static str = "users/follows";
@@ -567,28 +573,15 @@ pub mod create_user_follows {
         where
             Self: Sized,
         {
-            let text = std::str::from_utf8(&response.body())
-                .map_err(|e| helix::HelixRequestPostError::Utf8Error(response.body().clone(), e))?;
-            if let Ok(helix::HelixRequestError {
-                error,
-                status,
-                message,
-            }) = serde_json::from_str::<helix::HelixRequestError>(&text)
-            {
-                return Err(helix::HelixRequestPostError::Error {
-                    error,
-                    status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
-                    message,
-                    uri: uri.clone(),
-                    body: response.body().clone(),
-                });
-            }
-
-            let response = response.status().try_into().map_err(|_| {
+            #[cfg_attr(not(feature = "raw_json"), allow(unused_variables))]
+            let (text, request_id) = helix::post_response_text(uri, &response)?;
+            let status = response.status();
+            let response = status.try_into().map_err(|_| {
                 // This path should never be taken, but just to be sure we do this
                 helix::HelixRequestPostError::Error {
                     status: response.status(),
                     uri: uri.clone(),
+                    request_id: request_id.clone(),
                     body: response.body().clone(),
                     message: String::new(), // FIXME: None, but this branch should really never be hit
                     error: String::new(),
@@ -598,6 +591,10 @@ pub mod create_user_follows {
                 data: response, // FIXME: This should be a bit better...
                 pagination: <_>::default(),
                 request: self,
+                request_id,
+                status,
+                #[cfg(feature = "raw_json")]
+                raw: helix::raw_json(text),
             })
         }
     }
@@ -613,12 +610,8 @@ pub mod create_user_follows {
         let http_response = http::Response::builder().status(200).body(data).unwrap();
         // This is marked as 204 in twitch docs, but in reality it's 200
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/users/follows?"
-        );
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/users/follows?");
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
@@ -7,7 +7,7 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 //! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 //! let client = HelixClient::new();
 //! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetVideosRequest::builder()
@@ -91,7 +91,7 @@ pub enum VideoTypeFilter {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
 /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
 /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
-/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None).await?;
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
 /// let request = get_videos::GetVideosRequest::builder()
 ///     .user_id("1234".to_string())
 ///     .build();
@@ -111,9 +111,11 @@ pub mod get_videos {
     /// [`get-videos`](https://dev.twitch.tv/docs/api/reference#get-videos)
     #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
     #[non_exhaustive]
+    #[must_use]
     pub struct GetVideosRequest {
         /// ID of the video being queried. Limit: 100. If this is specified, you cannot use any of the optional query parameters below.
         #[builder(default)]
+        #[serde(default)]
         pub id: Vec<types::VideoId>,
         /// ID of the user who owns the video.
         #[builder(default, setter(into))]
@@ -150,7 +152,7 @@ pub mod get_videos {
     ///
     /// [`get-videos`](https://dev.twitch.tv/docs/api/reference#get-videos)
     #[derive(PartialEq, Deserialize, Debug, Clone)]
-    #[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
     #[non_exhaustive]
     pub struct Video {
         /// Date when the video was created.
@@ -187,6 +189,7 @@ pub mod get_videos {
     impl helix::Request for GetVideosRequest {
         type Response = Vec<Video>;
 
+        const METHOD: http::Method = http::Method::GET;
         const PATH: &'static str = "videos";
         #[cfg(feature = "twitch_oauth2")]
         const SCOPE: &'static [twitch_oauth2::Scope] = &[];
@@ -231,12 +234,8 @@ pub mod get_videos {
 
         let http_response = http::Response::builder().body(data).unwrap();
 
-        let uri = req.get_uri().unwrap();
-        assert_eq!(
-            uri.to_string(),
-            "https://api.twitch.tv/helix/videos?id=234482848"
-        );
+        let uri = helix::test::assert_uri(&req, "https://api.twitch.tv/helix/videos?id=234482848");
 
-        dbg!(req.parse_response(&uri, http_response).unwrap());
+        let _res = dbg!(req.parse_response(&uri, http_response).unwrap());
     }
 }
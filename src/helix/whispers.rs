@@ -0,0 +1,372 @@
+//! Endpoints regarding whispers
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, whispers::{SendWhisperBody, SendWhisperRequest}};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = SendWhisperRequest::builder()
+//!     .from_user_id("1234")
+//!     .to_user_id("5678")
+//!     .build();
+//! let body = SendWhisperBody::builder()
+//!     .message("You were timed out for spamming")
+//!     .build();
+//!
+//! println!("{:?}", &client.req_post(req, body, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+#[doc(inline)]
+pub use send_whisper::{SendWhisper, SendWhisperBody, SendWhisperError, SendWhisperRequest};
+
+use crate::{helix, types};
+use serde::{Deserialize, Serialize};
+
+/// Sends a whisper message to the specified user.
+/// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+///
+/// # Accessing the endpoint
+///
+/// ## Request: [SendWhisperRequest]
+///
+/// To use this endpoint, construct a [`SendWhisperRequest`] with the [`SendWhisperRequest::builder()`] method.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::whispers::send_whisper;
+/// let request = send_whisper::SendWhisperRequest::builder()
+///     .from_user_id("1234")
+///     .to_user_id("5678")
+///     .build();
+/// ```
+///
+/// ## Body: [SendWhisperBody]
+///
+/// We also need to provide a body to the request containing the message to send.
+///
+/// ```
+/// # use twitch_api2::helix::whispers::send_whisper;
+/// let body = send_whisper::SendWhisperBody::builder()
+///     .message("hello")
+///     .build();
+/// ```
+///
+/// ## Response: [SendWhisper]
+///
+/// Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, whispers::send_whisper};
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(&twitch_api2::DummyHttpClient, token, None, None).await?;
+/// let request = send_whisper::SendWhisperRequest::builder()
+///     .from_user_id("1234")
+///     .to_user_id("5678")
+///     .build();
+/// let body = send_whisper::SendWhisperBody::builder()
+///     .message("hello")
+///     .build();
+/// let response: send_whisper::SendWhisper = client.req_post(request, body, &token).await?.data;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+/// and parse the [`http::Response`] with [`request.parse_response(&request.get_uri()?)`](helix::RequestPost::parse_response())
+pub mod send_whisper {
+    use super::*;
+    use std::convert::TryInto;
+
+    /// Longest whisper Twitch accepts, in characters, when the recipient has whispered the sender before
+    pub const MAX_WHISPER_LENGTH: usize = 10_000;
+    /// Longest whisper Twitch accepts, in characters, when the recipient has not whispered the sender before
+    pub const MAX_NEW_CONVERSATION_WHISPER_LENGTH: usize = 500;
+
+    /// Query Parameters for [Send Whisper](super::send_whisper)
+    ///
+    /// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    #[must_use]
+    pub struct SendWhisperRequest {
+        /// The ID of the user sending the whisper. Must match the User ID in the Bearer token, and the user must have a verified phone number.
+        #[builder(setter(into))]
+        pub from_user_id: types::UserId,
+        /// The ID of the user to receive the whisper.
+        #[builder(setter(into))]
+        pub to_user_id: types::UserId,
+    }
+
+    /// Body Parameters for [Send Whisper](super::send_whisper)
+    ///
+    /// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+    #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct SendWhisperBody {
+        /// The whisper message to send.
+        ///
+        /// Limited to [`MAX_NEW_CONVERSATION_WHISPER_LENGTH`] characters if the recipient has not whispered the sender before, [`MAX_WHISPER_LENGTH`] otherwise.
+        /// Messages that are too long are truncated by Twitch.
+        #[builder(setter(into))]
+        pub message: String,
+    }
+
+    impl SendWhisperBody {
+        /// Check that the message is not longer than [`MAX_WHISPER_LENGTH`]
+        pub fn validate(&self) -> Result<(), helix::RequestValidationError> {
+            Self::check_length(&self.message, MAX_WHISPER_LENGTH)
+        }
+
+        /// Check that the message is not longer than [`MAX_NEW_CONVERSATION_WHISPER_LENGTH`], for recipients that have not whispered the sender before
+        pub fn validate_new_conversation(&self) -> Result<(), helix::RequestValidationError> {
+            Self::check_length(&self.message, MAX_NEW_CONVERSATION_WHISPER_LENGTH)
+        }
+
+        fn check_length(message: &str, max: usize) -> Result<(), helix::RequestValidationError> {
+            let length = message.chars().count();
+            if length > max {
+                Err(helix::RequestValidationError::MessageTooLong(length, max))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Return Values for [Send Whisper](super::send_whisper)
+    ///
+    /// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+    #[derive(PartialEq, Deserialize, Debug, Clone)]
+    #[non_exhaustive]
+    pub enum SendWhisper {
+        /// 204 - Successfully sent the whisper
+        Success,
+    }
+
+    impl std::convert::TryFrom<http::StatusCode> for SendWhisper {
+        type Error = std::borrow::Cow<'static, str>;
+
+        fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+            match s {
+                http::StatusCode::NO_CONTENT | http::StatusCode::OK => Ok(SendWhisper::Success),
+                other => Err(other.canonical_reason().unwrap_or("").into()),
+            }
+        }
+    }
+
+    /// Errors specific to [Send Whisper](super::send_whisper)
+    #[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum SendWhisperError {
+        /// sender is not allowed to send whispers, e.g. because they have no verified phone number
+        SenderNotAllowed,
+        /// recipient does not exist
+        RecipientNotFound,
+        /// sender has exceeded the number of whispers they may send
+        RateLimited,
+        /// recipient does not accept whispers from the sender
+        WhispersDisabled,
+    }
+
+    impl SendWhisperError {
+        /// Interpret an error returned by [Send Whisper](super::send_whisper) as a [`SendWhisperError`]
+        ///
+        /// Returns `None` if the error is not specific to this endpoint.
+        pub fn from_request_error(
+            error: &helix::HelixRequestPostError,
+        ) -> Option<SendWhisperError> {
+            match error {
+                helix::HelixRequestPostError::Error {
+                    status, message, ..
+                } => match *status {
+                    http::StatusCode::FORBIDDEN => Some(SendWhisperError::SenderNotAllowed),
+                    http::StatusCode::NOT_FOUND => Some(SendWhisperError::RecipientNotFound),
+                    http::StatusCode::TOO_MANY_REQUESTS => Some(SendWhisperError::RateLimited),
+                    http::StatusCode::BAD_REQUEST => {
+                        let message = message.to_lowercase();
+                        if message.contains("disabled") || message.contains("prevent") {
+                            Some(SendWhisperError::WhispersDisabled)
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+    }
+
+    impl helix::Request for SendWhisperRequest {
+        type Response = SendWhisper;
+
+        const METHOD: http::Method = http::Method::POST;
+        const PATH: &'static str = "whispers";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserManageWhispers];
+    }
+
+    impl helix::RequestPost for SendWhisperRequest {
+        type Body = SendWhisperBody;
+
+        fn validate_body(&self, body: &Self::Body) -> Result<(), helix::RequestValidationError> {
+            body.validate()
+        }
+
+        fn parse_response(
+            self,
+            uri: &http::Uri,
+            response: http::Response<Vec<u8>>,
+        ) -> Result<
+            helix::Response<Self, <Self as helix::Request>::Response>,
+            helix::HelixRequestPostError,
+        >
+        where
+            Self: Sized,
+        {
+            #[cfg_attr(not(feature = "raw_json"), allow(unused_variables))]
+            let (text, request_id) = helix::post_response_text(uri, &response)?;
+            let data = response.status().try_into().map_err(|_| {
+                // This path should never be taken, but just to be sure we do this
+                helix::HelixRequestPostError::Error {
+                    status: response.status(),
+                    uri: uri.clone(),
+                    request_id: request_id.clone(),
+                    body: response.body().clone(),
+                    message: String::new(),
+                    error: String::new(),
+                }
+            })?;
+            Ok(helix::Response {
+                data,
+                pagination: <_>::default(),
+                request: self,
+                request_id,
+                status: response.status(),
+                #[cfg(feature = "raw_json")]
+                raw: helix::raw_json(text),
+            })
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        use helix::*;
+        let req = SendWhisperRequest::builder()
+            .from_user_id("123")
+            .to_user_id("456")
+            .build();
+
+        let body = SendWhisperBody::builder().message("hello").build();
+        assert_eq!(req.body(&body).unwrap(), r#"{"message":"hello"}"#);
+
+        // From twitch docs
+        let data = br#""#.to_vec();
+
+        let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+        let uri = helix::test::assert_uri(
+            &req,
+            "https://api.twitch.tv/helix/whispers?from_user_id=123&to_user_id=456",
+        );
+
+        assert_eq!(
+            req.parse_response(&uri, http_response).unwrap().data,
+            SendWhisper::Success
+        );
+    }
+
+    #[test]
+    fn test_message_length() {
+        use helix::*;
+        let req = SendWhisperRequest::builder()
+            .from_user_id("123")
+            .to_user_id("456")
+            .build();
+
+        let body = SendWhisperBody::builder()
+            .message("a".repeat(MAX_WHISPER_LENGTH + 1))
+            .build();
+        assert!(matches!(
+            req.create_request(body, "token", &"clientid".into()),
+            Err(CreateRequestError::ValidationError(
+                RequestValidationError::MessageTooLong(10_001, 10_000)
+            ))
+        ));
+
+        let body = SendWhisperBody::builder()
+            .message("a".repeat(MAX_WHISPER_LENGTH))
+            .build();
+        assert!(req
+            .create_request(body, "token", &"clientid".into())
+            .is_ok());
+
+        // Counted in characters, not bytes
+        let body = SendWhisperBody::builder()
+            .message("ä".repeat(MAX_WHISPER_LENGTH))
+            .build();
+        assert!(body.validate().is_ok());
+
+        let body = SendWhisperBody::builder().message("a".repeat(501)).build();
+        assert!(body.validate().is_ok());
+        assert!(matches!(
+            body.validate_new_conversation(),
+            Err(RequestValidationError::MessageTooLong(501, 500))
+        ));
+    }
+
+    #[test]
+    fn test_errors() {
+        use helix::*;
+        let req = SendWhisperRequest::builder()
+            .from_user_id("123")
+            .to_user_id("456")
+            .build();
+        let uri = req.get_uri().unwrap();
+        let error = |status: u16, message: &str| {
+            let data = serde_json::json!({"error": "", "status": status, "message": message})
+                .to_string()
+                .into_bytes();
+            let http_response = http::Response::builder().status(status).body(data).unwrap();
+            let err = req.clone().parse_response(&uri, http_response).unwrap_err();
+            SendWhisperError::from_request_error(&err)
+        };
+
+        assert_eq!(
+            error(
+                403,
+                "The user in from_user_id must have a verified phone number."
+            ),
+            Some(SendWhisperError::SenderNotAllowed)
+        );
+        assert_eq!(
+            error(404, "The ID in to_user_id was not found."),
+            Some(SendWhisperError::RecipientNotFound)
+        );
+        assert_eq!(
+            error(
+                429,
+                "The sender has exceeded the number of whisper requests that they may make."
+            ),
+            Some(SendWhisperError::RateLimited)
+        );
+        assert_eq!(
+            error(
+                400,
+                "The recipient's settings prevent this sender from whispering them."
+            ),
+            Some(SendWhisperError::WhispersDisabled)
+        );
+        assert_eq!(error(400, "Missing required parameter \"message\""), None);
+        assert_eq!(error(403, ""), Some(SendWhisperError::SenderNotAllowed));
+    }
+}
@@ -19,22 +19,21 @@
 //!
 //! ```rust,no_run
 //! use twitch_api2::{TwitchClient, helix::channels::GetChannelInformationRequest};
-//! use twitch_oauth2::client::surf_http_client;
-//! use twitch_oauth2::{AppAccessToken, Scope, TwitchToken, tokens::errors::TokenError};
+//! use twitch_oauth2::{AppAccessToken, Scope, TwitchToken, tokens::errors::AppAccessTokenError};
 //!
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-//! # let surf_http_client = twitch_oauth2::dummy_http_client; // This is only here to fool doc tests
 //! # let client_id = twitch_oauth2::ClientId::new("validclientid".to_string());
 //! # let client_secret = twitch_oauth2::ClientSecret::new("validclientsecret".to_string());
+//! let client = TwitchClient::new();
+//! # let _: &TwitchClient<twitch_api2::DummyHttpClient> = &client;
+//! // The helix client can also be used to get tokens
 //! let token =
-//!     match AppAccessToken::get_app_access_token(surf_http_client, client_id, client_secret, Scope::all()).await {
+//!     match AppAccessToken::get_app_access_token(&client.helix, client_id, client_secret, Scope::all()).await {
 //!         Ok(t) => t,
-//!         Err(TokenError::Request(e)) => panic!("got error: {:?}", e),
-//!         Err(e) => panic!(e),
+//!         Err(AppAccessTokenError::Request(e)) => panic!("got error: {:?}", e),
+//!         Err(e) => panic!("{}", e),
 //!     };
-//! let client = TwitchClient::new();
-//! # let _: &TwitchClient<twitch_api2::DummyHttpClient> = &client;
 //! let req = GetChannelInformationRequest::builder()
 //!     .broadcaster_id("27620241")
 //!     .build();
@@ -56,15 +55,27 @@
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>tmi</code></span> | Enables deserializable structs for [TMI](tmi) |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>surf_client</code></span> | Enables surf for [`HttpClient`] |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>reqwest_client</code></span> | Enables reqwest for [`HttpClient`] |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>single_flight</code></span> | Enables [coalescing](helix::HelixClient::req_get_single_flight) of identical in-flight GET requests on [`HelixClient`] |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>chrono</code></span> | Enables [`chrono`](https://docs.rs/chrono) helpers on [`Timestamp`](types::Timestamp), like [`Timestamp::now`](types::Timestamp::now) |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>tokio</code></span> | Together with `chrono`, enables [`BannedUser::delay_until_expired`](helix::moderation::BannedUser::delay_until_expired) for waiting out a timeout with [`tokio`](https://docs.rs/tokio) |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>tolerant</code></span> | Enables [`RequestGet::parse_response_tolerant`](helix::RequestGet::parse_response_tolerant) for keeping the parts of a response that could be parsed |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>all</code></span> | Enables all above features. Including reqwest and surf. Do not use this in production, it's better if you specify exactly what you need |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>unsupported</code></span> | Enables undocumented or experimental endpoints or topics. Breakage may occur |
-//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>allow_unknown_fields</code></span> | Removes `#[serde(deny_unknown_fields)]` on all applicable structs/enums |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>deny_unknown_fields</code></span> | Adds `#[serde(deny_unknown_fields)]` on all applicable structs/enums, making new fields sent by twitch an error. Useful for finding fields missing in this crate |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>simd-json</code></span> | Parses helix responses with [`simd-json`](https://docs.rs/simd-json) instead of `serde_json`, faster for large pages like [Get Banned Events](helix::moderation::get_banned_events). Needs a CPU with SIMD support |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>tracing</code></span> | Logs a warning with [`tracing`](https://docs.rs/tracing) when Twitch says an endpoint is deprecated, see [`DeprecationWarning`](helix::DeprecationWarning) |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>metrics</code></span> | Counts the HTTP calls of [`HelixClient`] in `twitch_api2_requests_total` with [`metrics`](https://docs.rs/metrics), labeled by `path`, `method` and `status` |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>opentelemetry</code></span> | Wraps the HTTP calls of [`HelixClient`] in [OpenTelemetry](https://docs.rs/opentelemetry) client spans of the global tracer, with `http.method`, `http.url` without the query, and `http.status_code` |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>raw_json</code></span> | Keeps the body of helix responses as JSON in `Response::raw`, for fields not yet modeled by this crate |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>test-fixtures</code></span> | Compares serialized responses against the golden files in `tests/fixtures` when running tests, set `UPDATE_FIXTURES=1` to regenerate them |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>test-util</code></span> | Adds constructors like `Moderator::new` for response types, so tests of downstream crates can build them |
 //!
 
 #[doc(include = "../README.md")]
 #[cfg(all(doctest, feature = "all"))]
 pub struct ReadmeDoctests;
 
+#[macro_use]
 pub mod types;
 
 #[cfg(feature = "helix")]
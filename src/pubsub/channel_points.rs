@@ -23,7 +23,7 @@ impl pubsub::Topic for ChannelPointsChannelV1 {
 
 /// A redemption users can "buy" with channel points to trigger rewards
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Redemption {
     /// ID of channel where the redemption was triggered
@@ -66,7 +66,7 @@ pub enum RedemptionStatus {
 
 /// Reward data
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Reward {
     /// Color of background in rewards & challenges screen on client
@@ -116,7 +116,7 @@ pub struct Reward {
 
 /// Links to the same image of different sizes
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Image {
     /// URL to png of size 28x28
@@ -129,7 +129,7 @@ pub struct Image {
 
 /// Information about global cooldown
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct GlobalCooldown {
     /// Cooldown enabled
@@ -140,7 +140,7 @@ pub struct GlobalCooldown {
 
 /// Reward redemption max
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(untagged)]
 #[non_exhaustive]
 pub enum Max {
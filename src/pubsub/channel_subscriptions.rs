@@ -26,7 +26,7 @@ impl pubsub::Topic for ChannelSubscribeEventsV1 {
 /// Reply from [ChannelSubscribeEventsV1]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "context")]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub enum ChannelSubscribeEventsV1Reply {
     /// A subscription
@@ -156,7 +156,7 @@ pub struct Emote {
 
 /// Message sent with subscription
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct SubMessage {
     /// Emotes in subscription message
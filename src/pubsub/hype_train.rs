@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// A user redeems an reward using channel points.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(into = "String", try_from = "String")]
 pub struct HypeTrainEventsV1 {
     /// The channel_id to watch. Can be fetched with the [Get Users](crate::helix::users::get_users) endpoint
@@ -24,7 +24,7 @@ impl pubsub::Topic for HypeTrainEventsV1 {
 
 /// A user redeems an reward using channel points.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(into = "String", try_from = "String")]
 pub struct HypeTrainEventsV1Rewards {
     /// The channel_id to watch. Can be fetched with the [Get Users](crate::helix::users::get_users) endpoint
@@ -44,7 +44,7 @@ impl pubsub::Topic for HypeTrainEventsV1Rewards {
 
 /// Reply from [HypeTrainEventsV1] or [HypeTrainEventsV1Rewards]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(tag = "type", content = "data")]
 #[non_exhaustive]
 pub enum HypeTrainEventsV1Reply {
@@ -147,7 +147,7 @@ pub enum HypeTrainEventsV1Reply {
 
 /// Configuration of hype train
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Config {
     /// Hype train public callout emote ID
@@ -220,7 +220,7 @@ pub enum HypeTrainDifficulty {
 
 /// How many support events needed to start Hype Train
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Kickoff {
     /// Period in nanoseconds that events must occur
@@ -234,7 +234,7 @@ pub struct Kickoff {
 
 /// Conversion table of event to participation points
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ParticipationConversionRates {
     /// Bits
@@ -276,7 +276,7 @@ pub struct ParticipationConversionRates {
 
 /// Thresholds for notifications
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct NotificationThresholds {
     /// Bits
@@ -318,7 +318,7 @@ pub struct NotificationThresholds {
 
 /// Reward given to conductors
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct ConductorRewards {
     /// Reward to conductor of bits
@@ -331,7 +331,7 @@ pub struct ConductorRewards {
 
 /// Rewards
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BitsRewards {
     /// Rewards to bits conductor
@@ -344,7 +344,7 @@ pub struct BitsRewards {
 
 /// Rewards
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct SubsRewards {
     /// Rewards to subscriptions conductor
@@ -357,7 +357,7 @@ pub struct SubsRewards {
 
 /// Participations in hype train
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Participations {
     /// Bits
@@ -399,13 +399,13 @@ pub struct Participations {
 
 /// Unknown
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Conductors {}
 
 /// Progress of hype train
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct HypeTrainProgress {
     /// Participation points needed in this level
@@ -423,7 +423,7 @@ pub struct HypeTrainProgress {
 
 /// Description of a hype-train level
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Level {
     /// Participation points needed for this level
@@ -437,7 +437,7 @@ pub struct Level {
 
 /// A reward
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(tag = "type")]
 #[non_exhaustive]
 pub enum Reward {
@@ -36,7 +36,7 @@ impl pubsub::Topic for ChatModeratorActions {
 
 /// Reply from [ChatModeratorActions]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[serde(tag = "type", content = "data")]
 #[non_exhaustive]
 pub enum ChatModeratorActionsReply {
@@ -383,7 +383,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "allow_unknown_fields")]
+    #[cfg(not(feature = "deny_unknown_fields"))]
     fn allow_unknown() {
         let source = r#"
 {
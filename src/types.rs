@@ -1,11 +1,133 @@
 //! Twitch types
 //!
 
+/// Define a newtype around [`String`] that converts from and to strings and compares equal to them
+///
+/// The attributes, including the derives, are put on the struct as given.
+macro_rules! newtype_string {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident;) => {
+        $(#[$meta])*
+        $vis struct $name(String);
+
+        impl $name {
+            /// Create a new value from a string
+            pub fn new(s: impl Into<String>) -> $name { $name(s.into()) }
+
+            /// Get the value as a string slice
+            pub fn as_str(&self) -> &str { &self.0 }
+
+            /// Get the value as a [`String`]
+            pub fn into_string(self) -> String { self.0 }
+        }
+
+        impl From<String> for $name {
+            fn from(s: String) -> Self { $name(s) }
+        }
+
+        impl From<&str> for $name {
+            fn from(s: &str) -> Self { $name(s.to_string()) }
+        }
+
+        impl From<$name> for String {
+            fn from(s: $name) -> Self { s.0 }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str { &self.0 }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str { &self.0 }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { self.0.fmt(f) }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool { self.0 == other }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool { self.0 == *other }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool { &self.0 == other }
+        }
+    };
+}
+
 /// A user ID.
 pub type UserId = String;
 
-/// A username, also specified as login. Should not be capitalized.
-pub type UserName = Nickname;
+newtype_string! {
+    /// A username, also specified as login. Should not be capitalized.
+    ///
+    /// This is the name used in urls and IRC, and is not the same as a [`DisplayName`], which may differ in case or be localized.
+    ///
+    /// ```rust
+    /// # use twitch_api2::types::UserName;
+    /// let login = UserName::new("justintv");
+    /// assert_eq!(login, "justintv");
+    /// assert_eq!(login.as_str(), "justintv");
+    /// ```
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    #[serde(transparent)]
+    pub struct UserName;
+}
+
+newtype_string! {
+    /// The client id of an application, sent as the `Client-Id` header of helix requests
+    ///
+    /// Not interchangeable with other string ids, so it can't be passed in place of e.g. a [`UserId`] by mistake.
+    ///
+    /// ```rust
+    /// # use twitch_api2::types::ClientId;
+    /// let client_id = ClientId::from("clientid");
+    /// assert_eq!(client_id, "clientid");
+    /// assert_eq!(client_id.to_string(), "clientid");
+    /// ```
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    #[serde(transparent)]
+    pub struct ClientId;
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl From<twitch_oauth2::ClientId> for ClientId {
+    fn from(client_id: twitch_oauth2::ClientId) -> Self { ClientId(client_id.as_str().to_string()) }
+}
+
+#[cfg(feature = "twitch_oauth2")]
+impl From<&twitch_oauth2::ClientId> for ClientId {
+    fn from(client_id: &twitch_oauth2::ClientId) -> Self {
+        ClientId(client_id.as_str().to_string())
+    }
+}
 
 /// A users display name
 pub type DisplayName = String;
@@ -13,8 +135,323 @@ pub type DisplayName = String;
 /// A nickname, not capitalized.
 pub type Nickname = String;
 
-/// RFC3339 timestamp
-pub type Timestamp = String;
+newtype_string! {
+    /// A developer-generated message id, used to match messages sent to [Check AutoMod Status](crate::helix::moderation::check_automod_status) to their results.
+    ///
+    /// ```rust
+    /// # use twitch_api2::types::MsgId;
+    /// let msg_id = MsgId::new("123");
+    /// assert_eq!(msg_id, "123");
+    /// assert_eq!(msg_id.as_str(), "123");
+    /// ```
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    #[serde(transparent)]
+    pub struct MsgId;
+}
+
+newtype_string! {
+    /// RFC3339 timestamp
+    ///
+    /// Timestamps are ordered chronologically, regardless of their offset and fractional seconds.
+    /// Timestamps that are not valid RFC3339 are ordered after all valid ones.
+    /// Equality compares the strings, so the same instant written differently is ordered by the strings.
+    ///
+    /// ```rust
+    /// # use twitch_api2::types::Timestamp;
+    /// let earlier = Timestamp::new("2021-01-01T00:00:00Z");
+    /// let later = Timestamp::new("2021-01-02T00:00:00Z");
+    /// assert!(earlier < later);
+    /// assert_eq!(earlier, "2021-01-01T00:00:00Z");
+    /// assert!(Timestamp::new("2021-01-01T00:00:00.5Z") > Timestamp::new("2021-01-01T00:00:00Z"));
+    /// assert!(Timestamp::new("2021-01-01T00:30:00Z") > Timestamp::new("2021-01-01T01:00:00+01:00"));
+    /// ```
+    #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    pub struct Timestamp;
+}
+
+impl Timestamp {
+    /// The current time in UTC, with second precision, like Twitch formats timestamps
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(nightly, doc(cfg(feature = "chrono")))]
+    pub fn now() -> Timestamp {
+        Timestamp(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+    }
+
+    /// Parse the timestamp with [`chrono`]
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(nightly, doc(cfg(feature = "chrono")))]
+    pub fn to_chrono(&self) -> Result<chrono::DateTime<chrono::FixedOffset>, chrono::ParseError> {
+        chrono::DateTime::parse_from_rfc3339(&self.0)
+    }
+
+    /// Compare two timestamps chronologically, regardless of their format and offset
+    ///
+    /// Returns `None` if either timestamp is not valid RFC3339.
+    ///
+    /// ```rust
+    /// # use twitch_api2::types::Timestamp;
+    /// let a = Timestamp::new("2021-01-01T00:00:00.5Z");
+    /// let b = Timestamp::new("2021-01-01T01:00:00+01:00");
+    /// assert_eq!(a.cmp_chrono(&b), Some(std::cmp::Ordering::Greater));
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(nightly, doc(cfg(feature = "chrono")))]
+    pub fn cmp_chrono(&self, other: &Timestamp) -> Option<std::cmp::Ordering> {
+        Some(self.to_chrono().ok()?.cmp(&other.to_chrono().ok()?))
+    }
+
+    /// Compare the instants of two timestamps, `None` if either timestamp is not valid RFC3339
+    ///
+    /// Unlike [`Ord`], the same instant written differently is [`Equal`](std::cmp::Ordering::Equal).
+    pub(crate) fn cmp_instant(&self, other: &Timestamp) -> Option<std::cmp::Ordering> {
+        Some(self.to_unix()?.cmp(&other.to_unix()?))
+    }
+
+    /// Seconds and nanoseconds since the unix epoch, `None` if the timestamp is not valid RFC3339
+    pub(crate) fn to_unix(&self) -> Option<(i64, u32)> {
+        let s = self.0.as_bytes();
+        let num = |range: std::ops::Range<usize>| -> Option<i64> {
+            let digits = s.get(range)?;
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return None;
+            }
+            std::str::from_utf8(digits).ok()?.parse().ok()
+        };
+        let sep = |i: usize, seps: &[u8]| matches!(s.get(i), Some(c) if seps.contains(c));
+        if !(sep(4, b"-") && sep(7, b"-") && sep(10, b"Tt ") && sep(13, b":") && sep(16, b":")) {
+            return None;
+        }
+        let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+        let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+        let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            2 if leap_year => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        if !(1..=12).contains(&month)
+            || !(1..=days_in_month).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 60
+        {
+            return None;
+        }
+
+        let mut i = 19;
+        let mut nanos = 0;
+        if sep(i, b".") {
+            let start = i + 1;
+            i = start;
+            while matches!(s.get(i), Some(c) if c.is_ascii_digit()) {
+                i += 1;
+            }
+            if i == start {
+                return None;
+            }
+            for (n, digit) in s[start..i].iter().take(9).enumerate() {
+                nanos += u32::from(digit - b'0') * 10u32.pow(8 - n as u32);
+            }
+        }
+        let offset = match s.get(i..)? {
+            b"Z" | b"z" => 0,
+            [sign @ b'+', ..] | [sign @ b'-', ..] if s.len() == i + 6 && sep(i + 3, b":") => {
+                let (hours, minutes) = (num(i + 1..i + 3)?, num(i + 4..i + 6)?);
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                let offset = hours * 3600 + minutes * 60;
+                if *sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            _ => return None,
+        };
+
+        // Days since the unix epoch of a date in the proleptic Gregorian calendar
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146_097 + doe - 719_468;
+
+        Some((
+            days * 86400 + hour * 3600 + minute * 60 + second - offset,
+            nanos,
+        ))
+    }
+
+    /// The timestamp `secs` seconds after the unix epoch, in UTC with second precision
+    pub(crate) fn from_unix(secs: i64) -> Timestamp {
+        let (days, secs) = (secs.div_euclid(86400), secs.rem_euclid(86400));
+        // Inverse of the calculation in `to_unix`
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        Timestamp(format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year,
+            month,
+            day,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        ))
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+
+        match (self.to_unix(), other.to_unix()) {
+            (Some(this), Some(other)) => this.cmp(&other),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+        .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+#[test]
+fn test_to_unix() {
+    let unix = |timestamp: &str| Timestamp::new(timestamp).to_unix();
+    assert_eq!(unix("2021-01-01T00:00:00Z"), Some((1_609_459_200, 0)));
+    assert_eq!(
+        unix("2021-01-01t00:00:00.25z"),
+        Some((1_609_459_200, 250_000_000))
+    );
+    assert_eq!(unix("1969-12-31T23:59:59Z"), Some((-1, 0)));
+
+    // Offsets are subtracted to get UTC
+    assert_eq!(unix("2021-01-01T05:30:00+05:30"), Some((1_609_459_200, 0)));
+    assert_eq!(unix("2020-12-31T16:00:00-08:00"), Some((1_609_459_200, 0)));
+    assert_eq!(
+        unix("2021-01-01T00:00:00+23:59"),
+        Some((1_609_459_200 - 86_340, 0))
+    );
+    for offset in &["+24:00", "+05:60", "+0530", "+05:3", "05:30", "+05:30Z", ""] {
+        assert_eq!(
+            unix(&format!("2021-01-01T00:00:00{}", offset)),
+            None,
+            "{}",
+            offset
+        );
+    }
+
+    // Days are checked per month, February has 29 days in leap years only
+    assert!(unix("2020-02-29T00:00:00Z").is_some());
+    assert!(unix("2000-02-29T00:00:00Z").is_some());
+    assert!(unix("2021-01-31T00:00:00Z").is_some());
+    assert!(unix("2021-12-31T23:59:60Z").is_some());
+    for date in &[
+        "2021-02-29",
+        "1900-02-29",
+        "2020-02-30",
+        "2021-04-31",
+        "2021-06-31",
+        "2021-09-31",
+        "2021-11-31",
+        "2021-01-32",
+        "2021-01-00",
+        "2021-00-01",
+        "2021-13-01",
+    ] {
+        assert_eq!(unix(&format!("{}T00:00:00Z", date)), None, "{}", date);
+    }
+    assert_eq!(unix("2021-01-01T24:00:00Z"), None);
+    assert_eq!(unix("2021-01-01T00:60:00Z"), None);
+    assert_eq!(unix("2021-01-01T00:00:00.Z"), None);
+
+    // Invalid timestamps are ordered after valid ones
+    assert!(Timestamp::new("2021-02-29T00:00:00Z") > Timestamp::new("2021-03-01T00:00:00Z"));
+}
+
+/// Deserialize an optional [`Timestamp`], treating `null` and `""` as `None`
+///
+/// Twitch uses both for timestamps that are not set, e.g. the `expires_at` of a permanent ban.
+/// Use together with `#[serde(default)]` to also accept a missing field.
+///
+/// ```rust
+/// # use twitch_api2::types::{self, Timestamp};
+/// #[derive(serde::Deserialize)]
+/// struct Ban {
+///     #[serde(default, deserialize_with = "types::deserialize_optional_timestamp")]
+///     expires_at: Option<Timestamp>,
+/// }
+/// let parse = |json| serde_json::from_str::<Ban>(json).unwrap().expires_at;
+/// assert_eq!(parse(r#"{"expires_at":"2021-01-01T00:00:00Z"}"#), Some("2021-01-01T00:00:00Z".into()));
+/// assert_eq!(parse(r#"{"expires_at":""}"#), None);
+/// assert_eq!(parse(r#"{"expires_at":null}"#), None);
+/// assert_eq!(parse(r#"{}"#), None);
+/// ```
+pub fn deserialize_optional_timestamp<'de, D>(
+    deserializer: D,
+) -> Result<Option<Timestamp>, D::Error>
+where D: serde::Deserializer<'de> {
+    let timestamp = <Option<String> as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(timestamp
+        .filter(|timestamp| !timestamp.is_empty())
+        .map(Timestamp))
+}
+
+/// Deserialize a list that may also be sent as a single object, e.g. `data` of an endpoint that changed shape between versions
+///
+/// ```rust
+/// # use twitch_api2::types;
+/// #[derive(serde::Deserialize)]
+/// struct Response {
+///     #[serde(deserialize_with = "types::deserialize_vec_or_single")]
+///     data: Vec<u32>,
+/// }
+/// let parse = |json| serde_json::from_str::<Response>(json).unwrap().data;
+/// assert_eq!(parse(r#"{"data":[1,2]}"#), vec![1, 2]);
+/// assert_eq!(parse(r#"{"data":1}"#), vec![1]);
+/// ```
+pub fn deserialize_vec_or_single<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>, {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum VecOrSingle<T> {
+        Vec(Vec<T>),
+        Single(T),
+    }
+
+    let data = <VecOrSingle<T> as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(match data {
+        VecOrSingle::Vec(vec) => vec,
+        VecOrSingle::Single(single) => vec![single],
+    })
+}
 
 /// A game or category ID
 pub type CategoryId = String;
@@ -27,7 +464,7 @@ pub type VideoId = String;
 
 /// A game or category as defined by Twitch
 #[derive(PartialEq, serde::Deserialize, Debug, Clone)]
-#[cfg_attr(not(feature = "allow_unknown_fields"), serde(deny_unknown_fields))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct TwitchCategory {
     ///Template URL for the game’s box art.
@@ -218,6 +655,304 @@ pub enum CommercialLengthParseError {
     InvalidLength(u64),
 }
 
+/// A color for a users name in chat.
+///
+/// Named colors are available to all users, [`ChatColor::Hex`] is only available to Turbo and Prime users.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChatColor {
+    /// `blue`
+    Blue,
+    /// `blue_violet`
+    BlueViolet,
+    /// `cadet_blue`
+    CadetBlue,
+    /// `chocolate`
+    Chocolate,
+    /// `coral`
+    Coral,
+    /// `dodger_blue`
+    DodgerBlue,
+    /// `firebrick`
+    Firebrick,
+    /// `golden_rod`
+    GoldenRod,
+    /// `green`
+    Green,
+    /// `hot_pink`
+    HotPink,
+    /// `orange_red`
+    OrangeRed,
+    /// `red`
+    Red,
+    /// `sea_green`
+    SeaGreen,
+    /// `spring_green`
+    SpringGreen,
+    /// `yellow_green`
+    YellowGreen,
+    /// A hex color, e.g. `#9146FF`. Construct this with [`ChatColor::hex`] to validate it.
+    Hex(String),
+}
+
+impl ChatColor {
+    /// Create a [`ChatColor::Hex`], validating that it is in the form `#RRGGBB`
+    pub fn hex(color: impl Into<String>) -> Result<ChatColor, ChatColorParseError> {
+        let color = color.into();
+        if Self::is_valid_hex(&color) {
+            Ok(ChatColor::Hex(color))
+        } else {
+            Err(ChatColorParseError::InvalidColor(color))
+        }
+    }
+
+    /// Check that this color will be accepted by Twitch
+    pub fn validate(&self) -> Result<(), ChatColorParseError> {
+        match self {
+            ChatColor::Hex(color) if !Self::is_valid_hex(color) => {
+                Err(ChatColorParseError::InvalidColor(color.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn is_valid_hex(color: &str) -> bool {
+        color.len() == 7
+            && color.starts_with('#')
+            && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+    }
+
+    /// Get the color as Twitch represents it
+    pub fn as_str(&self) -> &str {
+        match self {
+            ChatColor::Blue => "blue",
+            ChatColor::BlueViolet => "blue_violet",
+            ChatColor::CadetBlue => "cadet_blue",
+            ChatColor::Chocolate => "chocolate",
+            ChatColor::Coral => "coral",
+            ChatColor::DodgerBlue => "dodger_blue",
+            ChatColor::Firebrick => "firebrick",
+            ChatColor::GoldenRod => "golden_rod",
+            ChatColor::Green => "green",
+            ChatColor::HotPink => "hot_pink",
+            ChatColor::OrangeRed => "orange_red",
+            ChatColor::Red => "red",
+            ChatColor::SeaGreen => "sea_green",
+            ChatColor::SpringGreen => "spring_green",
+            ChatColor::YellowGreen => "yellow_green",
+            ChatColor::Hex(color) => color,
+        }
+    }
+}
+
+impl std::str::FromStr for ChatColor {
+    type Err = ChatColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "blue" => ChatColor::Blue,
+            "blue_violet" => ChatColor::BlueViolet,
+            "cadet_blue" => ChatColor::CadetBlue,
+            "chocolate" => ChatColor::Chocolate,
+            "coral" => ChatColor::Coral,
+            "dodger_blue" => ChatColor::DodgerBlue,
+            "firebrick" => ChatColor::Firebrick,
+            "golden_rod" => ChatColor::GoldenRod,
+            "green" => ChatColor::Green,
+            "hot_pink" => ChatColor::HotPink,
+            "orange_red" => ChatColor::OrangeRed,
+            "red" => ChatColor::Red,
+            "sea_green" => ChatColor::SeaGreen,
+            "spring_green" => ChatColor::SpringGreen,
+            "yellow_green" => ChatColor::YellowGreen,
+            other => return ChatColor::hex(other),
+        })
+    }
+}
+
+impl std::fmt::Display for ChatColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl serde::Serialize for ChatColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ChatColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Error for the `FromStr` on [`ChatColor`]
+#[derive(thiserror::Error, Debug, Clone, displaydoc::Display)]
+pub enum ChatColorParseError {
+    /// invalid color {0:?}, expected a named color or a hex color like `#9146FF`
+    InvalidColor(String),
+}
+
+/// The color of a chat announcement.
+///
+/// [`AnnouncementColor::Primary`] uses the channel's accent color, and is the default.
+/// Colors Twitch adds later are parsed as [`AnnouncementColor::Unknown`].
+///
+/// ```rust
+/// # use twitch_api2::types::AnnouncementColor;
+/// assert_eq!(AnnouncementColor::default(), AnnouncementColor::Primary);
+/// assert_eq!(serde_json::to_string(&AnnouncementColor::Purple).unwrap(), r#""purple""#);
+/// let color: AnnouncementColor = serde_json::from_str(r#""gold""#).unwrap();
+/// assert_eq!(color, AnnouncementColor::Unknown("gold".to_string()));
+/// assert!(color.validate().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnnouncementColor {
+    /// `blue`
+    Blue,
+    /// `green`
+    Green,
+    /// `orange`
+    Orange,
+    /// `purple`
+    Purple,
+    /// `primary`, the channel's accent color
+    Primary,
+    /// A color not known to this crate
+    Unknown(String),
+}
+
+impl AnnouncementColor {
+    /// Check that this color will be accepted by Twitch, i.e. that it is not [`AnnouncementColor::Unknown`]
+    pub fn validate(&self) -> Result<(), AnnouncementColorError> {
+        match self {
+            AnnouncementColor::Unknown(color) => {
+                Err(AnnouncementColorError::UnknownColor(color.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the color as Twitch represents it
+    pub fn as_str(&self) -> &str {
+        match self {
+            AnnouncementColor::Blue => "blue",
+            AnnouncementColor::Green => "green",
+            AnnouncementColor::Orange => "orange",
+            AnnouncementColor::Purple => "purple",
+            AnnouncementColor::Primary => "primary",
+            AnnouncementColor::Unknown(color) => color,
+        }
+    }
+}
+
+impl Default for AnnouncementColor {
+    fn default() -> Self { AnnouncementColor::Primary }
+}
+
+impl From<String> for AnnouncementColor {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "blue" => AnnouncementColor::Blue,
+            "green" => AnnouncementColor::Green,
+            "orange" => AnnouncementColor::Orange,
+            "purple" => AnnouncementColor::Purple,
+            "primary" => AnnouncementColor::Primary,
+            _ => AnnouncementColor::Unknown(s),
+        }
+    }
+}
+
+impl std::str::FromStr for AnnouncementColor {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> { Ok(s.to_string().into()) }
+}
+
+impl std::fmt::Display for AnnouncementColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl serde::Serialize for AnnouncementColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AnnouncementColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
+/// Error for [`AnnouncementColor::validate`]
+#[derive(thiserror::Error, Debug, Clone, displaydoc::Display)]
+pub enum AnnouncementColorError {
+    /// unknown announcement color {0:?}, expected one of `blue`, `green`, `orange`, `purple` or `primary`
+    UnknownColor(String),
+}
+
+/// An AutoMod level for a category, from `0` (no filtering) to `4` (most aggressive filtering).
+///
+/// ```rust
+/// # use twitch_api2::types::AutoModLevel;
+/// assert_eq!(AutoModLevel::new(2).unwrap().get(), 2);
+/// assert!(AutoModLevel::new(5).is_err());
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(try_from = "u8", into = "u8")]
+pub struct AutoModLevel(u8);
+
+impl AutoModLevel {
+    /// The highest valid level
+    pub const MAX: u8 = 4;
+
+    /// Create a new level, validating that it is in the range `0..=4`
+    pub fn new(level: u8) -> Result<AutoModLevel, AutoModLevelError> {
+        if level <= Self::MAX {
+            Ok(AutoModLevel(level))
+        } else {
+            Err(AutoModLevelError::OutOfRange(level))
+        }
+    }
+
+    /// Get the level as a number
+    pub fn get(self) -> u8 { self.0 }
+}
+
+impl std::convert::TryFrom<u8> for AutoModLevel {
+    type Error = AutoModLevelError;
+
+    fn try_from(level: u8) -> Result<Self, Self::Error> { AutoModLevel::new(level) }
+}
+
+impl From<AutoModLevel> for u8 {
+    fn from(level: AutoModLevel) -> Self { level.0 }
+}
+
+impl std::fmt::Display for AutoModLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { self.0.fmt(f) }
+}
+
+/// Error for the `TryFrom` on [`AutoModLevel`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+pub enum AutoModLevelError {
+    /// automod level {0} is out of range, must be between 0 and 4
+    OutOfRange(u8),
+}
+
 /// A user according to many endpoints
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct User {
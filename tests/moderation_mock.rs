@@ -0,0 +1,106 @@
+//! Round trip of the moderation endpoints against the [Twitch CLI](https://github.com/twitchdev/twitch-cli) mock server
+//!
+//! Start the mock server with `twitch mock-api start`, get a user token for one of its users with
+//! `twitch token -u --mock`, then run
+//!
+//! ```sh
+//! TWITCH_CLIENT_ID=<client id> TWITCH_TOKEN=<token> TWITCH_BROADCASTER_ID=<user id> \
+//!     cargo test --features integration --test moderation_mock -- --ignored
+//! ```
+//!
+//! `TWITCH_API_BASE` defaults to `http://localhost:8080/mock`.
+
+use futures::TryStreamExt;
+use twitch_api2::helix::{moderation, HelixClient};
+
+const MOCK_API_BASE: &str = "http://localhost:8080/mock";
+
+fn var(name: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| panic!("Please set env: {}", name))
+}
+
+fn client<'a>() -> HelixClient<'a, reqwest::Client> {
+    let client = HelixClient::from_env();
+    if std::env::var_os("TWITCH_API_BASE").is_none() {
+        client.with_base_url(MOCK_API_BASE)
+    } else {
+        client
+    }
+}
+
+fn token() -> twitch_oauth2::UserToken {
+    twitch_oauth2::UserToken::from_existing_unchecked(
+        twitch_oauth2::AccessToken::new(var("TWITCH_TOKEN")),
+        None,
+        twitch_oauth2::ClientId::new(var("TWITCH_CLIENT_ID")),
+        None,
+        // the login and user id of the token are not used by these tests
+        String::new().into(),
+        String::new().into(),
+        None,
+        None,
+    )
+}
+
+#[tokio::test]
+#[ignore]
+async fn get_moderators() {
+    let client = client();
+    let token = token();
+    let req = moderation::GetModeratorsRequest::builder()
+        .broadcaster_id(var("TWITCH_BROADCASTER_ID"))
+        .build();
+
+    let first = client.req_get(req.clone(), &token).await.unwrap();
+    let all: Vec<_> = client
+        .paginate(req, &token)
+        .items()
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(all.len() >= first.data.len());
+    assert_eq!(&all[..first.data.len()], &first.data[..]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn get_banned_users() {
+    let client = client();
+    let token = token();
+    let req = moderation::GetBannedUsersRequest::builder()
+        .broadcaster_id(var("TWITCH_BROADCASTER_ID"))
+        .build();
+
+    let pages: Vec<_> = client.paginate(req, &token).try_collect().await.unwrap();
+    assert!(!pages.is_empty());
+    // Every page but the last one has a cursor to the next page
+    for page in &pages[..pages.len() - 1] {
+        assert!(page.has_more());
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn check_automod_status() {
+    let client = client();
+    let token = token();
+    let req = moderation::CheckAutoModStatusRequest::builder()
+        .broadcaster_id(var("TWITCH_BROADCASTER_ID"))
+        .build();
+    let bodies = vec![
+        moderation::CheckAutoModStatusBody::builder()
+            .msg_id("123")
+            .msg_text("hello world")
+            .user_id(var("TWITCH_BROADCASTER_ID"))
+            .build(),
+        moderation::CheckAutoModStatusBody::builder()
+            .msg_id("456")
+            .msg_text("goodbye world")
+            .user_id(var("TWITCH_BROADCASTER_ID"))
+            .build(),
+    ];
+
+    let response = client.req_post(req, bodies.clone(), &token).await.unwrap();
+    let zipped = response.zip_bodies(&bodies).unwrap();
+    assert_eq!(zipped.len(), bodies.len());
+}